@@ -1,3 +1,83 @@
+use std::fs;
+use std::path::Path;
+
 fn main() {
+    embed_locales();
+    list_async_commands();
     tauri_build::build()
 }
+
+// locales/*.json을 모두 바이너리에 넣는다. 새 언어는 파일만 추가하면 된다
+fn embed_locales() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("locales");
+    println!("cargo:rerun-if-changed={}", dir.display());
+
+    let mut files: Vec<_> = fs::read_dir(&dir)
+        .expect("locales directory is missing")
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+
+    let mut table = String::from("pub const LOCALE_FILES: &[(&str, &str)] = &[\n");
+    for path in files {
+        println!("cargo:rerun-if-changed={}", path.display());
+        let code = path.file_stem().unwrap().to_string_lossy();
+        table.push_str(&format!(
+            "    ({code:?}, include_str!({:?})),\n",
+            path.display().to_string()
+        ));
+    }
+    table.push_str("];\n");
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("locales.rs");
+    fs::write(out, table).expect("failed to write the locale table");
+}
+
+// invoke_handler는 async 커맨드가 넘겨지는 순간 돌아오므로 걸린 시간을 잴 수 없다.
+// #[command] 바로 다음 줄이 pub async fn인 커맨드 이름을 모아 두고 시간 기록에서 뺀다
+fn list_async_commands() {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+    let mut names = Vec::new();
+    collect_async_commands(&src, &mut names);
+    names.sort();
+    names.dedup();
+
+    let list: Vec<String> = names.iter().map(|name| format!("{name:?}")).collect();
+    let table = format!(
+        "pub const ASYNC_COMMANDS: &[&str] = &[{}];\n",
+        list.join(", ")
+    );
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("async_commands.rs");
+    fs::write(out, table).expect("failed to write the async command list");
+}
+
+fn collect_async_commands(dir: &Path, names: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).expect("failed to read src").flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_async_commands(&path, names);
+            continue;
+        }
+        if path.extension().is_none_or(|ext| ext != "rs") {
+            continue;
+        }
+        println!("cargo:rerun-if-changed={}", path.display());
+        let source = fs::read_to_string(&path).unwrap_or_default();
+        let mut lines = source.lines().map(str::trim);
+        while let Some(line) = lines.next() {
+            if line != "#[command]" {
+                continue;
+            }
+            let next = lines.next().unwrap_or_default();
+            if let Some(rest) = next.strip_prefix("pub async fn ") {
+                let name: String = rest
+                    .chars()
+                    .take_while(|c| c.is_alphanumeric() || *c == '_')
+                    .collect();
+                names.push(name);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+use super::project::{Project, MANIFEST_FILE, RUNS_DIR};
+
+const COPY_CHUNK: usize = 1024 * 1024;
+
+// 압축 파일 안에서는 모든 항목이 <프로젝트 폴더 이름>/ 아래에 들어간다
+fn top_dir(project: &Project) -> String {
+    project
+        .root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| project.name.clone())
+}
+
+// 다른 컴퓨터에서도 열리게 프로젝트 안을 가리키는 절대 경로는 상대 경로로 바꾼다.
+// 프로젝트 밖의 파일은 압축에 들어가지 않으니 경고만 남긴다
+pub fn make_portable(project: &mut Project) {
+    let root = project.root.clone();
+    let paths = project
+        .models
+        .iter_mut()
+        .map(|m| &mut m.path)
+        .chain(project.datasets.iter_mut().map(|d| &mut d.path))
+        .chain(project.pipelines.iter_mut().map(|p| &mut p.path));
+    for path in paths {
+        if !path.is_absolute() {
+            continue;
+        }
+        match path.strip_prefix(&root) {
+            Ok(relative) => *path = relative.to_path_buf(),
+            Err(_) => log::warn!(
+                "{} is outside the project and won't be exported",
+                path.display()
+            ),
+        }
+    }
+}
+
+// 매니페스트는 디스크의 파일 대신 넘겨받은 project로 쓴다. on_bytes가 false를 돌려주면
+// 멈추고 Ok(false). 임시 파일에 다 쓴 뒤에 이름을 바꾼다
+pub fn write_project(
+    project: &Project,
+    dest: &Path,
+    include_runs: bool,
+    mut on_bytes: impl FnMut(u64, u64) -> bool,
+) -> io::Result<bool> {
+    let mut files = Vec::new();
+    collect(&project.root, Path::new(""), &mut files)?;
+    files.retain(|file| {
+        let top = file.components().next();
+        file != Path::new(MANIFEST_FILE)
+            && (include_runs || top != Some(Component::Normal(RUNS_DIR.as_ref())))
+    });
+    let total: u64 = files
+        .iter()
+        .filter_map(|file| fs::metadata(project.root.join(file)).ok())
+        .map(|meta| meta.len())
+        .sum();
+
+    let tmp = dest.with_extension("zip.tmp");
+    let result = (|| {
+        let top = top_dir(project);
+        let options = SimpleFileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .large_file(total > u32::MAX as u64);
+        let mut zip = ZipWriter::new(File::create(&tmp)?);
+        zip.start_file(format!("{top}/{MANIFEST_FILE}"), options)?;
+        zip.write_all(&serde_json::to_vec_pretty(project)?)?;
+
+        let mut buf = vec![0u8; COPY_CHUNK];
+        let mut done = 0;
+        for file in &files {
+            let name = file
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            zip.start_file(format!("{top}/{name}"), options)?;
+            let mut reader = File::open(project.root.join(file))?;
+            loop {
+                let n = reader.read(&mut buf)?;
+                if n == 0 {
+                    break;
+                }
+                zip.write_all(&buf[..n])?;
+                done += n as u64;
+                if !on_bytes(done, total) {
+                    return Ok(false);
+                }
+            }
+        }
+        zip.finish()?.sync_all()?;
+        fs::rename(&tmp, dest)?;
+        Ok(true)
+    })();
+    if !matches!(result, Ok(true)) {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+// parent/<압축 안의 폴더 이름>으로 풀고 그 경로를 돌려준다. 이미 있는 폴더에는 풀지 않는다
+pub fn extract_project(archive: &Path, parent: &Path) -> Result<PathBuf, String> {
+    let read_err = |e: zip::result::ZipError| format!("failed to read {}: {e}", archive.display());
+    let file =
+        File::open(archive).map_err(|e| format!("failed to open {}: {e}", archive.display()))?;
+    let mut zip = ZipArchive::new(file).map_err(read_err)?;
+
+    // ..이나 절대 경로로 밖에 쓰려는 항목이 있으면 통째로 거부한다
+    let mut names = Vec::with_capacity(zip.len());
+    for i in 0..zip.len() {
+        let entry = zip.by_index(i).map_err(read_err)?;
+        let name = entry.enclosed_name().ok_or_else(|| {
+            format!(
+                "{} contains an unsafe path: {}",
+                archive.display(),
+                entry.name()
+            )
+        })?;
+        names.push(name);
+    }
+    let top = match names.first().and_then(|name| name.components().next()) {
+        Some(Component::Normal(top)) => PathBuf::from(top),
+        _ => return Err(format!("{} is empty", archive.display())),
+    };
+    if names.iter().any(|name| !name.starts_with(&top)) || !names.contains(&top.join(MANIFEST_FILE))
+    {
+        return Err(format!(
+            "{} is not a Metagate project archive",
+            archive.display()
+        ));
+    }
+    let dest = parent.join(&top);
+    if dest.exists() {
+        return Err(format!("{} already exists", dest.display()));
+    }
+
+    let result = (|| {
+        for (i, name) in names.iter().enumerate() {
+            let mut entry = zip.by_index(i).map_err(io::Error::other)?;
+            let target = parent.join(name);
+            if entry.is_dir() {
+                fs::create_dir_all(&target)?;
+                continue;
+            }
+            if let Some(dir) = target.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            io::copy(&mut entry, &mut File::create(&target)?)?;
+        }
+        Ok::<_, io::Error>(())
+    })();
+    if let Err(err) = result {
+        let _ = fs::remove_dir_all(&dest);
+        return Err(format!("failed to extract {}: {err}", archive.display()));
+    }
+    Ok(dest)
+}
+
+// 프로젝트 기준 상대 경로. 심볼릭 링크는 건너뛴다
+fn collect(root: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let kind = entry.file_type()?;
+        let path = relative.join(entry.file_name());
+        if kind.is_dir() {
+            collect(root, &path, files)?;
+        } else if kind.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::project::DatasetRef;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn project_archive_round_trips_without_runs() {
+        let base = TempDir::new("zip");
+        let root = base.join("demo");
+        fs::create_dir_all(root.join("datasets")).unwrap();
+        fs::create_dir_all(root.join("runs/r1")).unwrap();
+        fs::write(root.join("datasets/iris.csv"), "a,b\n1,2\n").unwrap();
+        fs::write(root.join("runs/r1/log.txt"), "noise").unwrap();
+        let mut project = Project::new(&root, "demo");
+        project.datasets.push(DatasetRef {
+            id: "iris".into(),
+            name: "Iris".into(),
+            path: root.join("datasets/iris.csv"),
+            tags: Vec::new(),
+            stats: None,
+        });
+        make_portable(&mut project);
+
+        let archive = base.join("demo.zip");
+        assert!(write_project(&project, &archive, false, |_, _| true).unwrap());
+        let out = base.join("out");
+        fs::create_dir_all(&out).unwrap();
+        let dest = extract_project(&archive, &out).unwrap();
+        let restored = Project::load(&dest.join(MANIFEST_FILE)).unwrap();
+        let data = fs::read_to_string(dest.join("datasets/iris.csv")).unwrap();
+        let has_runs = dest.join("runs").exists();
+        let again = extract_project(&archive, &out);
+
+        assert_eq!(dest, out.join("demo"));
+        assert_eq!(restored.datasets[0].path, Path::new("datasets/iris.csv"));
+        assert_eq!(data, "a,b\n1,2\n");
+        assert!(!has_runs);
+        assert!(again.unwrap_err().ends_with("already exists"));
+    }
+}
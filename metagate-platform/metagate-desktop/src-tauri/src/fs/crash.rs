@@ -0,0 +1,166 @@
+use std::backtrace::Backtrace;
+use std::fs;
+use std::io;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::logging::log_dir;
+use crate::ops::app::{current_app_info, AppInfo};
+use crate::ops::pipeline::{PipelineRuns, RunId};
+use crate::ops::project::ProjectState;
+use crate::ops::train::TrainingRuns;
+
+const CRASH_PREFIX: &str = "crash-";
+const CRASH_EXTENSION: &str = "json";
+// 리포트하거나 무시한 파일은 확장자를 바꿔서 다음 실행 때 다시 묻지 않는다
+const SENT_EXTENSION: &str = "sent";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CrashReport {
+    pub time: DateTime<Utc>,
+    pub app: AppInfo,
+    pub message: String,
+    pub location: Option<String>,
+    pub thread: Option<String>,
+    pub backtrace: String,
+    pub project: Option<PathBuf>,
+    pub active_runs: Vec<RunId>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingCrash {
+    pub file: PathBuf,
+    pub report: CrashReport,
+}
+
+// 로깅 훅 위에 얹어서 패닉 내용을 crash-*.json으로 남긴다.
+// 시그널로 죽는 경우는 훅이 불리지 않으므로 잡지 못한다
+pub fn install<R: Runtime>(app: AppHandle<R>) {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let report = capture(&app, info);
+        match log_dir(&app).map(|dir| write(&dir, &report)) {
+            Some(Ok(file)) => log::error!("crash report written to {}", file.display()),
+            Some(Err(err)) => log::error!("failed to write crash report: {err}"),
+            None => {}
+        }
+        previous(info);
+    }));
+}
+
+fn capture<R: Runtime>(app: &AppHandle<R>, info: &PanicHookInfo<'_>) -> CrashReport {
+    let message = info
+        .payload()
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".into());
+    // 패닉한 스레드가 락을 들고 있을 수 있어서 try_lock으로만 본다
+    let project = app.try_state::<ProjectState>().and_then(|state| {
+        state
+            .current
+            .try_lock()
+            .ok()
+            .and_then(|project| project.as_ref().map(|p| p.root.clone()))
+    });
+    let mut active_runs = app
+        .try_state::<PipelineRuns>()
+        .map(|runs| runs.try_ids())
+        .unwrap_or_default();
+    if let Some(runs) = app.try_state::<TrainingRuns>() {
+        active_runs.extend(runs.try_ids());
+    }
+
+    CrashReport {
+        time: Utc::now(),
+        app: current_app_info(),
+        message,
+        location: info
+            .location()
+            .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+        thread: std::thread::current().name().map(str::to_string),
+        backtrace: Backtrace::force_capture().to_string(),
+        project,
+        active_runs,
+    }
+}
+
+pub fn write(dir: &Path, report: &CrashReport) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let file = dir.join(format!(
+        "{CRASH_PREFIX}{}.{CRASH_EXTENSION}",
+        report.time.format("%Y%m%d-%H%M%S%.3f")
+    ));
+    fs::write(&file, serde_json::to_vec_pretty(report)?)?;
+    Ok(file)
+}
+
+// 아직 리포트하지 않은 크래시. 깨진 파일은 건너뛴다
+pub fn pending(dir: &Path) -> Vec<PendingCrash> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut crashes: Vec<PendingCrash> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| is_crash_file(path))
+        .filter_map(|file| {
+            let raw = fs::read_to_string(&file).ok()?;
+            let report = serde_json::from_str(&raw).ok()?;
+            Some(PendingCrash { file, report })
+        })
+        .collect();
+    crashes.sort_by_key(|crash| crash.report.time);
+    crashes
+}
+
+pub fn mark_sent(file: &Path) -> io::Result<()> {
+    if !is_crash_file(file) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} is not a crash report", file.display()),
+        ));
+    }
+    fs::rename(file, file.with_extension(SENT_EXTENSION))
+}
+
+fn is_crash_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == CRASH_EXTENSION)
+        && path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with(CRASH_PREFIX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn sent_reports_are_no_longer_pending() {
+        let dir = TempDir::new("crash");
+        let report = CrashReport {
+            time: Utc::now(),
+            app: current_app_info(),
+            message: "boom".into(),
+            location: Some("src/lib.rs:1:1".into()),
+            thread: Some("main".into()),
+            backtrace: String::new(),
+            project: None,
+            active_runs: vec!["run-1".into()],
+        };
+        let file = write(&dir, &report).unwrap();
+        fs::write(dir.join("metagate.log"), "not a crash").unwrap();
+
+        let found = pending(&dir);
+        mark_sent(&file).unwrap();
+        let after = pending(&dir);
+
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].report.message, "boom");
+        assert!(after.is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+use std::path::Path;
+use std::process::Command;
+
+// libgit2를 묶는 대신 설치된 git을 쓴다. 인증이나 프록시 설정도 사용자의 git 설정을 그대로 따른다
+pub fn clone(url: &str, reference: Option<&str>, dest: &Path) -> Result<(), String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err("template URL cannot be empty".into());
+    }
+    // -로 시작하면 git이 옵션으로 읽는다
+    if url.starts_with('-') {
+        return Err(format!("\"{url}\" is not a valid repository URL"));
+    }
+    let reference = reference.map(str::trim).filter(|r| !r.is_empty());
+    if let Some(reference) = reference.filter(|r| r.starts_with('-')) {
+        return Err(format!("\"{reference}\" is not a valid git reference"));
+    }
+
+    git(Command::new("git")
+        .args(["clone", "--quiet", "--"])
+        .arg(url)
+        .arg(dest))?;
+    // --branch는 커밋 해시를 받지 않아서 받은 뒤에 체크아웃한다
+    if let Some(reference) = reference {
+        git(Command::new("git")
+            .arg("-C")
+            .arg(dest)
+            .args(["checkout", "--quiet", "--detach", reference]))?;
+    }
+    Ok(())
+}
+
+fn git(command: &mut Command) -> Result<(), String> {
+    // 인증이 필요한 저장소에서 보이지 않는 프롬프트를 기다리며 멈추지 않게 한다
+    let output = command
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .output()
+        .map_err(|e| format!("failed to run git: {e}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+    Ok(())
+}
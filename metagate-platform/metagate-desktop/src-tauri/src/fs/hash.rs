@@ -0,0 +1,89 @@
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use sha2::{Digest, Sha256};
+
+// 몇 GB짜리 모델도 이 크기씩만 메모리에 올린다
+const CHUNK_SIZE: usize = 1024 * 1024;
+
+pub fn hash_file(path: &Path) -> io::Result<String> {
+    hash_file_with_progress(path, |_, _| {})
+}
+
+// 청크를 하나 읽을 때마다 (읽은 바이트, 전체 바이트)로 progress를 부른다
+pub fn hash_file_with_progress(
+    path: &Path,
+    mut progress: impl FnMut(u64, u64),
+) -> io::Result<String> {
+    let hash = hash_chunks(File::open(path)?, |_, done, total| {
+        progress(done, total);
+        Ok(true)
+    })?;
+    Ok(hash.unwrap_or_default())
+}
+
+// 복사하면서 같은 청크로 해시를 구해서 큰 파일을 두 번 읽지 않는다.
+// progress가 false를 돌려주면 거기서 멈추고 None. 덜 쓴 dest는 호출한 쪽이 지운다
+pub fn copy_and_hash(
+    source: &Path,
+    dest: &Path,
+    mut progress: impl FnMut(u64, u64) -> bool,
+) -> io::Result<Option<String>> {
+    let input = File::open(source)?;
+    let mut output = File::create(dest)?;
+    let hash = hash_chunks(input, |chunk, done, total| {
+        output.write_all(chunk)?;
+        Ok(progress(done, total))
+    })?;
+    output.flush()?;
+    Ok(hash)
+}
+
+fn hash_chunks(
+    mut input: File,
+    mut each: impl FnMut(&[u8], u64, u64) -> io::Result<bool>,
+) -> io::Result<Option<String>> {
+    let total = input.metadata()?.len();
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut done = 0u64;
+    loop {
+        let read = match input.read(&mut buf) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        };
+        hasher.update(&buf[..read]);
+        done += read as u64;
+        if !each(&buf[..read], done, total)? {
+            return Ok(None);
+        }
+    }
+    Ok(Some(
+        hasher
+            .finalize()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn hashes_match_known_digest() {
+        let dir = TempDir::new("hash");
+        let path = dir.join("abc.txt");
+        std::fs::write(&path, b"abc").unwrap();
+        let hash = hash_file(&path);
+        assert_eq!(
+            hash.unwrap(),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
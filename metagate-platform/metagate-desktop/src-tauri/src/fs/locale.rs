@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use tauri::{Manager, Runtime};
+
+// build.rs가 locales/*.json을 (언어 코드, 내용) 목록으로 만들어 넣는다
+include!(concat!(env!("OUT_DIR"), "/locales.rs"));
+
+pub const DEFAULT_LOCALE: &str = "en";
+
+// 플랫폼마다 라벨이 다른 항목은 "quit.windows"처럼 id 뒤에 플랫폼을 붙인 키를 먼저 본다
+const PLATFORM: &str = if cfg!(target_os = "macos") {
+    "macos"
+} else if cfg!(target_os = "windows") {
+    "windows"
+} else {
+    "linux"
+};
+
+// 메뉴 id -> 라벨
+type Catalog = HashMap<String, String>;
+
+fn catalogs() -> &'static HashMap<&'static str, Catalog> {
+    static CATALOGS: OnceLock<HashMap<&'static str, Catalog>> = OnceLock::new();
+    CATALOGS.get_or_init(|| {
+        LOCALE_FILES
+            .iter()
+            .filter_map(|(code, raw)| match serde_json::from_str(raw) {
+                Ok(catalog) => Some((*code, catalog)),
+                Err(err) => {
+                    log::error!("locale file {code}.json is not valid: {err}");
+                    None
+                }
+            })
+            .collect()
+    })
+}
+
+pub fn available_locales() -> Vec<&'static str> {
+    let mut locales: Vec<&str> = catalogs().keys().copied().collect();
+    locales.sort();
+    locales
+}
+
+// "ko-KR"처럼 지역까지 붙어 있고 그 파일이 없으면 언어만 맞춰 본다
+pub fn resolve(locale: &str) -> Option<&'static str> {
+    let locale = locale.trim().replace('_', "-");
+    let language = locale.split('-').next().unwrap_or_default();
+    let known = |code: &str| {
+        catalogs()
+            .keys()
+            .copied()
+            .find(|known| known.eq_ignore_ascii_case(code))
+    };
+    known(&locale).or_else(|| known(language))
+}
+
+// 대체 없이 그 언어 파일에서만 찾는다
+pub fn translation(id: &str, locale: &str) -> Option<&'static str> {
+    let catalog = catalogs().get(locale)?;
+    catalog
+        .get(&format!("{id}.{PLATFORM}"))
+        .or_else(|| catalog.get(id))
+        .map(String::as_str)
+}
+
+// 번역이 없으면 영어, 영어에도 없으면 id를 그대로 쓴다
+pub fn t<'a>(id: &'a str, locale: &str) -> &'a str {
+    resolve(locale)
+        .and_then(|locale| translation(id, locale))
+        .or_else(|| translation(id, DEFAULT_LOCALE))
+        .unwrap_or(id)
+}
+
+// 메뉴를 다시 만들 때마다 환경설정 파일을 읽지 않도록 여기 들고 있는다
+pub struct MenuLocale(Mutex<String>);
+
+impl MenuLocale {
+    pub fn new(locale: String) -> Self {
+        MenuLocale(Mutex::new(locale))
+    }
+
+    pub fn get(&self) -> String {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    pub fn set(&self, locale: String) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = locale;
+    }
+}
+
+// 테스트용 mock 앱처럼 상태가 없으면 영어를 쓴다
+pub fn app_locale<R: Runtime, M: Manager<R>>(app: &M) -> String {
+    app.try_state::<MenuLocale>()
+        .map_or_else(|| DEFAULT_LOCALE.into(), |state| state.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_translations_fall_back_to_english() {
+        assert!(available_locales().contains(&DEFAULT_LOCALE));
+        assert_eq!(available_locales().len(), LOCALE_FILES.len());
+        assert_eq!(resolve("ko_KR"), Some("ko"));
+        assert_eq!(resolve("xx"), None);
+
+        assert_eq!(t("file_menu", "ko"), "파일");
+        assert_eq!(t("file_menu", "xx"), "File");
+        assert_eq!(t("no_such_item", "ko"), "no_such_item");
+        let quit = if cfg!(target_os = "windows") {
+            "Exit"
+        } else {
+            "Quit"
+        };
+        assert_eq!(t("quit", DEFAULT_LOCALE), quit);
+    }
+}
@@ -0,0 +1,87 @@
+use std::backtrace::Backtrace;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, Runtime};
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{fmt, EnvFilter};
+
+use super::preferences::Preferences;
+
+// RUST_LOG와 같은 문법. 설정돼 있으면 환경설정보다 우선한다
+const LOG_ENV: &str = "METAGATE_LOG";
+const LOG_FILE_PREFIX: &str = "metagate.log";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    fn directive(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+// 드롭되면 버퍼에 남은 로그가 파일에 써지지 않으므로 앱이 끝날 때까지 들고 있는다
+pub struct LogGuard(#[allow(dead_code)] WorkerGuard);
+
+pub fn log_dir<R: Runtime, M: Manager<R>>(app: &M) -> Option<PathBuf> {
+    app.path().app_log_dir().ok()
+}
+
+// 앱 로그 디렉토리에 날짜별 파일로 남기고, 개발 중에는 터미널에도 찍는다.
+// 기존 log:: 매크로도 tracing으로 넘어온다
+pub fn init<R: Runtime, M: Manager<R>>(app: &M) -> Option<LogGuard> {
+    let filter = EnvFilter::try_from_env(LOG_ENV)
+        .unwrap_or_else(|_| EnvFilter::new(Preferences::load(app).log_level.directive()));
+
+    let (file_layer, guard) = match log_dir(app) {
+        Some(dir) => {
+            let (writer, guard) = tracing_appender::non_blocking(tracing_appender::rolling::daily(
+                dir,
+                LOG_FILE_PREFIX,
+            ));
+            let layer = fmt::layer().with_writer(writer).with_ansi(false);
+            (Some(layer), Some(LogGuard(guard)))
+        }
+        None => (None, None),
+    };
+
+    let result = tracing_subscriber::registry()
+        .with(filter)
+        .with(file_layer)
+        .with(cfg!(debug_assertions).then(fmt::layer))
+        .try_init();
+    if let Err(err) = result {
+        eprintln!("failed to initialize logging: {err}");
+    }
+    log_panics();
+    guard
+}
+
+// 기본 훅(stderr 출력)은 그대로 두고 백트레이스를 로그 파일에도 남긴다
+fn log_panics() {
+    let default = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        tracing::error!(
+            backtrace = %Backtrace::force_capture(),
+            "panic: {info}"
+        );
+        default(info);
+    }));
+}
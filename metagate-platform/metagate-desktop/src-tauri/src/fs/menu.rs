@@ -1,183 +1,495 @@
-use tauri::{menu::*, App, Runtime};
-
-pub fn create_menu<R: Runtime>(app: &App<R>) -> Result<Menu<R>, tauri::Error> {
-    let app_menu = SubmenuBuilder::new(app, "Metagate")
-        .item(
-            &MenuItemBuilder::with_id("preferences", "Preferences...")
-                .accelerator("CmdOrCtrl+,")
-                .build(app)?,
-        )
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("quit", "Quit")
-                .accelerator("CmdOrCtrl+Q")
-                .build(app)?,
-        )
-        .build()?;
-
-    // File 메뉴
-    let file_menu = SubmenuBuilder::new(app, "File")
-        .item(
-            &MenuItemBuilder::with_id("new_project", "New Project")
-                .accelerator("CmdOrCtrl+N")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("open_project", "Open Project...")
-                .accelerator("CmdOrCtrl+O")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("save_project", "Save Project")
-                .accelerator("CmdOrCtrl+S")
-                .build(app)?,
-        )
-        .separator()
-        .item(&MenuItemBuilder::with_id("import_model", "Import Model...").build(app)?)
-        .item(&MenuItemBuilder::with_id("export_model", "Export Model...").build(app)?)
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("close_window", "Close Window")
-                .accelerator("CmdOrCtrl+W")
-                .build(app)?,
-        )
-        .build()?;
-
-    // Edit 메뉴
-    let edit_menu = SubmenuBuilder::new(app, "Edit")
-        .item(
-            &MenuItemBuilder::with_id("undo", "Undo")
-                .accelerator("CmdOrCtrl+Z")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("redo", "Redo")
-                .accelerator("CmdOrCtrl+Shift+Z")
-                .build(app)?,
-        )
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("cut", "Cut")
-                .accelerator("CmdOrCtrl+X")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("copy", "Copy")
-                .accelerator("CmdOrCtrl+C")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("paste", "Paste")
-                .accelerator("CmdOrCtrl+V")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("select_all", "Select All")
-                .accelerator("CmdOrCtrl+A")
-                .build(app)?,
-        )
-        .build()?;
-
-    // View 메뉴
-    let view_menu = SubmenuBuilder::new(app, "View")
-        .item(
-            &MenuItemBuilder::with_id("dashboard", "Dashboard")
-                .accelerator("CmdOrCtrl+1")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("models", "Models")
-                .accelerator("CmdOrCtrl+2")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("datasets", "Datasets")
-                .accelerator("CmdOrCtrl+3")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("pipelines", "Pipelines")
-                .accelerator("CmdOrCtrl+4")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("monitoring", "Monitoring")
-                .accelerator("CmdOrCtrl+5")
-                .build(app)?,
-        )
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("toggle_sidebar", "Toggle Sidebar")
-                .accelerator("CmdOrCtrl+B")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("full_screen", "Enter Full Screen")
-                .accelerator("Ctrl+Cmd+F")
-                .build(app)?,
-        )
-        .build()?;
-
-    // MLOps 메뉴
-    let mlops_menu = SubmenuBuilder::new(app, "MLOps")
-        .item(
-            &MenuItemBuilder::with_id("train_model", "Train Model")
-                .accelerator("CmdOrCtrl+T")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("deploy_model", "Deploy Model")
-                .accelerator("CmdOrCtrl+D")
-                .build(app)?,
-        )
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("run_pipeline", "Run Pipeline")
-                .accelerator("CmdOrCtrl+R")
-                .build(app)?,
-        )
-        .item(
-            &MenuItemBuilder::with_id("stop_pipeline", "Stop Pipeline")
-                .accelerator("CmdOrCtrl+.")
-                .build(app)?,
-        )
-        .separator()
-        .item(
-            &MenuItemBuilder::with_id("view_logs", "View Logs")
-                .accelerator("CmdOrCtrl+L")
-                .build(app)?,
-        )
-        .build()?;
-
-    // Window 메뉴
-    let window_menu = SubmenuBuilder::new(app, "Window")
-        .item(
-            &MenuItemBuilder::with_id("minimize", "Minimize")
-                .accelerator("CmdOrCtrl+M")
-                .build(app)?,
-        )
-        .item(&MenuItemBuilder::with_id("zoom", "Zoom").build(app)?)
-        .separator()
-        .item(&MenuItemBuilder::with_id("bring_to_front", "Bring All to Front").build(app)?)
-        .build()?;
-
-    // Help 메뉴
-    let help_menu = SubmenuBuilder::new(app, "Help")
-        .item(&MenuItemBuilder::with_id("documentation", "Documentation").build(app)?)
-        .item(&MenuItemBuilder::with_id("shortcuts", "Keyboard Shortcuts").build(app)?)
-        .separator()
-        .item(&MenuItemBuilder::with_id("report_issue", "Report Issue").build(app)?)
-        .item(&MenuItemBuilder::with_id("check_updates", "Check for Updates").build(app)?)
-        .build()?;
-
-    // 메인 메뉴 생성
-    MenuBuilder::new(app)
-        .item(&app_menu)
-        .item(&file_menu)
-        .item(&edit_menu)
-        .item(&view_menu)
-        .item(&mlops_menu)
-        .item(&window_menu)
-        .item(&help_menu)
-        .build()
+use serde::{Deserialize, Serialize};
+use tauri::{menu::*, AppHandle, Manager, Runtime};
+
+use super::locale::{app_locale, t};
+use super::preferences::Preferences;
+use super::recent::RecentProjects;
+use super::shortcuts::app_shortcut;
+use crate::ops::history::{UndoStack, REDO_ITEM, UNDO_ITEM};
+use crate::ops::project::{ProjectState, MUTATING_MENU_ITEMS};
+use crate::ops::view::{
+    SidebarState, FULL_SCREEN_ITEM, SIDEBAR_ITEM, ZOOM_IN_ITEM, ZOOM_OUT_ITEM, ZOOM_RESET_ITEM,
+};
+use crate::ops::window::NEW_WINDOW_ITEM;
+
+const IS_MACOS: bool = cfg!(target_os = "macos");
+
+// 메뉴 전체를 데이터로 표현한 것. 네이티브 메뉴도 이걸로 만들기 때문에
+// 프론트엔드가 받는 구조와 실제 메뉴가 어긋나지 않는다
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MenuTree {
+    pub menus: Vec<MenuNode>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MenuNode {
+    // 구분선은 빈 문자열
+    pub id: String,
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accelerator: Option<String>,
+    #[serde(default)]
+    pub children: Vec<MenuNode>,
+    pub kind: MenuNodeKind,
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    // Check 항목만 의미가 있다
+    #[serde(default)]
+    pub checked: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MenuNodeKind {
+    Submenu,
+    Item,
+    Check,
+    Separator,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+impl MenuNode {
+    fn new(kind: MenuNodeKind, id: &str, label: &str) -> Self {
+        MenuNode {
+            id: id.into(),
+            label: label.into(),
+            accelerator: None,
+            children: Vec::new(),
+            kind,
+            enabled: true,
+            checked: false,
+        }
+    }
+
+    // 라벨은 id로 번역 파일에서 찾는다
+    fn submenu(id: &str, locale: &str, children: Vec<MenuNode>) -> Self {
+        MenuNode {
+            children,
+            ..MenuNode::new(MenuNodeKind::Submenu, id, t(id, locale))
+        }
+    }
+
+    fn item(id: &str, locale: &str) -> Self {
+        MenuNode::new(MenuNodeKind::Item, id, t(id, locale))
+    }
+
+    fn separator() -> Self {
+        MenuNode::new(MenuNodeKind::Separator, "", "")
+    }
+
+    fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+pub fn create_menu<R: Runtime, M: Manager<R>>(app: &M) -> Result<Menu<R>, tauri::Error> {
+    build_menu(app, &menu_tree(app))
+}
+
+// 메인 메뉴 구조. macOS만 앱 이름 메뉴를 따로 둔다
+pub fn menu_tree<R: Runtime, M: Manager<R>>(app: &M) -> MenuTree {
+    let locale = app_locale(app);
+    let mut menus = Vec::new();
+    if IS_MACOS {
+        menus.push(app_menu(app, &locale));
+    }
+    menus.extend([
+        file_menu(app, &locale),
+        edit_menu(app, &locale),
+        view_menu(app, &locale),
+        mlops_menu(app, &locale),
+        window_menu(app, &locale),
+        help_menu(&locale),
+    ]);
+    if app
+        .try_state::<ProjectState>()
+        .is_some_and(|state| state.is_read_only())
+    {
+        disable(&mut menus, &MUTATING_MENU_ITEMS);
+    }
+    MenuTree { menus }
+}
+
+fn disable(nodes: &mut [MenuNode], ids: &[&str]) {
+    for node in nodes {
+        if ids.contains(&node.id.as_str()) {
+            node.enabled = false;
+        }
+        disable(&mut node.children, ids);
+    }
+}
+
+pub fn build_menu<R: Runtime, M: Manager<R>>(
+    app: &M,
+    tree: &MenuTree,
+) -> Result<Menu<R>, tauri::Error> {
+    let mut menu = MenuBuilder::new(app);
+    for node in &tree.menus {
+        menu = menu.item(&build_submenu(app, node)?);
+    }
+    menu.build()
+}
+
+fn build_submenu<R: Runtime, M: Manager<R>>(
+    app: &M,
+    node: &MenuNode,
+) -> Result<Submenu<R>, tauri::Error> {
+    let mut submenu = SubmenuBuilder::with_id(app, &node.id, &node.label).enabled(node.enabled);
+    for child in &node.children {
+        submenu = match child.kind {
+            MenuNodeKind::Separator => submenu.separator(),
+            MenuNodeKind::Submenu => submenu.item(&build_submenu(app, child)?),
+            MenuNodeKind::Check => {
+                let mut item = CheckMenuItemBuilder::with_id(&child.id, &child.label)
+                    .checked(child.checked)
+                    .enabled(child.enabled);
+                if let Some(accelerator) = &child.accelerator {
+                    item = item.accelerator(accelerator);
+                }
+                submenu.item(&item.build(app)?)
+            }
+            MenuNodeKind::Item => {
+                let mut item =
+                    MenuItemBuilder::with_id(&child.id, &child.label).enabled(child.enabled);
+                if let Some(accelerator) = &child.accelerator {
+                    item = item.accelerator(accelerator);
+                }
+                submenu.item(&item.build(app)?)
+            }
+        };
+    }
+    submenu.build()
+}
+
+fn app_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    MenuNode::submenu(
+        "app_menu",
+        locale,
+        vec![
+            shortcut_item(app, "preferences"),
+            MenuNode::separator(),
+            shortcut_item(app, "quit"),
+        ],
+    )
+}
+
+// File 메뉴. Windows/Linux에서는 Preferences와 종료 항목도 여기 들어간다
+fn file_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    let mut items = vec![
+        shortcut_item(app, "new_project"),
+        shortcut_item(app, "open_project"),
+        recent_menu(app, locale),
+        shortcut_item(app, "save_project"),
+        shortcut_item(app, "save_project_as"),
+        MenuNode::item("revert_project", locale),
+        MenuNode::item("close_project", locale),
+        MenuNode::separator(),
+        MenuNode::item("import_model", locale),
+        MenuNode::item("export_model", locale),
+        MenuNode::separator(),
+        shortcut_item(app, "close_window"),
+    ];
+    if !IS_MACOS {
+        items.extend([
+            MenuNode::separator(),
+            shortcut_item(app, "preferences"),
+            MenuNode::separator(),
+            shortcut_item(app, "quit"),
+        ]);
+    }
+    MenuNode::submenu("file_menu", locale, items)
+}
+
+// Edit 메뉴
+fn edit_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    let (can_undo, can_redo) = app
+        .try_state::<UndoStack>()
+        .map_or((false, false), |stack| (stack.can_undo(), stack.can_redo()));
+
+    MenuNode::submenu(
+        "edit_menu",
+        locale,
+        vec![
+            shortcut_item(app, UNDO_ITEM).enabled(can_undo),
+            shortcut_item(app, REDO_ITEM).enabled(can_redo),
+            MenuNode::separator(),
+            shortcut_item(app, "cut"),
+            shortcut_item(app, "copy"),
+            shortcut_item(app, "paste"),
+            shortcut_item(app, "select_all"),
+        ],
+    )
+}
+
+// View 메뉴
+fn view_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    let sidebar_visible = app
+        .try_state::<SidebarState>()
+        .is_none_or(|state| state.visible());
+    let sidebar = MenuNode {
+        kind: MenuNodeKind::Check,
+        checked: sidebar_visible,
+        ..shortcut_item(app, SIDEBAR_ITEM)
+    };
+
+    MenuNode::submenu(
+        "view_menu",
+        locale,
+        vec![
+            shortcut_item(app, "dashboard"),
+            shortcut_item(app, "models"),
+            shortcut_item(app, "datasets"),
+            shortcut_item(app, "pipelines"),
+            shortcut_item(app, "monitoring"),
+            MenuNode::separator(),
+            sidebar,
+            shortcut_item(app, FULL_SCREEN_ITEM),
+        ],
+    )
+}
+
+// MLOps 메뉴
+fn mlops_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    MenuNode::submenu(
+        "mlops_menu",
+        locale,
+        vec![
+            shortcut_item(app, "train_model"),
+            shortcut_item(app, "deploy_model"),
+            MenuNode::separator(),
+            shortcut_item(app, "run_pipeline"),
+            shortcut_item(app, "stop_pipeline"),
+            MenuNode::separator(),
+            shortcut_item(app, "view_logs"),
+        ],
+    )
+}
+
+// Window 메뉴
+fn window_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    MenuNode::submenu(
+        "window_menu",
+        locale,
+        vec![
+            shortcut_item(app, NEW_WINDOW_ITEM),
+            MenuNode::separator(),
+            shortcut_item(app, "minimize"),
+            MenuNode::separator(),
+            shortcut_item(app, ZOOM_IN_ITEM),
+            shortcut_item(app, ZOOM_OUT_ITEM),
+            shortcut_item(app, ZOOM_RESET_ITEM),
+            MenuNode::separator(),
+            MenuNode::item("bring_to_front", locale),
+        ],
+    )
+}
+
+// Help 메뉴
+fn help_menu(locale: &str) -> MenuNode {
+    MenuNode::submenu(
+        "help_menu",
+        locale,
+        vec![
+            MenuNode::item("documentation", locale),
+            MenuNode::item("shortcuts", locale),
+            MenuNode::separator(),
+            MenuNode::item("report_issue", locale),
+            MenuNode::item("open_log_folder", locale),
+            MenuNode::item("clear_cache", locale),
+            MenuNode::item("save_debug_snapshot", locale),
+            MenuNode::item("check_updates", locale),
+        ],
+    )
+}
+
+// 단축키가 있는 항목은 라벨과 단축키를 shortcuts()에서 가져온다. 사용자가 바꾼 단축키가 우선한다.
+// 목록에 없으면 단축키 없는 항목으로 만든다
+fn shortcut_item<R: Runtime, M: Manager<R>>(app: &M, id: &str) -> MenuNode {
+    let Some(entry) = app_shortcut(app, id) else {
+        log::warn!("no shortcut registered for menu item {id}");
+        return MenuNode::item(id, &app_locale(app));
+    };
+    MenuNode {
+        accelerator: Some(entry.accelerator),
+        ..MenuNode::new(MenuNodeKind::Item, entry.id, entry.label)
+    }
+}
+
+// 최근 프로젝트 메뉴. ID는 recent_0, recent_1 ... 순서
+fn recent_menu<R: Runtime, M: Manager<R>>(app: &M, locale: &str) -> MenuNode {
+    let recent = RecentProjects::load(app);
+    let limit = Preferences::load(app).max_recent_projects;
+    let mut items = Vec::new();
+
+    if recent.list().is_empty() {
+        items.push(MenuNode::item("recent_empty", locale).enabled(false));
+    }
+    for (i, path) in recent.list().iter().take(limit).enumerate() {
+        items.push(MenuNode::new(
+            MenuNodeKind::Item,
+            &format!("recent_{i}"),
+            &path.display().to_string(),
+        ));
+    }
+    items.extend([
+        MenuNode::separator(),
+        MenuNode::item("clear_recent", locale).enabled(!recent.list().is_empty()),
+    ]);
+    MenuNode::submenu("open_recent", locale, items)
+}
+
+pub fn refresh_menu<R: Runtime>(app: &AppHandle<R>) {
+    let result = create_menu(app).and_then(|menu| app.set_menu(menu));
+    if let Err(err) = result {
+        log::error!("failed to rebuild menu: {err}");
+    }
+}
+
+// Menu::get은 최상위 항목만 보기 때문에 하위 메뉴까지 내려가며 찾는다
+pub fn find_menu_item<R: Runtime>(app: &AppHandle<R>, id: &str) -> Option<MenuItemKind<R>> {
+    fn search<R: Runtime>(items: Vec<MenuItemKind<R>>, id: &str) -> Option<MenuItemKind<R>> {
+        items.into_iter().find_map(|item| {
+            if item.id() == id {
+                return Some(item);
+            }
+            let children = item.as_submenu()?.items().ok()?;
+            search(children, id)
+        })
+    }
+    search(app.menu()?.items().ok()?, id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::locale::{translation, DEFAULT_LOCALE};
+    use crate::fs::shortcuts::shortcuts;
+
+    fn item_ids(node: &MenuNode) -> Vec<&str> {
+        node.children.iter().map(|item| item.id.as_str()).collect()
+    }
+
+    #[test]
+    fn file_menu_holds_quit_only_off_macos() {
+        let app = tauri::test::mock_app();
+        let menu = file_menu(&app, "en");
+        let ids = item_ids(&menu);
+        assert!(ids.contains(&"open_project"));
+        assert_eq!(ids.contains(&"quit"), !IS_MACOS);
+        assert_eq!(ids.contains(&"preferences"), !IS_MACOS);
+    }
+
+    #[test]
+    fn view_menu_lists_every_route() {
+        let app = tauri::test::mock_app();
+        let menu = view_menu(&app, "en");
+        let ids = item_ids(&menu);
+        for id in super::super::menu_events::VIEW_MENU_IDS {
+            assert!(ids.contains(&id), "missing {id}");
+        }
+    }
+
+    #[test]
+    fn every_menu_id_has_an_english_label() {
+        fn check(nodes: &[MenuNode]) {
+            for node in nodes {
+                // 구분선과 최근 프로젝트 경로는 번역하지 않는다
+                let path = node.id.starts_with("recent_") && node.id[7..].parse::<usize>().is_ok();
+                if !node.id.is_empty() && !path {
+                    assert!(
+                        translation(&node.id, DEFAULT_LOCALE).is_some(),
+                        "locales/en.json has no label for {}",
+                        node.id
+                    );
+                }
+                check(&node.children);
+            }
+        }
+        let app = tauri::test::mock_app();
+        let mut menus = menu_tree(&app).menus;
+        // 플랫폼마다 빠지는 메뉴가 있어 앱 메뉴는 따로 넣어 본다
+        menus.push(app_menu(&app, DEFAULT_LOCALE));
+        check(&menus);
+    }
+
+    #[test]
+    fn menu_tree_round_trips_and_matches_the_native_menu() {
+        let app = tauri::test::mock_app();
+        let tree = menu_tree(&app);
+        let json = serde_json::to_string(&tree).unwrap();
+        assert_eq!(serde_json::from_str::<MenuTree>(&json).unwrap(), tree);
+
+        let labels: Vec<&str> = tree.menus.iter().map(|m| m.label.as_str()).collect();
+        let mut expected = vec!["File", "Edit", "View", "MLOps", "Window", "Help"];
+        if IS_MACOS {
+            expected.insert(0, "Metagate");
+        }
+        assert_eq!(labels, expected);
+
+        // 구분선은 네이티브 메뉴에서 id가 따로 붙으니 빼고 비교한다
+        let native = create_menu(&app).unwrap().items().unwrap();
+        assert_eq!(native.len(), tree.menus.len());
+        for (item, node) in native.iter().zip(&tree.menus) {
+            let children: Vec<String> = item
+                .as_submenu()
+                .unwrap()
+                .items()
+                .unwrap()
+                .iter()
+                .filter(|child| !matches!(child, MenuItemKind::Predefined(_)))
+                .map(|child| child.id().as_ref().to_string())
+                .collect();
+            let expected: Vec<&str> = node
+                .children
+                .iter()
+                .filter(|child| child.kind != MenuNodeKind::Separator)
+                .map(|child| child.id.as_str())
+                .collect();
+            assert_eq!(item.id().as_ref(), node.id);
+            assert_eq!(children, expected);
+        }
+    }
+
+    #[test]
+    fn every_shortcut_item_has_a_registered_shortcut() {
+        let constants = [
+            ("UNDO_ITEM", UNDO_ITEM),
+            ("REDO_ITEM", REDO_ITEM),
+            ("SIDEBAR_ITEM", SIDEBAR_ITEM),
+            ("FULL_SCREEN_ITEM", FULL_SCREEN_ITEM),
+            ("NEW_WINDOW_ITEM", NEW_WINDOW_ITEM),
+            ("ZOOM_IN_ITEM", ZOOM_IN_ITEM),
+            ("ZOOM_OUT_ITEM", ZOOM_OUT_ITEM),
+            ("ZOOM_RESET_ITEM", ZOOM_RESET_ITEM),
+        ];
+        let registered: Vec<&str> = shortcuts().iter().map(|entry| entry.id).collect();
+        // 테스트 모듈 앞까지에서 shortcut_item으로 만드는 항목을 모두 찾는다
+        let source = include_str!("menu.rs")
+            .split("#[cfg(test)]")
+            .next()
+            .unwrap();
+        let calls: Vec<&str> = source
+            .split("shortcut_item(app, ")
+            .skip(1)
+            .filter_map(|rest| rest.split(')').next())
+            .collect();
+        assert!(!calls.is_empty());
+        for call in calls {
+            let id = match call.strip_prefix('"') {
+                Some(literal) => literal.trim_end_matches('"'),
+                None => {
+                    constants
+                        .iter()
+                        .find(|(name, _)| *name == call)
+                        .unwrap_or_else(|| panic!("add {call} to the constants above"))
+                        .1
+                }
+            };
+            assert!(registered.contains(&id), "no shortcut registered for {id}");
+        }
+    }
 }
@@ -0,0 +1,290 @@
+use tauri::{menu::MenuEvent, AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::menu::refresh_menu;
+use super::recent::RecentProjects;
+use super::shortcuts::app_shortcuts;
+use crate::ops::app::{clear_app_cache, open_documentation, open_issue_tracker, reveal_log_folder};
+use crate::ops::debug::save_snapshot;
+use crate::ops::emit;
+use crate::ops::history::{redo_last, undo_last, REDO_ITEM, UNDO_ITEM};
+use crate::ops::model::{import_model_path, pick_model_file};
+use crate::ops::preferences::open_preferences_window;
+use crate::ops::project::{
+    open_project_at, pick_project, pick_save_as, request_close_project, request_revert_project,
+    ProjectInfo, ProjectState,
+};
+use crate::ops::shutdown::request_quit;
+use crate::ops::update::fetch_update_status;
+use crate::ops::view::{
+    select_all, toggle_full_screen, toggle_sidebar, zoom, Zoom, FULL_SCREEN_ITEM, SIDEBAR_ITEM,
+    ZOOM_IN_ITEM, ZOOM_OUT_ITEM, ZOOM_RESET_ITEM,
+};
+use crate::ops::window::{
+    bring_all_to_front, minimize_focused, open_window, update_window_title, NEW_WINDOW_ITEM,
+};
+
+pub const VIEW_MENU_IDS: [&str; 5] = ["dashboard", "models", "datasets", "pipelines", "monitoring"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationTarget {
+    Dashboard,
+    Models,
+    Datasets,
+    Pipelines,
+    Monitoring,
+}
+
+impl NavigationTarget {
+    pub fn to_route(self) -> &'static str {
+        match self {
+            NavigationTarget::Dashboard => "dashboard",
+            NavigationTarget::Models => "models",
+            NavigationTarget::Datasets => "datasets",
+            NavigationTarget::Pipelines => "pipelines",
+            NavigationTarget::Monitoring => "monitoring",
+        }
+    }
+}
+
+// 알 수 없는 ID는 대시보드로 보낸다
+impl From<&str> for NavigationTarget {
+    fn from(id: &str) -> Self {
+        match id {
+            "models" => NavigationTarget::Models,
+            "datasets" => NavigationTarget::Datasets,
+            "pipelines" => NavigationTarget::Pipelines,
+            "monitoring" => NavigationTarget::Monitoring,
+            _ => NavigationTarget::Dashboard,
+        }
+    }
+}
+
+pub fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, event: MenuEvent) {
+    run_menu_action(app, event.id().as_ref());
+}
+
+// 메뉴 클릭과 커맨드 팔레트가 모두 여기로 들어와서 같은 동작을 한다
+pub fn run_menu_action<R: Runtime>(app: &AppHandle<R>, id: &str) {
+    // 버그 리포트에 무엇을 눌렀는지 남도록 메뉴 이벤트마다 span을 연다
+    let span = tracing::info_span!("menu_event", id);
+    let _entered = span.enter();
+    tracing::info!("menu item clicked");
+
+    match id {
+        "quit" => {
+            request_quit(app);
+        }
+        "preferences" => {
+            if let Err(err) = open_preferences_window(app) {
+                log::error!("failed to open preferences window: {err}");
+            }
+        }
+        UNDO_ITEM => history_step(app, undo_last),
+        REDO_ITEM => history_step(app, redo_last),
+        NEW_WINDOW_ITEM => {
+            if let Err(err) = open_window(app) {
+                log::error!("failed to open new window: {err}");
+            }
+        }
+        "close_window" => close_focused_window(app),
+        "minimize" => minimize_focused(app),
+        "bring_to_front" => bring_all_to_front(app),
+        // 이름/위치/템플릿은 프론트엔드 대화상자에서 받아 create_project로 넘긴다
+        "new_project" => emit(app, "new-project-requested", ()),
+        "open_project" => open_project(app),
+        "save_project" => save_project(app),
+        "save_project_as" => save_project_as(app),
+        "revert_project" => {
+            if let Err(err) = request_revert_project(app) {
+                log::error!("failed to revert project: {err}");
+            }
+        }
+        "close_project" => {
+            request_close_project(app);
+        }
+        "clear_recent" => clear_recent(app),
+        "import_model" => import_model(app),
+        // 선택된 모델은 프론트엔드만 알고 있으니 요청만 전달한다
+        "export_model" => emit(app, "export-model-requested", ()),
+        "cut" => emit(app, "cut-requested", ()),
+        "copy" => emit(app, "copy-requested", ()),
+        "paste" => emit(app, "paste-requested", ()),
+        "select_all" => select_all(app),
+        "train_model" => emit(app, "train-model-requested", ()),
+        "deploy_model" => emit(app, "deploy-model-requested", ()),
+        "run_pipeline" => emit(app, "run-pipeline-requested", ()),
+        "stop_pipeline" => emit(app, "stop-pipeline-requested", ()),
+        "view_logs" => emit(app, "view-logs-requested", ()),
+        "shortcuts" => emit(app, "show-shortcuts", app_shortcuts(app)),
+        "check_updates" => check_updates(app),
+        "open_log_folder" => {
+            if let Err(err) = reveal_log_folder(app) {
+                log::error!("failed to open log folder: {err}");
+            }
+        }
+        "documentation" => {
+            if let Err(err) = open_documentation(app, None) {
+                log::error!("failed to open documentation: {err}");
+            }
+        }
+        "clear_cache" => {
+            if let Err(err) = clear_app_cache(app) {
+                log::error!("failed to clear cache: {err}");
+            }
+        }
+        "save_debug_snapshot" => save_debug_snapshot(app),
+        "report_issue" => {
+            if let Err(err) = open_issue_tracker(app) {
+                log::error!("failed to open issue tracker: {err}");
+            }
+        }
+        SIDEBAR_ITEM => toggle_sidebar(app),
+        FULL_SCREEN_ITEM => toggle_full_screen(app),
+        ZOOM_IN_ITEM => zoom(app, Zoom::In),
+        ZOOM_OUT_ITEM => zoom(app, Zoom::Out),
+        ZOOM_RESET_ITEM => zoom(app, Zoom::Reset),
+        id if id.starts_with("recent_") => open_recent(app, id),
+        id if VIEW_MENU_IDS.contains(&id) => navigate(app, NavigationTarget::from(id)),
+        id => log::warn!("unhandled menu event: {id}"),
+    }
+}
+
+pub fn navigate<R: Runtime>(app: &AppHandle<R>, target: NavigationTarget) {
+    emit(app, "navigate", target.to_route());
+}
+
+pub fn focused_window<R: Runtime>(app: &AppHandle<R>) -> Option<WebviewWindow<R>> {
+    app.webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false))
+}
+
+fn open_project<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match pick_project(app.clone()).await {
+            Ok(Some(info)) => project_opened(&app, info),
+            Ok(None) => {}
+            Err(err) => log::warn!("open project failed: {err}"),
+        }
+    });
+}
+
+fn import_model<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let path = match pick_model_file(app.clone()).await {
+            Ok(Some(path)) => path,
+            Ok(None) => return,
+            Err(err) => return log::warn!("import model failed: {err}"),
+        };
+        match import_model_path(app.clone(), path).await {
+            Ok(model) => emit(&app, "model-imported", model),
+            Err(err) => log::warn!("import model failed: {err}"),
+        }
+    });
+}
+
+fn open_recent<R: Runtime>(app: &AppHandle<R>, id: &str) {
+    let Some(index) = id
+        .strip_prefix("recent_")
+        .and_then(|n| n.parse::<usize>().ok())
+    else {
+        log::warn!("unhandled menu event: {id}");
+        return;
+    };
+    let Some(dir) = RecentProjects::load(app).list().get(index).cloned() else {
+        log::warn!("no recent project at index {index}");
+        return;
+    };
+    match open_project_at(app, &dir) {
+        Ok(info) => project_opened(app, info),
+        Err(err) => log::warn!("open recent project failed: {err}"),
+    }
+}
+
+fn project_opened<R: Runtime>(app: &AppHandle<R>, info: ProjectInfo) {
+    emit(app, "project-opened", info);
+}
+
+fn clear_recent<R: Runtime>(app: &AppHandle<R>) {
+    let mut recent = RecentProjects::load(app);
+    recent.clear();
+    if let Err(err) = recent.save(app) {
+        log::warn!("failed to save recent projects: {err}");
+    }
+    refresh_menu(app);
+}
+
+fn save_project<R: Runtime>(app: &AppHandle<R>) {
+    match app.state::<ProjectState>().save() {
+        Ok(true) => {
+            update_window_title(app);
+            emit(app, "project-saved", ());
+        }
+        Ok(false) => {}
+        Err(err) => log::error!("save project failed: {err}"),
+    }
+}
+
+fn save_project_as<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = pick_save_as(app).await {
+            log::error!("save project as failed: {err}");
+        }
+    });
+}
+
+fn save_debug_snapshot<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = save_snapshot(app).await {
+            log::error!("failed to save debug snapshot: {err}");
+        }
+    });
+}
+
+// 결과는 같은 이벤트로 보내고, 확인 자체가 실패하면 별도 이벤트로 알린다
+fn check_updates<R: Runtime>(app: &AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match fetch_update_status().await {
+            Ok(status) => emit(&app, "update-status", status),
+            Err(err) => emit(&app, "update-check-failed", err.to_string()),
+        }
+    });
+}
+
+fn history_step<R: Runtime>(app: &AppHandle<R>, step: fn(&AppHandle<R>) -> Result<bool, String>) {
+    if let Err(err) = step(app) {
+        log::warn!("undo/redo failed: {err}");
+    }
+}
+
+fn close_focused_window<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = focused_window(app) {
+        if let Err(err) = window.close() {
+            log::error!("failed to close window {}: {err}", window.label());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_view_menu_id_resolves_to_a_route() {
+        for id in VIEW_MENU_IDS {
+            let route = NavigationTarget::from(id).to_route();
+            assert!(!route.is_empty(), "{id} resolved to an empty route");
+            assert_eq!(route, id);
+        }
+    }
+
+    #[test]
+    fn unknown_id_falls_back_to_dashboard() {
+        assert_eq!(NavigationTarget::from("nope"), NavigationTarget::Dashboard);
+    }
+}
@@ -1 +1,18 @@
+pub mod archive;
+pub mod crash;
+pub mod git;
+pub mod hash;
+pub mod locale;
+pub mod logging;
 pub mod menu;
+pub mod menu_events;
+pub mod onnx;
+pub mod pipeline;
+pub mod preferences;
+pub mod project;
+pub mod recent;
+pub mod run;
+pub mod schedule;
+pub mod share;
+pub mod shortcuts;
+pub mod window_state;
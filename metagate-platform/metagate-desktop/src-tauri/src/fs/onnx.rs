@@ -0,0 +1,283 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::Serialize;
+
+// ONNX 파일에서 모델 비교에 필요한 부분만 읽는 최소한의 protobuf 파서.
+// 필드 번호는 onnx.proto 기준이다
+const MODEL_GRAPH: u32 = 7;
+const GRAPH_NODE: u32 = 1;
+const GRAPH_INITIALIZER: u32 = 5;
+const GRAPH_INPUT: u32 = 11;
+const GRAPH_OUTPUT: u32 = 12;
+const NODE_OP_TYPE: u32 = 4;
+const TENSOR_DIMS: u32 = 1;
+const TENSOR_NAME: u32 = 8;
+const VALUE_INFO_NAME: u32 = 1;
+const VALUE_INFO_TYPE: u32 = 2;
+const TYPE_TENSOR: u32 = 1;
+const TENSOR_TYPE_SHAPE: u32 = 2;
+const SHAPE_DIM: u32 = 1;
+const DIM_VALUE: u32 = 1;
+const DIM_PARAM: u32 = 2;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TensorShape {
+    pub name: String,
+    // 숫자가 아니면 "batch" 같은 심볼 이름, 알 수 없으면 "?"
+    pub dims: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnnxGraph {
+    pub node_count: usize,
+    pub inputs: Vec<TensorShape>,
+    pub outputs: Vec<TensorShape>,
+    // Conv, Relu 같은 op 이름별 노드 수
+    pub op_counts: BTreeMap<String, usize>,
+    // initializer 텐서 원소 수의 합
+    pub parameter_count: u64,
+}
+
+enum Value<'a> {
+    Varint(u64),
+    Bytes(&'a [u8]),
+    Fixed,
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    fn varint(&mut self) -> Result<u64, String> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.buf.get(self.pos).ok_or("truncated varint")?;
+            self.pos += 1;
+            value |= u64::from(byte & 0x7f) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("varint is too long".into())
+    }
+
+    fn skip(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.buf.len());
+        let end = end.ok_or("truncated field")?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn next_field(&mut self) -> Result<Option<(u32, Value<'a>)>, String> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+        let key = self.varint()?;
+        let field = (key >> 3) as u32;
+        let value = match key & 0x7 {
+            0 => Value::Varint(self.varint()?),
+            1 => {
+                self.skip(8)?;
+                Value::Fixed
+            }
+            2 => {
+                let len = self.varint()? as usize;
+                Value::Bytes(self.skip(len)?)
+            }
+            5 => {
+                self.skip(4)?;
+                Value::Fixed
+            }
+            wire => return Err(format!("unsupported wire type {wire}")),
+        };
+        Ok(Some((field, value)))
+    }
+
+    // 원하는 필드의 바이트만 골라서 넘긴다. 나머지는 건너뛴다
+    fn for_each_bytes(
+        mut self,
+        mut f: impl FnMut(u32, &'a [u8]) -> Result<(), String>,
+    ) -> Result<(), String> {
+        while let Some((field, value)) = self.next_field()? {
+            if let Value::Bytes(bytes) = value {
+                f(field, bytes)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn parse_graph(model: &[u8]) -> Result<OnnxGraph, String> {
+    let mut graph = None;
+    Reader::new(model).for_each_bytes(|field, bytes| {
+        if field == MODEL_GRAPH {
+            graph = Some(bytes);
+        }
+        Ok(())
+    })?;
+    let graph = graph.ok_or("model has no graph")?;
+
+    let mut parsed = OnnxGraph::default();
+    let mut initializers = HashSet::new();
+    let mut inputs = Vec::new();
+    Reader::new(graph).for_each_bytes(|field, bytes| {
+        match field {
+            GRAPH_NODE => {
+                parsed.node_count += 1;
+                let mut op_type = String::new();
+                Reader::new(bytes).for_each_bytes(|field, bytes| {
+                    if field == NODE_OP_TYPE {
+                        op_type = String::from_utf8_lossy(bytes).into_owned();
+                    }
+                    Ok(())
+                })?;
+                *parsed.op_counts.entry(op_type).or_default() += 1;
+            }
+            GRAPH_INITIALIZER => {
+                let (name, elements) = initializer(bytes)?;
+                initializers.insert(name);
+                parsed.parameter_count = parsed.parameter_count.saturating_add(elements);
+            }
+            GRAPH_INPUT => inputs.push(value_info(bytes)?),
+            GRAPH_OUTPUT => parsed.outputs.push(value_info(bytes)?),
+            _ => {}
+        }
+        Ok(())
+    })?;
+    // 예전 버전 ONNX는 가중치도 입력 목록에 넣으므로 빼고 본다
+    parsed.inputs = inputs
+        .into_iter()
+        .filter(|input| !initializers.contains(&input.name))
+        .collect();
+    Ok(parsed)
+}
+
+// 이름과 원소 수. dims가 없으면 스칼라라서 1개다
+fn initializer(bytes: &[u8]) -> Result<(String, u64), String> {
+    let mut reader = Reader::new(bytes);
+    let mut name = String::new();
+    let mut elements = 1u64;
+    let mut dim = |value: u64| elements = elements.saturating_mul(value);
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (TENSOR_DIMS, Value::Varint(value)) => dim(value),
+            // packed로 쓰는 도구도 있다
+            (TENSOR_DIMS, Value::Bytes(packed)) => {
+                let mut packed = Reader::new(packed);
+                while packed.pos < packed.buf.len() {
+                    dim(packed.varint()?);
+                }
+            }
+            (TENSOR_NAME, Value::Bytes(bytes)) => {
+                name = String::from_utf8_lossy(bytes).into_owned();
+            }
+            _ => {}
+        }
+    }
+    Ok((name, elements))
+}
+
+fn value_info(bytes: &[u8]) -> Result<TensorShape, String> {
+    let mut shape = TensorShape {
+        name: String::new(),
+        dims: Vec::new(),
+    };
+    Reader::new(bytes).for_each_bytes(|field, bytes| {
+        match field {
+            VALUE_INFO_NAME => shape.name = String::from_utf8_lossy(bytes).into_owned(),
+            VALUE_INFO_TYPE => shape.dims = tensor_dims(bytes)?,
+            _ => {}
+        }
+        Ok(())
+    })?;
+    Ok(shape)
+}
+
+fn tensor_dims(type_proto: &[u8]) -> Result<Vec<String>, String> {
+    let mut dims = Vec::new();
+    Reader::new(type_proto).for_each_bytes(|field, tensor| {
+        if field != TYPE_TENSOR {
+            return Ok(());
+        }
+        Reader::new(tensor).for_each_bytes(|field, shape| {
+            if field != TENSOR_TYPE_SHAPE {
+                return Ok(());
+            }
+            Reader::new(shape).for_each_bytes(|field, dim| {
+                if field == SHAPE_DIM {
+                    dims.push(dimension(dim)?);
+                }
+                Ok(())
+            })
+        })
+    })?;
+    Ok(dims)
+}
+
+fn dimension(bytes: &[u8]) -> Result<String, String> {
+    let mut reader = Reader::new(bytes);
+    let mut dim = "?".to_string();
+    while let Some((field, value)) = reader.next_field()? {
+        match (field, value) {
+            (DIM_VALUE, Value::Varint(value)) => dim = (value as i64).to_string(),
+            (DIM_PARAM, Value::Bytes(name)) => dim = String::from_utf8_lossy(name).into_owned(),
+            _ => {}
+        }
+    }
+    Ok(dim)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(number: u32, bytes: &[u8]) -> Vec<u8> {
+        let mut out = vec![((number << 3) | 2) as u8, bytes.len() as u8];
+        out.extend_from_slice(bytes);
+        out
+    }
+
+    fn value_info(name: &str, dims: &[u8]) -> Vec<u8> {
+        let shape: Vec<u8> = dims
+            .iter()
+            .flat_map(|d| field(SHAPE_DIM, &[(DIM_VALUE << 3) as u8, *d]))
+            .collect();
+        let tensor = field(TENSOR_TYPE_SHAPE, &shape);
+        let mut out = field(VALUE_INFO_NAME, name.as_bytes());
+        out.extend(field(VALUE_INFO_TYPE, &field(TYPE_TENSOR, &tensor)));
+        out
+    }
+
+    #[test]
+    fn reads_nodes_and_shapes_skipping_initializers() {
+        let mut graph = field(GRAPH_NODE, &field(NODE_OP_TYPE, b"Gemm"));
+        graph.extend(field(GRAPH_NODE, &field(NODE_OP_TYPE, b"Relu")));
+        let mut weight = vec![(TENSOR_DIMS << 3) as u8, 3, (TENSOR_DIMS << 3) as u8, 4];
+        weight.extend(field(TENSOR_NAME, b"w"));
+        graph.extend(field(GRAPH_INITIALIZER, &weight));
+        graph.extend(field(GRAPH_INPUT, &value_info("x", &[1, 3])));
+        graph.extend(field(GRAPH_INPUT, &value_info("w", &[3])));
+        graph.extend(field(GRAPH_OUTPUT, &value_info("y", &[1])));
+        let mut model = vec![0x08, 0x07];
+        model.extend(field(MODEL_GRAPH, &graph));
+
+        let parsed = parse_graph(&model).unwrap();
+        assert_eq!(parsed.node_count, 2);
+        assert_eq!(parsed.inputs.len(), 1);
+        assert_eq!(parsed.inputs[0].dims, ["1", "3"]);
+        assert_eq!(parsed.outputs[0].name, "y");
+        assert_eq!(parsed.op_counts.get("Relu"), Some(&1));
+        assert_eq!(parsed.parameter_count, 12);
+        assert!(parse_graph(&model[..model.len() - 3]).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+// pipelines/*.yaml 파일 포맷. JSON도 YAML로 읽힌다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineDefinition {
+    pub name: String,
+    #[serde(default)]
+    pub stages: Vec<Stage>,
+    // 러너에 넘기는 환경 변수 기본값. 파일에 그대로 남으니 비밀 값은 set_secret_env로 넣는다
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Stage {
+    pub name: String,
+    #[serde(default)]
+    pub run: Option<String>,
+    // 비어 있으면 바로 앞 스테이지 다음에 실행된다
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    #[serde(default)]
+    pub with: BTreeMap<String, serde_yaml::Value>,
+}
+
+impl Stage {
+    pub fn param_str(&self, key: &str) -> Option<&str> {
+        self.with.get(key).and_then(serde_yaml::Value::as_str)
+    }
+}
+
+#[derive(Debug)]
+pub enum DefinitionError {
+    Io(PathBuf, io::Error),
+    Malformed(PathBuf, serde_yaml::Error),
+}
+
+impl fmt::Display for DefinitionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DefinitionError::Io(path, err) => write!(f, "failed to read {}: {err}", path.display()),
+            DefinitionError::Malformed(path, err) => {
+                write!(f, "malformed pipeline definition {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for DefinitionError {}
+
+impl PipelineDefinition {
+    pub fn load(path: &Path) -> Result<Self, DefinitionError> {
+        let raw = fs::read_to_string(path).map_err(|e| DefinitionError::Io(path.into(), e))?;
+        serde_yaml::from_str(&raw).map_err(|e| DefinitionError::Malformed(path.into(), e))
+    }
+}
+
+// 구조체로 읽었다 쓰면 모르는 키(에디터가 붙인 배치 정보 등)가 빠지므로 YAML 값 그대로 복사한다.
+// 에디터가 스테이지에 붙인 id가 있으면 새로 발급해서 원본과 엮이지 않게 한다
+pub fn clone_definition(source: &Path, dest: &Path, name: &str) -> Result<(), DefinitionError> {
+    let raw = fs::read_to_string(source).map_err(|e| DefinitionError::Io(source.into(), e))?;
+    let mut value: serde_yaml::Value =
+        serde_yaml::from_str(&raw).map_err(|e| DefinitionError::Malformed(source.into(), e))?;
+    serde_yaml::from_value::<PipelineDefinition>(value.clone())
+        .map_err(|e| DefinitionError::Malformed(source.into(), e))?;
+
+    if let Some(map) = value.as_mapping_mut() {
+        map.insert("name".into(), name.into());
+    }
+    let stages = value
+        .get_mut("stages")
+        .and_then(serde_yaml::Value::as_sequence_mut);
+    for stage in stages.into_iter().flatten() {
+        if let Some(id) = stage.get_mut("id") {
+            *id = uuid::Uuid::new_v4().to_string().into();
+        }
+    }
+
+    let out =
+        serde_yaml::to_string(&value).map_err(|e| DefinitionError::Malformed(dest.into(), e))?;
+    fs::write(dest, out).map_err(|e| DefinitionError::Io(dest.into(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn clone_renames_and_reissues_stage_ids() {
+        let dir = TempDir::new("clone");
+        let source = dir.join("a.yaml");
+        let dest = dir.join("b.yaml");
+        fs::write(
+            &source,
+            "name: a\nlayout: { zoom: 2 }\nstages:\n  - { id: s1, name: prep, run: split }\n",
+        )
+        .unwrap();
+
+        clone_definition(&source, &dest, "b").unwrap();
+        let copy: serde_yaml::Value =
+            serde_yaml::from_str(&fs::read_to_string(&dest).unwrap()).unwrap();
+        let original = fs::read_to_string(&source).unwrap();
+
+        assert_eq!(copy["name"], "b");
+        assert_eq!(copy["layout"]["zoom"], 2);
+        assert_eq!(copy["stages"][0]["name"], "prep");
+        assert_ne!(copy["stages"][0]["id"], "s1");
+        assert!(original.contains("id: s1"));
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, Runtime};
+
+use super::locale::DEFAULT_LOCALE;
+use super::logging::LogLevel;
+use super::recent::MAX_RECENT;
+use crate::ops::model::ExportFormat;
+
+const PREFERENCES_FILE: &str = "preferences.json";
+pub const DEFAULT_DOCS_URL: &str = "https://github.com/Jeffr-K/metagate/wiki";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Theme {
+    Light,
+    Dark,
+    #[default]
+    System,
+}
+
+// 새 필드를 추가해도 예전 파일이 그대로 읽히도록 serde(default)를 건다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    pub theme: Theme,
+    pub default_export_format: ExportFormat,
+    pub telemetry_enabled: bool,
+    pub max_recent_projects: usize,
+    pub stop_grace_period_secs: u64,
+    pub sidebar_visible: bool,
+    pub undo_history_depth: usize,
+    pub zoom_factor: f64,
+    pub log_level: LogLevel,
+    // None이면 자동 저장하지 않는다
+    pub autosave_interval_secs: Option<u64>,
+    // 메뉴 id -> 단축키. 여기 없는 항목은 기본 단축키를 쓴다
+    pub accelerators: HashMap<String, String>,
+    // 백그라운드에 있을 때 학습/파이프라인/배포 결과를 OS 알림으로 보낸다
+    pub notifications_enabled: bool,
+    // 자체 호스팅하는 문서를 쓸 때 바꾼다
+    pub docs_url: String,
+    // 이보다 오래 걸린 커맨드는 경고 로그를 남긴다. 0이면 끈다
+    pub slow_command_ms: u64,
+    // 동시에 돌리는 파이프라인과 학습 수. 0이면 제한하지 않는다
+    pub max_concurrent_jobs: usize,
+    // 한도가 찼을 때 false면 새 실행을 거절한다
+    pub queue_jobs_when_busy: bool,
+    // 메뉴 언어. locales/에 파일이 있는 코드만 받는다
+    pub locale: String,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Preferences {
+            theme: Theme::default(),
+            default_export_format: ExportFormat::Onnx,
+            telemetry_enabled: false,
+            max_recent_projects: MAX_RECENT,
+            stop_grace_period_secs: 10,
+            sidebar_visible: true,
+            undo_history_depth: 100,
+            zoom_factor: 1.0,
+            log_level: LogLevel::default(),
+            autosave_interval_secs: None,
+            accelerators: HashMap::new(),
+            notifications_enabled: true,
+            docs_url: DEFAULT_DOCS_URL.into(),
+            slow_command_ms: 500,
+            max_concurrent_jobs: 0,
+            queue_jobs_when_busy: true,
+            locale: DEFAULT_LOCALE.into(),
+        }
+    }
+}
+
+impl Preferences {
+    // 파일이 없거나 깨졌으면 기본값을 쓴다
+    pub fn load<R: Runtime, M: Manager<R>>(app: &M) -> Self {
+        let Some(file) = preferences_file(app) else {
+            return Self::default();
+        };
+        fs::read_to_string(file)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save<R: Runtime, M: Manager<R>>(&self, app: &M) -> io::Result<()> {
+        let file = preferences_file(app).ok_or_else(|| io::Error::other("no app config dir"))?;
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(file, serde_json::to_vec_pretty(self)?)
+    }
+}
+
+fn preferences_file<R: Runtime, M: Manager<R>>(app: &M) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(PREFERENCES_FILE))
+}
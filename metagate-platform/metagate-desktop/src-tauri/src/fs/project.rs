@@ -0,0 +1,354 @@
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::ops::dataset::DatasetFileStats;
+
+pub const MANIFEST_FILE: &str = "metagate.json";
+pub const RUNS_DIR: &str = "runs";
+// 묶어서 치운 실행 기록(.tar.gz)
+pub const ARCHIVES_DIR: &str = "archives";
+pub const PROJECT_DIRS: [&str; 3] = ["models", "datasets", "pipelines"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelFormat {
+    Onnx,
+    TorchScript,
+    SafeTensors,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRef {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    pub format: ModelFormat,
+    pub size_bytes: u64,
+    pub imported_at: DateTime<Utc>,
+    // 해시 기능 전에 가져온 모델은 None
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    // create_model_version으로 만든 버전이면 원본 모델 id
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DatasetRef {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    // list_datasets만 채운다
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub stats: Option<DatasetFileStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRef {
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+// 태그를 붙일 수 있는 항목
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AssetKind {
+    Model,
+    Dataset,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProjectTemplate {
+    Blank,
+    Classification,
+    Nlp,
+    // 시작용 저장소를 받아 온다. reference는 브랜치, 태그, 커밋 중 하나.
+    // 보통은 새 프로젝트를 원하므로 keep_git을 켜지 않으면 .git을 지운다
+    FromGit {
+        url: String,
+        #[serde(default)]
+        reference: Option<String>,
+        #[serde(default)]
+        keep_git: bool,
+    },
+}
+
+impl ProjectTemplate {
+    // 템플릿마다 시작용 파이프라인 정의를 하나씩 깔아 둔다: (id, 이름, 파일 경로, 내용)
+    pub fn pipelines(&self) -> &'static [(&'static str, &'static str, &'static str, &'static str)] {
+        match self {
+            ProjectTemplate::Blank | ProjectTemplate::FromGit { .. } => &[],
+            ProjectTemplate::Classification => &[(
+                "train-classifier",
+                "Train Classifier",
+                "pipelines/train-classifier.yaml",
+                include_str!("templates/classification.yaml"),
+            )],
+            ProjectTemplate::Nlp => &[(
+                "fine-tune-text",
+                "Fine-tune Text Model",
+                "pipelines/fine-tune-text.yaml",
+                include_str!("templates/nlp.yaml"),
+            )],
+        }
+    }
+}
+
+// metagate.json 파일 포맷
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    // 매니페스트가 있는 디렉토리. 파일에는 저장하지 않는다
+    #[serde(skip)]
+    pub root: PathBuf,
+    pub name: String,
+    #[serde(default = "default_version")]
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub models: Vec<ModelRef>,
+    #[serde(default)]
+    pub datasets: Vec<DatasetRef>,
+    #[serde(default)]
+    pub pipelines: Vec<PipelineRef>,
+}
+
+// 실행 기록(로그, 메트릭)은 runs/<run_id>/ 아래에 모인다
+pub fn run_dir(root: &Path, run_id: &str) -> PathBuf {
+    root.join(RUNS_DIR).join(run_id)
+}
+
+fn default_version() -> String {
+    "0.1.0".into()
+}
+
+#[derive(Debug)]
+pub enum ProjectError {
+    Io(PathBuf, io::Error),
+    Malformed(PathBuf, serde_json::Error),
+    Clone(String, String),
+}
+
+impl fmt::Display for ProjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProjectError::Io(path, err) => write!(f, "failed to access {}: {err}", path.display()),
+            ProjectError::Malformed(path, err) => {
+                write!(f, "malformed project manifest {}: {err}", path.display())
+            }
+            ProjectError::Clone(url, err) => write!(f, "failed to clone template {url}: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ProjectError {}
+
+impl Project {
+    pub fn tags(&self, kind: AssetKind, id: &str) -> Option<&Vec<String>> {
+        match kind {
+            AssetKind::Model => self.models.iter().find(|m| m.id == id).map(|m| &m.tags),
+            AssetKind::Dataset => self.datasets.iter().find(|d| d.id == id).map(|d| &d.tags),
+        }
+    }
+
+    pub fn tags_mut(&mut self, kind: AssetKind, id: &str) -> Option<&mut Vec<String>> {
+        match kind {
+            AssetKind::Model => self
+                .models
+                .iter_mut()
+                .find(|m| m.id == id)
+                .map(|m| &mut m.tags),
+            AssetKind::Dataset => self
+                .datasets
+                .iter_mut()
+                .find(|d| d.id == id)
+                .map(|d| &mut d.tags),
+        }
+    }
+
+    pub fn new(root: &Path, name: &str) -> Self {
+        Project {
+            root: root.to_path_buf(),
+            name: name.into(),
+            version: default_version(),
+            created_at: Utc::now(),
+            models: Vec::new(),
+            datasets: Vec::new(),
+            pipelines: Vec::new(),
+        }
+    }
+
+    // 디렉토리 구조를 만들고 템플릿 파일과 매니페스트를 쓴다
+    pub fn create(
+        root: &Path,
+        name: &str,
+        template: ProjectTemplate,
+    ) -> Result<Self, ProjectError> {
+        if let ProjectTemplate::FromGit {
+            url,
+            reference,
+            keep_git,
+        } = &template
+        {
+            return Project::create_from_git(root, name, url, reference.as_deref(), *keep_git);
+        }
+        let io_err = |path: &Path, e| ProjectError::Io(path.into(), e);
+        let mut project = Project::new(root, name);
+
+        for dir in PROJECT_DIRS {
+            let dir = root.join(dir);
+            fs::create_dir_all(&dir).map_err(|e| io_err(&dir, e))?;
+        }
+        for (id, name, path, contents) in template.pipelines() {
+            let file = root.join(path);
+            fs::write(&file, contents).map_err(|e| io_err(&file, e))?;
+            project.pipelines.push(PipelineRef {
+                id: (*id).into(),
+                name: (*name).into(),
+                path: PathBuf::from(path),
+                tags: Vec::new(),
+            });
+        }
+
+        project.save(&project.manifest_path())?;
+        Ok(project)
+    }
+
+    // 받아 온 저장소에 매니페스트가 있으면 이름만 바꿔 쓰고, 없으면 새로 만든다.
+    // 중간에 실패하면 만들던 디렉토리를 지운다. 원래 있던 빈 디렉토리는 다시 비워 둔다
+    fn create_from_git(
+        root: &Path,
+        name: &str,
+        url: &str,
+        reference: Option<&str>,
+        keep_git: bool,
+    ) -> Result<Self, ProjectError> {
+        let existed = root.exists();
+        let result = (|| {
+            // git은 이미 있는 빈 디렉토리에도 받아 준다
+            crate::fs::git::clone(url, reference, root)
+                .map_err(|e| ProjectError::Clone(url.into(), e))?;
+            let io_err = |path: &Path, e| ProjectError::Io(path.into(), e);
+            if !keep_git {
+                let git_dir = root.join(".git");
+                fs::remove_dir_all(&git_dir).map_err(|e| io_err(&git_dir, e))?;
+            }
+            for dir in PROJECT_DIRS {
+                let dir = root.join(dir);
+                fs::create_dir_all(&dir).map_err(|e| io_err(&dir, e))?;
+            }
+
+            let manifest = root.join(MANIFEST_FILE);
+            let mut project = if manifest.exists() {
+                Project::load(&manifest)?
+            } else {
+                Project::new(root, name)
+            };
+            project.name = name.into();
+            project.created_at = Utc::now();
+            project.save(&manifest)?;
+            Ok(project)
+        })();
+        if result.is_err() {
+            let _ = fs::remove_dir_all(root);
+            if existed {
+                let _ = fs::create_dir_all(root);
+            }
+        }
+        result
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ProjectError> {
+        let raw = fs::read_to_string(path).map_err(|e| ProjectError::Io(path.into(), e))?;
+        let mut project: Project =
+            serde_json::from_str(&raw).map_err(|e| ProjectError::Malformed(path.into(), e))?;
+        project.root = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(project)
+    }
+
+    pub fn manifest_path(&self) -> PathBuf {
+        self.root.join(MANIFEST_FILE)
+    }
+
+    // 임시 파일에 쓴 뒤 rename 해서 중간에 죽어도 기존 파일이 깨지지 않게 한다
+    pub fn save(&self, path: &Path) -> Result<(), ProjectError> {
+        let io_err = |e| ProjectError::Io(path.into(), e);
+        let json =
+            serde_json::to_vec_pretty(self).map_err(|e| ProjectError::Malformed(path.into(), e))?;
+
+        let tmp = path.with_extension("json.tmp");
+        let mut file = File::create(&tmp).map_err(io_err)?;
+        file.write_all(&json).map_err(io_err)?;
+        file.sync_all().map_err(io_err)?;
+        drop(file);
+
+        fs::rename(&tmp, path).map_err(io_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use std::process::Command;
+
+    #[test]
+    fn git_template_is_renamed_and_detached_or_cleaned_up() {
+        let base = TempDir::new("git");
+        let upstream = base.join("starter");
+        fs::create_dir_all(&upstream).unwrap();
+        Project::new(&upstream, "starter")
+            .save(&upstream.join(MANIFEST_FILE))
+            .unwrap();
+        let git = |args: &[&str]| {
+            let status = Command::new("git")
+                .arg("-C")
+                .arg(&upstream)
+                .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+                .args(args)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success());
+        };
+        git(&["init", "--quiet"]);
+        git(&["add", "."]);
+        git(&["commit", "--quiet", "-m", "init"]);
+
+        let template = |url: &Path| ProjectTemplate::FromGit {
+            url: url.display().to_string(),
+            reference: None,
+            keep_git: false,
+        };
+        let root = base.join("mine");
+        let project = Project::create(&root, "mine", template(&upstream)).unwrap();
+        let saved = Project::load(&root.join(MANIFEST_FILE)).unwrap();
+        let has_git = root.join(".git").exists();
+        let has_dirs = PROJECT_DIRS.iter().all(|dir| root.join(dir).is_dir());
+
+        let broken = base.join("broken");
+        let failed = Project::create(&broken, "broken", template(&base.join("missing")));
+        let left_behind = broken.exists();
+
+        assert_eq!(project.name, "mine");
+        assert_eq!(saved.name, "mine");
+        assert!(!has_git);
+        assert!(has_dirs);
+        assert!(matches!(failed, Err(ProjectError::Clone(..))));
+        assert!(!left_behind);
+    }
+}
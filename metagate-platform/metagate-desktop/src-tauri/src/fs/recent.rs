@@ -0,0 +1,57 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, Runtime};
+
+const RECENT_FILE: &str = "recent_projects.json";
+pub const MAX_RECENT: usize = 10;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RecentProjects {
+    paths: Vec<PathBuf>,
+}
+
+impl RecentProjects {
+    // 파일이 없거나 깨졌으면 빈 목록으로 시작한다
+    pub fn load<R: Runtime, M: Manager<R>>(app: &M) -> Self {
+        let Some(file) = recent_file(app) else {
+            return Self::default();
+        };
+        fs::read_to_string(file)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save<R: Runtime, M: Manager<R>>(&self, app: &M) -> io::Result<()> {
+        let file = recent_file(app).ok_or_else(|| io::Error::other("no app config dir"))?;
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(file, serde_json::to_vec_pretty(self)?)
+    }
+
+    pub fn push(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        self.paths.retain(|p| p != &path);
+        self.paths.insert(0, path);
+        self.paths.truncate(MAX_RECENT);
+    }
+
+    pub fn list(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    pub fn clear(&mut self) {
+        self.paths.clear();
+    }
+}
+
+fn recent_file<R: Runtime, M: Manager<R>>(app: &M) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(RECENT_FILE))
+}
@@ -0,0 +1,185 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+// runs/<run_id>/ 아래 파일들
+pub const RUN_FILE: &str = "run.json";
+pub const METRICS_FILE: &str = "metrics.jsonl";
+// 러너가 결과물을 남기는 폴더. 하위 폴더 구조는 러너 마음대로다
+pub const ARTIFACTS_DIR: &str = "artifacts";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunKind {
+    Pipeline,
+    Training,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunStatus {
+    Running,
+    // 학습을 잠시 멈춘 상태. 프로세스는 살아 있다
+    Paused,
+    Succeeded,
+    Failed,
+    Stopped,
+    // Running으로 남아 있는데 추적 중이 아닌 경우. 앱이 죽었거나 강제 종료된 것
+    Interrupted,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub run_id: String,
+    pub kind: RunKind,
+    // 파이프라인 id 또는 학습한 모델 id
+    pub subject: String,
+    pub status: RunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub exit_code: Option<i32>,
+    // 시작할 때의 설정을 복사해 둔다. 나중에 파일이 바뀌어도 보고서는 실행 당시 값을 보여준다
+    #[serde(default)]
+    pub config: Option<serde_json::Value>,
+}
+
+impl RunRecord {
+    pub fn start(run_id: &str, kind: RunKind, subject: &str) -> Self {
+        RunRecord {
+            run_id: run_id.into(),
+            kind,
+            subject: subject.into(),
+            status: RunStatus::Running,
+            started_at: Utc::now(),
+            finished_at: None,
+            exit_code: None,
+            config: None,
+        }
+    }
+
+    pub fn with_config(mut self, config: &impl Serialize) -> Self {
+        self.config = serde_json::to_value(config).ok();
+        self
+    }
+
+    pub fn load(dir: &Path) -> Option<Self> {
+        let raw = fs::read_to_string(dir.join(RUN_FILE)).ok()?;
+        serde_json::from_str(&raw).ok()
+    }
+
+    pub fn save(&self, dir: &Path) -> io::Result<()> {
+        fs::write(dir.join(RUN_FILE), serde_json::to_vec_pretty(self)?)
+    }
+}
+
+// 실행 기록 파일은 부가 정보라서 쓰기 실패가 실행 자체를 막지는 않게 로그만 남긴다
+pub fn finish_run(dir: &Path, status: RunStatus, exit_code: Option<i32>) {
+    let Some(mut record) = RunRecord::load(dir) else {
+        log::warn!("no run record in {}", dir.display());
+        return;
+    };
+    record.status = status;
+    record.exit_code = exit_code;
+    record.finished_at = Some(Utc::now());
+    if let Err(err) = record.save(dir) {
+        log::warn!("failed to save run record in {}: {err}", dir.display());
+    }
+}
+
+// 끝나지 않은 실행의 상태만 바꾼다
+pub fn set_run_status(dir: &Path, status: RunStatus) {
+    let Some(mut record) = RunRecord::load(dir) else {
+        log::warn!("no run record in {}", dir.display());
+        return;
+    };
+    record.status = status;
+    if let Err(err) = record.save(dir) {
+        log::warn!("failed to save run record in {}: {err}", dir.display());
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricPoint {
+    pub epoch: u32,
+    pub loss: f64,
+    pub accuracy: Option<f64>,
+    pub timestamp: DateTime<Utc>,
+}
+
+pub fn append_metric(dir: &Path, point: &MetricPoint) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(METRICS_FILE))?;
+    writeln!(file, "{}", serde_json::to_string(point)?)
+}
+
+// 트레이너가 MetricPoint에 없는 값을 더 써 넣었을 수 있어서 줄을 JSON 객체 그대로 읽는다
+pub fn read_metric_rows(dir: &Path) -> io::Result<Vec<serde_json::Map<String, serde_json::Value>>> {
+    read_json_lines(&dir.join(METRICS_FILE))
+}
+
+// 깨진 줄은 건너뛰고 읽을 수 있는 것만 돌려준다
+pub fn read_metrics(dir: &Path) -> io::Result<Vec<MetricPoint>> {
+    read_json_lines(&dir.join(METRICS_FILE))
+}
+
+// UTF-8이 아니거나 JSON으로 읽히지 않는 줄만 건너뛴다. 트레이너가 죽으면서
+// 반쯤 쓴 줄이 중간에 끼어 있어도 그 뒤의 줄은 그대로 읽는다
+fn read_json_lines<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut rows = Vec::new();
+    for line in BufReader::new(file).split(b'\n') {
+        let line = line?;
+        if let Some(row) = std::str::from_utf8(&line)
+            .ok()
+            .and_then(|line| serde_json::from_str(line).ok())
+        {
+            rows.push(row);
+        }
+    }
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn a_corrupt_line_does_not_hide_the_metrics_after_it() {
+        let dir = TempDir::new("metrics");
+        let point = |epoch| {
+            serde_json::to_string(&MetricPoint {
+                epoch,
+                loss: 0.5,
+                accuracy: None,
+                timestamp: Utc::now(),
+            })
+            .unwrap()
+        };
+        let mut raw = format!("{}\n", point(1)).into_bytes();
+        raw.extend_from_slice(b"{\"epoch\": \xff\xfe\n{\"epoch\": 2, \"loss\"\n");
+        raw.extend_from_slice(format!("{}\n", point(3)).as_bytes());
+        fs::write(dir.join(METRICS_FILE), raw).unwrap();
+
+        let epochs: Vec<u32> = read_metrics(&dir)
+            .unwrap()
+            .iter()
+            .map(|p| p.epoch)
+            .collect();
+        assert_eq!(epochs, [1, 3]);
+        let rows = read_metric_rows(&dir).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[1]["epoch"], 3);
+        assert!(read_metrics(&dir.join("missing")).unwrap().is_empty());
+    }
+}
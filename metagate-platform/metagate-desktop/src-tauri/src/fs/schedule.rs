@@ -0,0 +1,215 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use chrono::{DateTime, Local, Utc};
+use croner::Cron;
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, Runtime};
+
+const SCHEDULES_FILE: &str = "schedules.json";
+
+pub type ScheduleId = String;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Schedule {
+    Once { at: DateTime<Utc> },
+    // 분 시 일 월 요일. 초를 앞에 붙인 6자리도 받는다. 시각은 로컬 시간으로 읽는다
+    Cron { expr: String },
+}
+
+impl Schedule {
+    // after 이후의 첫 실행 시각. Once가 이미 지났으면 None
+    pub fn next_after(&self, after: DateTime<Utc>) -> Result<Option<DateTime<Utc>>, String> {
+        match self {
+            Schedule::Once { at } => Ok((*at > after).then_some(*at)),
+            Schedule::Cron { expr } => {
+                let cron = Cron::from_str(expr)
+                    .map_err(|e| format!("invalid cron expression {expr:?}: {e}"))?;
+                let next = cron
+                    .find_next_occurrence(&after.with_timezone(&Local), false)
+                    .map_err(|e| format!("cron expression {expr:?} never fires: {e}"))?;
+                Ok(Some(next.with_timezone(&Utc)))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    pub id: ScheduleId,
+    pub pipeline_id: String,
+    // 파이프라인 id는 프로젝트마다 따로라서 이 프로젝트가 열려 있을 때만 실행한다
+    pub project: PathBuf,
+    pub schedule: Schedule,
+    pub next_run: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub last_run: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScheduledRun {
+    // 한 번만 도는 예약은 프로젝트가 열릴 때까지 기다린다. 반복 예약은 다음 차례로 넘긴다
+    fn waits_for(&self, open: Option<&Path>) -> bool {
+        matches!(self.schedule, Schedule::Once { .. }) && open != Some(self.project.as_path())
+    }
+}
+
+// 앱을 다시 켜도 예약이 남도록 설정 폴더에 저장한다
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Schedules {
+    schedules: Vec<ScheduledRun>,
+}
+
+impl Schedules {
+    // 파일이 없거나 깨졌으면 빈 목록으로 시작한다
+    pub fn load<R: Runtime, M: Manager<R>>(app: &M) -> Self {
+        let Some(file) = schedules_file(app) else {
+            return Self::default();
+        };
+        fs::read_to_string(file)
+            .ok()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save<R: Runtime, M: Manager<R>>(&self, app: &M) -> io::Result<()> {
+        let file = schedules_file(app).ok_or_else(|| io::Error::other("no app config dir"))?;
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // 쓰다가 죽어도 기존 예약이 깨지지 않게 임시 파일에 쓰고 바꾼다
+        let tmp = file.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(tmp, file)
+    }
+
+    pub fn list(&self) -> &[ScheduledRun] {
+        &self.schedules
+    }
+
+    pub fn push(&mut self, run: ScheduledRun) {
+        self.schedules.push(run);
+    }
+
+    pub fn remove(&mut self, id: &str) -> Option<ScheduledRun> {
+        let index = self.schedules.iter().position(|s| s.id == id)?;
+        Some(self.schedules.remove(index))
+    }
+
+    // 지운 파이프라인의 예약을 정리할 때 쓴다
+    pub fn remove_pipeline(&mut self, project: &Path, pipeline_id: &str) {
+        self.schedules
+            .retain(|s| s.project != project || s.pipeline_id != pipeline_id);
+    }
+
+    // open은 지금 열린 프로젝트. 그 프로젝트를 기다리는 예약은 뺀다
+    pub fn next_due(&self, open: Option<&Path>) -> Option<DateTime<Utc>> {
+        self.schedules
+            .iter()
+            .filter(|s| !s.waits_for(open))
+            .filter_map(|s| s.next_run)
+            .min()
+    }
+
+    // 시각이 된 예약을 꺼내고 다음 실행 시각으로 넘긴다. 더 실행할 일이 없는 예약은 지운다.
+    // 앱이 꺼져 있는 동안 놓친 실행은 몇 번이었든 한 번만 돌린다. 프로젝트가 열려 있지 않은
+    // 한 번짜리 예약은 꺼내지 않고 남겨 둔다
+    pub fn take_due(&mut self, now: DateTime<Utc>, open: Option<&Path>) -> Vec<ScheduledRun> {
+        let mut due = Vec::new();
+        self.schedules.retain_mut(|run| {
+            if run.next_run.is_none_or(|at| at > now) || run.waits_for(open) {
+                return true;
+            }
+            due.push(run.clone());
+            run.last_run = Some(now);
+            run.next_run = run.schedule.next_after(now).unwrap_or_else(|err| {
+                log::warn!("dropping schedule {}: {err}", run.id);
+                None
+            });
+            run.next_run.is_some()
+        });
+        due
+    }
+}
+
+fn schedules_file<R: Runtime, M: Manager<R>>(app: &M) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(SCHEDULES_FILE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn scheduled(id: &str, schedule: Schedule, next_run: DateTime<Utc>) -> ScheduledRun {
+        ScheduledRun {
+            id: id.into(),
+            pipeline_id: "train".into(),
+            project: PathBuf::from("/p"),
+            schedule,
+            next_run: Some(next_run),
+            last_run: None,
+            created_at: next_run,
+        }
+    }
+
+    #[test]
+    fn due_schedules_advance_or_are_dropped() {
+        let now = Utc::now();
+        let hourly = Schedule::Cron {
+            expr: "0 * * * *".into(),
+        };
+        let mut schedules = Schedules::default();
+        schedules.push(scheduled("once", Schedule::Once { at: now }, now));
+        schedules.push(scheduled("hourly", hourly, now - Duration::hours(3)));
+        schedules.push(scheduled(
+            "later",
+            Schedule::Once {
+                at: now + Duration::hours(1),
+            },
+            now + Duration::hours(1),
+        ));
+
+        let project = Some(Path::new("/p"));
+        let due: Vec<String> = schedules
+            .take_due(now, project)
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(due, ["once", "hourly"]);
+        let ids: Vec<&str> = schedules.list().iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["hourly", "later"]);
+        let next = schedules.list()[0].next_run.unwrap();
+        assert!(next > now && next <= now + Duration::hours(1));
+        assert!(schedules.take_due(now, project).is_empty());
+
+        let bad = Schedule::Cron {
+            expr: "61 * * * *".into(),
+        };
+        assert!(bad.next_after(now).is_err());
+    }
+
+    #[test]
+    fn once_schedules_wait_for_their_project() {
+        let now = Utc::now();
+        let mut schedules = Schedules::default();
+        schedules.push(scheduled("once", Schedule::Once { at: now }, now));
+
+        assert!(schedules.take_due(now, None).is_empty());
+        assert!(schedules
+            .take_due(now, Some(Path::new("/other")))
+            .is_empty());
+        assert_eq!(schedules.next_due(None), None);
+        assert_eq!(schedules.next_due(Some(Path::new("/p"))), Some(now));
+
+        let due = schedules.take_due(now, Some(Path::new("/p")));
+        assert_eq!(due.len(), 1);
+        assert!(schedules.list().is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+// 배포 공유 토큰은 <deployment_id>.<만료 unix 초>.<nonce>.<서명> 모양이다.
+// 서명은 배포마다 만든 키로 앞의 세 부분을 HMAC-SHA256 한 것이라 추측하거나 고칠 수 없다.
+// 로컬 추론 서버는 키와 취소 목록 파일 경로를 환경 변수로 받아 verify와 같은 순서로 검사한다
+pub const KEY_ENV: &str = "METAGATE_SHARE_KEY";
+pub const REVOKED_ENV: &str = "METAGATE_SHARE_REVOKED";
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 16;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub struct ShareKey([u8; KEY_LEN]);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedToken {
+    pub deployment_id: String,
+    pub expires_at: i64,
+    pub nonce: String,
+}
+
+impl ShareKey {
+    pub fn generate() -> Result<Self, String> {
+        let mut key = [0u8; KEY_LEN];
+        getrandom::fill(&mut key).map_err(|e| format!("failed to generate a share key: {e}"))?;
+        Ok(ShareKey(key))
+    }
+
+    pub fn hex(&self) -> String {
+        hex(&self.0)
+    }
+
+    pub fn sign(&self, deployment_id: &str, expires_at: i64) -> Result<String, String> {
+        let mut nonce = [0u8; NONCE_LEN];
+        getrandom::fill(&mut nonce).map_err(|e| format!("failed to generate a token: {e}"))?;
+        let payload = format!("{deployment_id}.{expires_at}.{}", hex(&nonce));
+        let signature = hex(&self.mac(&payload).finalize().into_bytes());
+        Ok(format!("{payload}.{signature}"))
+    }
+
+    // 서명을 먼저 보고, 그다음 만료와 취소를 본다. 서명 비교는 상수 시간이다
+    pub fn verify(
+        &self,
+        token: &str,
+        now: i64,
+        revoked: &HashMap<String, i64>,
+    ) -> Result<ParsedToken, String> {
+        let parsed = parse_token(token)?;
+        let (payload, signature) = token.rsplit_once('.').ok_or(MALFORMED)?;
+        let signature = unhex(signature).ok_or(MALFORMED)?;
+        self.mac(payload)
+            .verify_slice(&signature)
+            .map_err(|_| "share token signature is invalid".to_string())?;
+        if parsed.expires_at <= now {
+            return Err("share token has expired".into());
+        }
+        if revoked.contains_key(&parsed.nonce) {
+            return Err("share token was revoked".into());
+        }
+        Ok(parsed)
+    }
+
+    fn mac(&self, payload: &str) -> HmacSha256 {
+        let mut mac = HmacSha256::new_from_slice(&self.0).expect("HMAC accepts any key length");
+        mac.update(payload.as_bytes());
+        mac
+    }
+}
+
+const MALFORMED: &str = "malformed share token";
+
+// 서명은 보지 않는다. 어느 배포의 토큰인지 찾을 때 쓴다
+pub fn parse_token(token: &str) -> Result<ParsedToken, String> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+    let [deployment_id, expires_at, nonce, _signature] = parts[..] else {
+        return Err(MALFORMED.into());
+    };
+    Ok(ParsedToken {
+        deployment_id: deployment_id.into(),
+        expires_at: expires_at.parse().map_err(|_| MALFORMED)?,
+        nonce: nonce.into(),
+    })
+}
+
+// nonce -> 만료 시각. 만료된 항목은 어차피 거절되니 쓸 때 뺀다
+pub fn save_revoked(path: &Path, revoked: &mut HashMap<String, i64>, now: i64) -> io::Result<()> {
+    revoked.retain(|_, expires_at| *expires_at > now);
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, serde_json::to_vec(revoked)?)?;
+    fs::rename(tmp, path)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn unhex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(text.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_expire_can_be_revoked_and_resist_tampering() {
+        let key = ShareKey::generate().unwrap();
+        let token = key.sign("dep-1", 1_000).unwrap();
+        let mut revoked = HashMap::new();
+
+        let parsed = key.verify(&token, 999, &revoked).unwrap();
+        assert_eq!(parsed.deployment_id, "dep-1");
+        assert_ne!(token, key.sign("dep-1", 1_000).unwrap());
+        assert_eq!(
+            key.verify(&token, 1_000, &revoked),
+            Err("share token has expired".into())
+        );
+
+        let forged = token.replacen(".1000.", ".9999.", 1);
+        assert_eq!(
+            key.verify(&forged, 999, &revoked),
+            Err("share token signature is invalid".into())
+        );
+        let other = ShareKey::generate().unwrap();
+        assert!(other.verify(&token, 999, &revoked).is_err());
+
+        revoked.insert(parsed.nonce, parsed.expires_at);
+        assert_eq!(
+            key.verify(&token, 999, &revoked),
+            Err("share token was revoked".into())
+        );
+        assert_eq!(parse_token("nope"), Err(MALFORMED.into()));
+    }
+}
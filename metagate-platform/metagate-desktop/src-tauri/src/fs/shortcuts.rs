@@ -0,0 +1,361 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+use muda::accelerator::Accelerator;
+use serde::Serialize;
+use tauri::{Manager, Runtime};
+
+use super::locale::{app_locale, t, DEFAULT_LOCALE};
+use crate::ops::view::{
+    FULL_SCREEN_ITEM, SIDEBAR_ITEM, ZOOM_IN_ITEM, ZOOM_OUT_ITEM, ZOOM_RESET_ITEM,
+};
+use crate::ops::window::NEW_WINDOW_ITEM;
+
+const IS_MACOS: bool = cfg!(target_os = "macos");
+const IS_WINDOWS: bool = cfg!(target_os = "windows");
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ShortcutEntry {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub accelerator: String,
+}
+
+// 라벨은 locales/en.json에서 가져온다. 앱에서 쓸 때는 app_shortcuts가 설정한 언어로 바꾼다
+fn entry(id: &'static str, accelerator: &str) -> ShortcutEntry {
+    ShortcutEntry {
+        id,
+        label: t(id, DEFAULT_LOCALE),
+        accelerator: accelerator.into(),
+    }
+}
+
+// 사용자가 바꾼 단축키(메뉴 id -> 단축키). 시작할 때 환경설정에서 읽어 오고
+// 메뉴를 다시 만들 때마다 파일을 읽지 않도록 여기 들고 있는다
+#[derive(Default)]
+pub struct AcceleratorOverrides(Mutex<HashMap<String, String>>);
+
+impl AcceleratorOverrides {
+    pub fn new(overrides: HashMap<String, String>) -> Self {
+        AcceleratorOverrides(Mutex::new(overrides))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, String>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn get(&self) -> HashMap<String, String> {
+        self.lock().clone()
+    }
+
+    pub fn set(&self, overrides: HashMap<String, String>) {
+        *self.lock() = overrides;
+    }
+}
+
+// 메뉴 단축키의 기본값. 메뉴를 만들 때도, 단축키 창에 보여줄 때도 이 목록에 덮어쓴 값을 얹어서 쓴다
+pub fn shortcuts() -> Vec<ShortcutEntry> {
+    vec![
+        entry("new_project", "CmdOrCtrl+N"),
+        entry("open_project", "CmdOrCtrl+O"),
+        entry("save_project", "CmdOrCtrl+S"),
+        entry("save_project_as", "CmdOrCtrl+Shift+S"),
+        entry("close_window", "CmdOrCtrl+W"),
+        entry("preferences", "CmdOrCtrl+,"),
+        if IS_WINDOWS {
+            entry("quit", "Alt+F4")
+        } else {
+            entry("quit", "CmdOrCtrl+Q")
+        },
+        entry("undo", "CmdOrCtrl+Z"),
+        if IS_WINDOWS {
+            entry("redo", "Ctrl+Y")
+        } else {
+            entry("redo", "CmdOrCtrl+Shift+Z")
+        },
+        entry("cut", "CmdOrCtrl+X"),
+        entry("copy", "CmdOrCtrl+C"),
+        entry("paste", "CmdOrCtrl+V"),
+        entry("select_all", "CmdOrCtrl+A"),
+        entry("dashboard", "CmdOrCtrl+1"),
+        entry("models", "CmdOrCtrl+2"),
+        entry("datasets", "CmdOrCtrl+3"),
+        entry("pipelines", "CmdOrCtrl+4"),
+        entry("monitoring", "CmdOrCtrl+5"),
+        entry(SIDEBAR_ITEM, "CmdOrCtrl+B"),
+        if IS_MACOS {
+            entry(FULL_SCREEN_ITEM, "Ctrl+Cmd+F")
+        } else {
+            entry(FULL_SCREEN_ITEM, "F11")
+        },
+        entry("train_model", "CmdOrCtrl+T"),
+        entry("deploy_model", "CmdOrCtrl+D"),
+        entry("run_pipeline", "CmdOrCtrl+R"),
+        entry("stop_pipeline", "CmdOrCtrl+."),
+        entry("view_logs", "CmdOrCtrl+L"),
+        entry(NEW_WINDOW_ITEM, "CmdOrCtrl+Shift+N"),
+        entry("minimize", "CmdOrCtrl+M"),
+        entry(ZOOM_IN_ITEM, "CmdOrCtrl+="),
+        entry(ZOOM_OUT_ITEM, "CmdOrCtrl+-"),
+        entry(ZOOM_RESET_ITEM, "CmdOrCtrl+0"),
+    ]
+}
+
+pub fn shortcuts_with(overrides: &HashMap<String, String>) -> Vec<ShortcutEntry> {
+    let mut entries = shortcuts();
+    for entry in &mut entries {
+        if let Some(accelerator) = overrides.get(entry.id) {
+            entry.accelerator = accelerator.clone();
+        }
+    }
+    entries
+}
+
+// 테스트용 mock 앱처럼 상태가 없으면 기본값을 쓴다
+pub fn app_shortcuts<R: Runtime, M: Manager<R>>(app: &M) -> Vec<ShortcutEntry> {
+    let overrides = app
+        .try_state::<AcceleratorOverrides>()
+        .map(|state| state.get())
+        .unwrap_or_default();
+    let locale = app_locale(app);
+    let mut entries = shortcuts_with(&overrides);
+    for entry in &mut entries {
+        entry.label = t(entry.id, &locale);
+    }
+    entries
+}
+
+pub fn app_shortcut<R: Runtime, M: Manager<R>>(app: &M, id: &str) -> Option<ShortcutEntry> {
+    app_shortcuts(app).into_iter().find(|entry| entry.id == id)
+}
+
+// muda가 받는 수식키 이름. 대소문자는 가리지 않는다
+const MODIFIERS: [&str; 12] = [
+    "alt",
+    "option",
+    "ctrl",
+    "control",
+    "shift",
+    "cmd",
+    "command",
+    "super",
+    "meta",
+    "cmdorctrl",
+    "cmdorcontrol",
+    "commandorcontrol",
+];
+
+// 단축키 편집기에서 입력하는 동안 바로 알려 줄 수 있게 실패 이유를 하나씩 따로 말한다.
+// muda의 파싱 에러는 대부분 InvalidFormat 하나로 뭉뚱그려져 있다
+pub fn validate_accelerator(accelerator: &str) -> Result<(), String> {
+    let accelerator = accelerator.trim();
+    if accelerator.is_empty() {
+        return Err("shortcut is empty".into());
+    }
+    // + 자체는 키로 쓸 수 없다. 끝이 +면 아직 키를 누르지 않은 것이다
+    if accelerator.ends_with('+') {
+        return Err(format!("{accelerator} is missing a key after the last +"));
+    }
+    let (modifiers, key) = match accelerator.rsplit_once('+') {
+        Some((modifiers, key)) => (modifiers, key.trim()),
+        None => ("", accelerator),
+    };
+
+    let mut seen = Vec::new();
+    for token in modifiers.split('+').filter(|_| !modifiers.is_empty()) {
+        let token = token.trim().to_ascii_lowercase();
+        if token.is_empty() {
+            return Err(format!("{accelerator} has an empty modifier between two +"));
+        }
+        if !MODIFIERS.contains(&token.as_str()) {
+            return Err(format!(
+                "unknown modifier {token}; use Ctrl, Alt, Shift, Cmd, Super or CmdOrCtrl"
+            ));
+        }
+        if seen.contains(&token) {
+            return Err(format!("modifier {token} is repeated"));
+        }
+        seen.push(token);
+    }
+    if MODIFIERS.contains(&key.to_ascii_lowercase().as_str()) {
+        return Err(format!("{accelerator} has only modifiers; add a key"));
+    }
+    accelerator
+        .parse::<Accelerator>()
+        .map(|_| ())
+        .map_err(|_| format!("unknown key {key}"))
+}
+
+// 덮어쓴 결과에 id의 단축키를 바꿔 넣었을 때 문제가 없으면 새 덮어쓰기 목록을 돌려준다.
+// 기본값과 같아지면 덮어쓰기에서 뺀다
+pub fn remap(
+    overrides: &HashMap<String, String>,
+    id: &str,
+    accelerator: &str,
+) -> Result<HashMap<String, String>, String> {
+    let accelerator = accelerator.trim();
+    let default = shortcuts()
+        .into_iter()
+        .find(|entry| entry.id == id)
+        .ok_or_else(|| format!("{id} has no keyboard shortcut"))?;
+    validate_accelerator(accelerator)?;
+    let parsed: Accelerator = accelerator
+        .parse()
+        .map_err(|e| format!("invalid shortcut {accelerator}: {e}"))?;
+
+    let conflict = shortcuts_with(overrides).into_iter().find(|entry| {
+        entry.id != id && entry.accelerator.parse::<Accelerator>().ok() == Some(parsed)
+    });
+    if let Some(other) = conflict {
+        return Err(format!(
+            "{accelerator} is already used by {}",
+            other.label.trim_end_matches("...")
+        ));
+    }
+
+    let mut overrides = overrides.clone();
+    if default.accelerator.parse::<Accelerator>().ok() == Some(parsed) {
+        overrides.remove(id);
+    } else {
+        overrides.insert(id.to_string(), accelerator.to_string());
+    }
+    Ok(overrides)
+}
+
+// 환경설정 창에서 통째로 저장할 때 쓴다. 하나씩 remap에 넣어서 문법과 겹침을 같은 규칙으로 본다
+pub fn remap_all(overrides: &HashMap<String, String>) -> Result<HashMap<String, String>, String> {
+    let mut ids: Vec<&String> = overrides.keys().collect();
+    ids.sort();
+    let mut checked = overrides.clone();
+    for id in ids {
+        checked = remap(&checked, id, &overrides[id])?;
+    }
+    Ok(checked)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::fs::menu::create_menu;
+    use tauri::menu::MenuItemKind;
+    use tauri::Runtime;
+
+    // CmdOrCtrl은 플랫폼마다 실제 키가 달라서 풀어서 비교한다
+    fn normalize(accelerator: &str) -> String {
+        let primary = if IS_MACOS { "cmd" } else { "ctrl" };
+        let mut keys: Vec<String> = accelerator
+            .split('+')
+            .map(|key| match key.to_ascii_lowercase().as_str() {
+                "cmdorctrl" | "commandorcontrol" => primary.to_string(),
+                "command" | "super" => "cmd".to_string(),
+                "control" => "ctrl".to_string(),
+                other => other.to_string(),
+            })
+            .collect();
+        keys.sort();
+        keys.join("+")
+    }
+
+    fn collect_ids<R: Runtime>(items: Vec<MenuItemKind<R>>, ids: &mut Vec<String>) {
+        for item in items {
+            ids.push(item.id().as_ref().to_string());
+            if let Some(submenu) = item.as_submenu() {
+                collect_ids(submenu.items().unwrap(), ids);
+            }
+        }
+    }
+
+    #[test]
+    fn no_duplicate_accelerators() {
+        let mut seen: HashMap<String, &str> = HashMap::new();
+        for entry in shortcuts() {
+            if let Some(other) = seen.insert(normalize(&entry.accelerator), entry.id) {
+                panic!("{} and {other} share {}", entry.id, entry.accelerator);
+            }
+        }
+    }
+
+    #[test]
+    fn remap_rejects_conflicts_and_drops_defaults() {
+        let overrides = remap(&HashMap::new(), "view_logs", "CmdOrCtrl+Shift+L").unwrap();
+        assert_eq!(overrides["view_logs"], "CmdOrCtrl+Shift+L");
+        assert_eq!(
+            shortcuts_with(&overrides)
+                .iter()
+                .find(|e| e.id == "view_logs")
+                .unwrap()
+                .accelerator,
+            "CmdOrCtrl+Shift+L"
+        );
+
+        let err = remap(&overrides, "train_model", "CmdOrCtrl+S").unwrap_err();
+        assert_eq!(err, "CmdOrCtrl+S is already used by Save Project");
+        assert!(remap(&overrides, "train_model", "CmdOrCtrl+Nope").is_err());
+        assert!(remap(&overrides, "import_model", "F5").is_err());
+
+        let reset = remap(&overrides, "view_logs", "CmdOrCtrl+L").unwrap();
+        assert!(reset.is_empty());
+    }
+
+    #[test]
+    fn remap_all_checks_every_override() {
+        let swapped = HashMap::from([
+            ("view_logs".to_string(), "CmdOrCtrl+Shift+L".to_string()),
+            ("train_model".to_string(), "CmdOrCtrl+L".to_string()),
+        ]);
+        assert_eq!(
+            remap_all(&swapped).unwrap()["view_logs"],
+            "CmdOrCtrl+Shift+L"
+        );
+
+        let clash = HashMap::from([("train_model".to_string(), "CmdOrCtrl+S".to_string())]);
+        assert_eq!(
+            remap_all(&clash).unwrap_err(),
+            "CmdOrCtrl+S is already used by Save Project"
+        );
+        let typo = HashMap::from([("view_logs".to_string(), "Ctrl+".to_string())]);
+        assert!(remap_all(&typo).is_err());
+    }
+
+    #[test]
+    fn validate_accelerator_explains_each_mistake() {
+        for valid in [
+            "CmdOrCtrl+Shift+K",
+            "F5",
+            "Alt + Enter",
+            "Ctrl+=",
+            "shift+cmd+1",
+        ] {
+            assert_eq!(validate_accelerator(valid), Ok(()), "{valid}");
+        }
+        let error = |accelerator| validate_accelerator(accelerator).unwrap_err();
+        assert_eq!(error(" "), "shortcut is empty");
+        assert_eq!(error("Ctrl+"), "Ctrl+ is missing a key after the last +");
+        assert_eq!(
+            error("Hyper+K"),
+            "unknown modifier hyper; use Ctrl, Alt, Shift, Cmd, Super or CmdOrCtrl"
+        );
+        assert_eq!(
+            error("Ctrl++K"),
+            "Ctrl++K has an empty modifier between two +"
+        );
+        assert_eq!(error("Shift+shift+K"), "modifier shift is repeated");
+        assert_eq!(
+            error("Ctrl+Shift"),
+            "Ctrl+Shift has only modifiers; add a key"
+        );
+        assert_eq!(error("Ctrl+Banana"), "unknown key Banana");
+    }
+
+    #[test]
+    fn every_shortcut_is_in_the_menu() {
+        let app = tauri::test::mock_app();
+        let mut ids = Vec::new();
+        collect_ids(create_menu(&app).unwrap().items().unwrap(), &mut ids);
+        for entry in shortcuts() {
+            assert!(ids.iter().any(|id| id == entry.id), "missing {}", entry.id);
+        }
+    }
+}
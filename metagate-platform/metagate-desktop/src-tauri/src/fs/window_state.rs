@@ -0,0 +1,174 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{Manager, PhysicalPosition, PhysicalSize, Runtime, Window, WindowEvent};
+
+const WINDOW_STATE_FILE: &str = "window_state.json";
+pub const MAIN_WINDOW: &str = "main";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+    pub maximized: bool,
+}
+
+// 모니터에서 작업 표시줄 등을 뺀 영역
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkArea {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl WorkArea {
+    fn overlap(&self, state: &WindowState) -> i64 {
+        let w =
+            (self.x + self.width as i32).min(state.x + state.width as i32) - self.x.max(state.x);
+        let h =
+            (self.y + self.height as i32).min(state.y + state.height as i32) - self.y.max(state.y);
+        if w > 0 && h > 0 {
+            w as i64 * h as i64
+        } else {
+            0
+        }
+    }
+
+    fn distance(&self, state: &WindowState) -> i64 {
+        let dx = (self.x + self.width as i32 / 2) - (state.x + state.width as i32 / 2);
+        let dy = (self.y + self.height as i32 / 2) - (state.y + state.height as i32 / 2);
+        dx as i64 * dx as i64 + dy as i64 * dy as i64
+    }
+}
+
+impl WindowState {
+    pub fn load<R: Runtime, M: Manager<R>>(app: &M) -> Option<Self> {
+        let raw = fs::read_to_string(state_file(app)?).ok()?;
+        serde_json::from_str(&raw).ok()
+    }
+
+    pub fn save<R: Runtime, M: Manager<R>>(&self, app: &M) -> io::Result<()> {
+        let file = state_file(app).ok_or_else(|| io::Error::other("no app config dir"))?;
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(file, serde_json::to_vec_pretty(self)?)
+    }
+
+    // 가장 많이 겹치는(없으면 가장 가까운) 모니터 안으로 창을 끌어온다.
+    // 모니터를 뽑아서 화면 밖에 창이 남는 경우를 막기 위함
+    pub fn clamp_to(&self, areas: &[WorkArea]) -> WindowState {
+        let best = areas
+            .iter()
+            .max_by_key(|area| area.overlap(self))
+            .filter(|area| area.overlap(self) > 0)
+            .or_else(|| areas.iter().min_by_key(|area| area.distance(self)));
+        let Some(area) = best else {
+            return *self;
+        };
+
+        let width = self.width.min(area.width);
+        let height = self.height.min(area.height);
+        let max_x = area.x + (area.width - width) as i32;
+        let max_y = area.y + (area.height - height) as i32;
+        WindowState {
+            x: self.x.clamp(area.x, max_x),
+            y: self.y.clamp(area.y, max_y),
+            width,
+            height,
+            maximized: self.maximized,
+        }
+    }
+}
+
+// 최대화 상태일 때는 그 직전의 일반 크기/위치를 기억해 둔다
+#[derive(Default)]
+pub struct TrackedWindowState(Mutex<Option<WindowState>>);
+
+pub fn restore<R: Runtime>(window: &tauri::WebviewWindow<R>) -> tauri::Result<()> {
+    if let Some(saved) = WindowState::load(window) {
+        let areas: Vec<WorkArea> = window
+            .available_monitors()?
+            .iter()
+            .map(|monitor| {
+                let rect = monitor.work_area();
+                WorkArea {
+                    x: rect.position.x,
+                    y: rect.position.y,
+                    width: rect.size.width,
+                    height: rect.size.height,
+                }
+            })
+            .collect();
+        let state = saved.clamp_to(&areas);
+
+        window.set_size(PhysicalSize::new(state.width, state.height))?;
+        window.set_position(PhysicalPosition::new(state.x, state.y))?;
+        if state.maximized {
+            window.maximize()?;
+        }
+        *window
+            .state::<TrackedWindowState>()
+            .0
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(state);
+    }
+    window.show()
+}
+
+pub fn track<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    if window.label() != MAIN_WINDOW {
+        return;
+    }
+    // Quit 메뉴는 CloseRequested 없이 종료되므로 이동/크기 변경 때마다 저장한다
+    if !matches!(
+        event,
+        WindowEvent::Moved(_) | WindowEvent::Resized(_) | WindowEvent::CloseRequested { .. }
+    ) {
+        return;
+    }
+    if let Some(state) = update(window) {
+        if let Err(err) = state.save(window) {
+            log::warn!("failed to save window state: {err}");
+        }
+    }
+}
+
+fn update<R: Runtime>(window: &Window<R>) -> Option<WindowState> {
+    let tracked = window.state::<TrackedWindowState>();
+    let mut tracked = tracked.0.lock().unwrap_or_else(|e| e.into_inner());
+    let maximized = window.is_maximized().unwrap_or(false);
+
+    let state = match (*tracked, maximized) {
+        (Some(previous), true) => WindowState {
+            maximized,
+            ..previous
+        },
+        _ => {
+            let position = window.outer_position().ok()?;
+            let size = window.inner_size().ok()?;
+            WindowState {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized,
+            }
+        }
+    };
+    *tracked = Some(state);
+    Some(state)
+}
+
+fn state_file<R: Runtime, M: Manager<R>>(app: &M) -> Option<PathBuf> {
+    app.path()
+        .app_config_dir()
+        .ok()
+        .map(|dir| dir.join(WINDOW_STATE_FILE))
+}
@@ -1,18 +1,286 @@
 mod fs;
 mod ops;
+#[cfg(test)]
+mod testing;
 
+use std::time::Instant;
+
+use fs::locale::MenuLocale;
 use fs::menu::create_menu;
+use fs::menu_events::handle_menu_event;
+use fs::preferences::Preferences;
+use fs::shortcuts::AcceleratorOverrides;
+use fs::window_state::{TrackedWindowState, MAIN_WINDOW};
+use ops::autosave::AutoSave;
+use ops::delete::PendingDelete;
+use ops::deploy::Deployments;
+use ops::env::SecretEnv;
+use ops::history::UndoStack;
+use ops::jobs::JobSlots;
+use ops::launch::LaunchProject;
+use ops::logs::LogStreams;
+use ops::monitoring::SystemMonitor;
+use ops::notify::PendingNavigation;
+use ops::operation::Operations;
+use ops::pipeline::{PipelineRuns, PipelineSchedules, StagePlugins};
+use ops::project::{PendingRevert, ProjectState};
+use ops::tabs::OpenProjects;
+use ops::timing::CommandTimings;
+use ops::train::TrainingRuns;
+use ops::view::{ActiveViews, SidebarState, ThemeState};
+use ops::watch::ProjectWatcher;
+use ops::window::WindowCounter;
+use tauri::webview::PageLoadEvent;
+use tauri::{DragDropEvent, Manager, WindowEvent};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
+// greet는 기존 프론트엔드 호환 때문에 남겨둔다
+#[allow(deprecated)]
 pub fn run() {
+    let handler: Box<dyn Fn(tauri::ipc::Invoke) -> bool + Send + Sync> =
+        Box::new(tauri::generate_handler![
+            ops::greet::greet,
+            ops::app::app_info,
+            ops::app::open_log_folder,
+            ops::app::open_docs,
+            ops::app::clear_cache,
+            ops::debug::debug_snapshot,
+            ops::timing::command_timings,
+            ops::app::report_crash,
+            ops::app::dismiss_crash,
+            ops::update::check_for_updates,
+            ops::project::create_project,
+            ops::project::open_project,
+            ops::project::open_project_readonly,
+            ops::project::repair_project,
+            ops::project::scan_integrity,
+            ops::project::save_project,
+            ops::project::save_project_as,
+            ops::project::export_project_archive,
+            ops::project::import_project_archive,
+            ops::project::close_project,
+            ops::project::confirm_discard,
+            ops::project::revert_project,
+            ops::project::confirm_revert,
+            ops::project::rename_project,
+            ops::project::remove_model,
+            ops::project::remove_dataset,
+            ops::project::transfer_asset,
+            ops::delete::delete_model,
+            ops::delete::delete_dataset,
+            ops::delete::delete_pipeline,
+            ops::delete::confirm_delete,
+            ops::project::search_project,
+            ops::project::add_tag,
+            ops::project::remove_tag,
+            ops::project::list_tags,
+            ops::project::filter_by_tags,
+            ops::project::reveal_in_explorer,
+            ops::project::project_disk_usage,
+            ops::history::undo,
+            ops::history::redo,
+            ops::dataset::list_datasets,
+            ops::dataset::infer_schema,
+            ops::dataset::dataset_stats,
+            ops::dataset::preview_dataset,
+            ops::model::export_model,
+            ops::deploy::deploy_model,
+            ops::deploy::create_share_token,
+            ops::deploy::revoke_share_token,
+            ops::deploy::list_deployments,
+            ops::deploy::deployment_health,
+            ops::deploy::start_health_monitor,
+            ops::deploy::stop_health_monitor,
+            ops::deploy::test_inference,
+            ops::model::import_model,
+            ops::model::import_models_from_dir,
+            ops::model::update_model_metadata,
+            ops::model::diff_models,
+            ops::model::model_graph_summary,
+            ops::model::verify_model,
+            ops::model::create_model_version,
+            ops::model::model_lineage,
+            ops::operation::cancel_operation,
+            ops::clipboard::copy_model_ref,
+            ops::clipboard::cut_model_ref,
+            ops::clipboard::paste_model_ref,
+            ops::logs::open_log_stream,
+            ops::logs::close_log_stream,
+            ops::pipeline::validate_pipeline,
+            ops::pipeline::run_pipeline,
+            ops::status::active_operations,
+            ops::jobs::job_queue_status,
+            ops::tabs::list_open_projects,
+            ops::tabs::switch_project,
+            ops::tabs::open_additional_project,
+            ops::pipeline::schedule_pipeline,
+            ops::pipeline::list_schedules,
+            ops::pipeline::cancel_schedule,
+            ops::pipeline::stop_pipeline,
+            ops::pipeline::export_run_report,
+            ops::pipeline::export_artifacts,
+            ops::pipeline::clone_pipeline,
+            ops::pipeline::register_stage_plugin,
+            ops::pipeline::list_stage_plugins,
+            ops::train::start_training,
+            ops::env::set_secret_env,
+            ops::env::remove_secret_env,
+            ops::env::list_secret_env,
+            ops::train::check_compatibility,
+            ops::train::cancel_training,
+            ops::train::pause_training,
+            ops::train::resume_training,
+            ops::preferences::load_preferences,
+            ops::preferences::save_preferences,
+            ops::view::set_sidebar_visible,
+            ops::view::set_theme,
+            ops::view::set_active_view,
+            ops::shortcuts::list_shortcuts,
+            ops::shortcuts::set_accelerator,
+            ops::shortcuts::validate_accelerator,
+            ops::shortcuts::reset_accelerators,
+            ops::locale::list_locales,
+            ops::locale::set_locale,
+            ops::palette::list_commands,
+            ops::palette::run_command,
+            ops::palette::fuzzy_match_commands,
+            ops::palette::menu_structure,
+            ops::shutdown::confirm_quit,
+            ops::shutdown::reset_workspace,
+            ops::monitoring::system_metrics,
+            ops::monitoring::start_metrics_stream,
+            ops::monitoring::stop_metrics_stream,
+            ops::monitoring::training_history,
+            ops::monitoring::export_metrics_csv,
+            ops::monitoring::list_runs,
+            ops::monitoring::list_gpus,
+            ops::monitoring::compare_runs,
+            ops::monitoring::archive_runs,
+            ops::monitoring::restore_archive,
+            ops::watch::watch_project,
+            ops::watch::unwatch_project,
+            ops::window::new_window,
+        ]);
+
     tauri::Builder::default()
+        .manage(ProjectState::default())
+        .manage(PipelineRuns::default())
+        .manage(LogStreams::default())
+        .manage(Deployments::default())
+        .manage(TrainingRuns::default())
+        .manage(UndoStack::default())
+        .manage(ProjectWatcher::default())
+        .manage(SystemMonitor::default())
+        .manage(TrackedWindowState::default())
+        .manage(WindowCounter::default())
+        .manage(AutoSave::default())
+        .manage(LaunchProject::default())
+        .manage(ThemeState::default())
+        .manage(Operations::default())
+        .manage(StagePlugins::default())
+        .manage(ActiveViews::default())
+        .manage(PendingNavigation::default())
+        .manage(SecretEnv::default())
+        .manage(PipelineSchedules::default())
+        .manage(PendingDelete::default())
+        .manage(PendingRevert::default())
+        .manage(OpenProjects::default())
         .setup(|app| {
+            if let Some(guard) = fs::logging::init(app) {
+                app.manage(guard);
+            }
+            fs::crash::install(app.handle().clone());
+            let preferences = Preferences::load(app);
+            app.manage(SidebarState::new(preferences.sidebar_visible));
+            app.manage(AcceleratorOverrides::new(preferences.accelerators));
+            app.manage(MenuLocale::new(preferences.locale));
+            app.manage(CommandTimings::new(preferences.slow_command_ms));
+            app.manage(JobSlots::new(
+                preferences.max_concurrent_jobs,
+                preferences.queue_jobs_when_busy,
+            ));
             let menu = create_menu(app)?;
             app.set_menu(menu)?;
+            ops::autosave::init(app.handle());
+            ops::deploy::clear_share_files(app.handle());
+            ops::launch::open_from_args(app.handle());
+            // 놓친 예약을 바로 돌릴 수 있게 실행 인자로 받은 프로젝트를 먼저 연다
+            ops::pipeline::start_scheduler(app.handle());
+
+            // 메인 창은 숨긴 채로 만들어지고, 저장된 크기/위치를 적용한 뒤에 보여준다
+            if let Some(window) = app.get_webview_window(MAIN_WINDOW) {
+                if let Err(err) = ops::view::apply_saved_zoom(&window) {
+                    log::warn!("failed to apply zoom: {err}");
+                }
+                if let Err(err) = ops::view::apply_saved_theme(&window) {
+                    log::warn!("failed to apply theme: {err}");
+                }
+                if let Err(err) = fs::window_state::restore(&window) {
+                    log::warn!("failed to restore window state: {err}");
+                    window.show()?;
+                }
+            }
             Ok(())
         })
+        .on_menu_event(handle_menu_event)
+        .on_window_event(|window, event| {
+            fs::window_state::track(window, event);
+            ops::view::track_full_screen(window, event);
+            ops::view::track_theme(window, event);
+            ops::notify::track_focus(window, event);
+            if let WindowEvent::DragDrop(DragDropEvent::Drop { paths, .. }) = event {
+                ops::model::import_dropped(window.app_handle(), paths.clone());
+            }
+            // 마지막 창을 닫으면 앱이 끝나므로 Quit과 같은 확인을 거친다
+            if let WindowEvent::CloseRequested { api, .. } = event {
+                let app = window.app_handle();
+                if app.webview_windows().len() == 1 && !ops::shutdown::request_quit(app) {
+                    api.prevent_close();
+                }
+            }
+            if let WindowEvent::Destroyed = event {
+                window
+                    .state::<LogStreams>()
+                    .close_for_window(window.label());
+                window.state::<ActiveViews>().remove(window.label());
+            }
+        })
+        .on_page_load(|webview, payload| {
+            if webview.label() == MAIN_WINDOW && payload.event() == PageLoadEvent::Finished {
+                ops::launch::page_loaded(webview.app_handle());
+                ops::app::notify_pending_crashes(webview.app_handle());
+            }
+        })
         .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![ops::greet::greet])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
+        .plugin(tauri_plugin_notification::init())
+        .invoke_handler(move |invoke| {
+            // 커맨드 호출마다 span을 열어서 로그에 어떤 커맨드 중이었는지 남긴다
+            let span = tracing::info_span!("command", name = invoke.message.command());
+            let _entered = span.enter();
+            tracing::debug!("invoked");
+            let name = invoke.message.command().to_string();
+            let states = invoke.message.state();
+            let started = Instant::now();
+            let handled = handler(invoke);
+            if let Some(timings) = states
+                .try_get::<CommandTimings>()
+                .filter(|_| CommandTimings::is_timed(&name))
+            {
+                timings.record(&name, started.elapsed());
+            }
+            handled
+        })
+        .build(tauri::generate_context!())
+        .expect("error while running tauri application")
+        .run(|_app, _event| {
+            // macOS에서 파일 연결로 열면 인자 대신 Opened 이벤트로 온다
+            #[cfg(target_os = "macos")]
+            if let tauri::RunEvent::Opened { urls } = _event {
+                for path in urls.iter().filter_map(|url| url.to_file_path().ok()) {
+                    ops::launch::open_launch_path(_app, &path);
+                }
+            }
+        });
 }
@@ -0,0 +1,261 @@
+use std::path::{Path, PathBuf};
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_opener::OpenerExt;
+
+use super::emit;
+use super::project::entry_size;
+use super::view::ActiveViews;
+use crate::fs::crash::{self, CrashReport};
+use crate::fs::logging::log_dir;
+use crate::fs::menu_events::{focused_window, VIEW_MENU_IDS};
+use crate::fs::preferences::Preferences;
+
+const ISSUES_URL: &str = "https://github.com/Jeffr-K/metagate/issues/new";
+// URL 길이 제한에 걸리지 않도록 백트레이스는 앞부분만 싣는다
+const BACKTRACE_LINES: usize = 40;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppInfo {
+    pub version: String,
+    pub tauri_version: String,
+    pub os: String,
+    pub arch: String,
+    pub cpu_cores: usize,
+}
+
+#[command]
+pub fn app_info() -> AppInfo {
+    current_app_info()
+}
+
+pub fn current_app_info() -> AppInfo {
+    AppInfo {
+        version: env!("CARGO_PKG_VERSION").into(),
+        tauri_version: tauri::VERSION.into(),
+        os: std::env::consts::OS.into(),
+        arch: std::env::consts::ARCH.into(),
+        cpu_cores: std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+    }
+}
+
+#[command]
+pub fn open_log_folder(app: AppHandle) -> Result<(), String> {
+    reveal_log_folder(&app)
+}
+
+pub fn reveal_log_folder<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let dir = log_dir(app).ok_or("no log directory")?;
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    app.opener()
+        .open_path(dir.to_string_lossy(), None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+pub fn open_issue_tracker<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    open_issue(app, &issue_url(&current_app_info(), None))
+}
+
+#[command]
+pub fn clear_cache(app: AppHandle) -> Result<u64, String> {
+    clear_app_cache(&app)
+}
+
+// 캐시 폴더 안의 내용만 지우고 폴더 자체는 남긴다. 돌려주는 값은 지운 바이트 수
+pub fn clear_app_cache<R: Runtime>(app: &AppHandle<R>) -> Result<u64, String> {
+    let path = app.path();
+    let cache = path.app_cache_dir().map_err(|e| e.to_string())?;
+    // 플랫폼에 따라 설정/데이터 폴더와 겹칠 수 있다. 그럴 때는 환경설정까지 지우게 되므로 하지 않는다
+    let protected = [path.app_config_dir(), path.app_data_dir()];
+    if protected
+        .into_iter()
+        .flatten()
+        .any(|dir| dir.starts_with(&cache))
+    {
+        return Err(format!(
+            "{} also holds app data; not clearing it",
+            cache.display()
+        ));
+    }
+    let freed = clear_dir(&cache)?;
+    emit(app, "cache-cleared", freed);
+    Ok(freed)
+}
+
+// 지우지 못한 항목은 로그만 남기고 나머지는 계속 지운다
+fn clear_dir(dir: &Path) -> Result<u64, String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(err) => return Err(format!("failed to read {}: {err}", dir.display())),
+    };
+    let mut freed = 0;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let size = entry_size(&path);
+        let removed = match entry.file_type() {
+            Ok(kind) if kind.is_dir() => std::fs::remove_dir_all(&path),
+            _ => std::fs::remove_file(&path),
+        };
+        match removed {
+            Ok(()) => freed += size,
+            Err(err) => log::warn!("failed to remove {}: {err}", path.display()),
+        }
+    }
+    Ok(freed)
+}
+
+// topic이 없으면 포커스된 창에서 보고 있는 화면의 문서로 간다
+#[command]
+pub fn open_docs(app: AppHandle, topic: Option<String>) -> Result<(), String> {
+    open_documentation(&app, topic)
+}
+
+pub fn open_documentation<R: Runtime>(
+    app: &AppHandle<R>,
+    topic: Option<String>,
+) -> Result<(), String> {
+    let topic = topic.or_else(|| {
+        let window = focused_window(app)?;
+        app.state::<ActiveViews>().get(window.label())
+    });
+    let url = docs_url(&Preferences::load(app).docs_url, topic.as_deref());
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+// 문서에 없는 주제는 첫 페이지로 보낸다
+fn docs_url(base: &str, topic: Option<&str>) -> String {
+    let base = base.trim_end_matches('#');
+    match topic {
+        Some(topic) if VIEW_MENU_IDS.contains(&topic) => format!("{base}#{topic}"),
+        _ => base.to_string(),
+    }
+}
+
+fn open_issue<R: Runtime>(app: &AppHandle<R>, url: &str) -> Result<(), String> {
+    app.opener()
+        .open_url(url, None::<&str>)
+        .map_err(|e| e.to_string())
+}
+
+// 프론트엔드가 뜬 뒤에 불러서 지난 실행의 크래시를 알린다
+pub fn notify_pending_crashes<R: Runtime>(app: &AppHandle<R>) {
+    let Some(dir) = log_dir(app) else {
+        return;
+    };
+    let crashes = crash::pending(&dir);
+    if !crashes.is_empty() {
+        emit(app, "crash-detected", crashes);
+    }
+}
+
+// 크래시 당시 버전으로 이슈를 채우고, 다시 묻지 않도록 보낸 것으로 표시한다
+#[command]
+pub fn report_crash(app: AppHandle, file: PathBuf) -> Result<(), String> {
+    let file = crash_file(&app, &file)?;
+    let raw = std::fs::read_to_string(&file).map_err(|e| e.to_string())?;
+    let report: CrashReport = serde_json::from_str(&raw).map_err(|e| e.to_string())?;
+    open_issue(&app, &issue_url(&report.app, Some(&report)))?;
+    crash::mark_sent(&file).map_err(|e| e.to_string())
+}
+
+#[command]
+pub fn dismiss_crash(app: AppHandle, file: PathBuf) -> Result<(), String> {
+    let file = crash_file(&app, &file)?;
+    crash::mark_sent(&file).map_err(|e| e.to_string())
+}
+
+// 로그 디렉토리 밖의 파일은 건드리지 않는다
+fn crash_file<R: Runtime>(app: &AppHandle<R>, file: &Path) -> Result<PathBuf, String> {
+    let dir = log_dir(app).ok_or("no log directory")?;
+    match file.file_name() {
+        Some(name) if file.parent() == Some(dir.as_path()) => Ok(dir.join(name)),
+        _ => Err(format!("{} is not a crash report", file.display())),
+    }
+}
+
+// 버그 리포트에 실행 환경이 같이 들어가도록 본문을 미리 채워 둔다
+pub fn issue_url(info: &AppInfo, crash: Option<&CrashReport>) -> String {
+    let mut body = String::from("**Describe the bug**\n\n\n**Steps to reproduce**\n\n\n");
+    if let Some(crash) = crash {
+        let backtrace: Vec<&str> = crash.backtrace.lines().take(BACKTRACE_LINES).collect();
+        body.push_str(&format!(
+            "**Crash**\n\n```\n{} at {}\n{}\n```\n\n",
+            crash.message,
+            crash.location.as_deref().unwrap_or("unknown location"),
+            backtrace.join("\n")
+        ));
+    }
+    body.push_str(&format!(
+        "---\nMetagate {} (Tauri {})\nOS: {} {}\n",
+        info.version, info.tauri_version, info.os, info.arch
+    ));
+    format!("{ISSUES_URL}?body={}", encode_query(&body))
+}
+
+fn encode_query(value: &str) -> String {
+    utf8_percent_encode(value, NON_ALPHANUMERIC).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn spaces_and_newlines_are_percent_encoded() {
+        assert_eq!(encode_query("a b\nc"), "a%20b%0Ac");
+        assert_eq!(encode_query("x&y=z"), "x%26y%3Dz");
+    }
+
+    #[test]
+    fn clear_dir_empties_the_folder_and_counts_bytes() {
+        let dir = TempDir::new("cache");
+        std::fs::create_dir_all(dir.join("thumbnails")).unwrap();
+        std::fs::write(dir.join("thumbnails/a.png"), [0u8; 10]).unwrap();
+        std::fs::write(dir.join("schema.json"), [0u8; 5]).unwrap();
+
+        let freed = clear_dir(&dir);
+        let left = std::fs::read_dir(&dir).unwrap().count();
+
+        assert_eq!(freed, Ok(15));
+        assert_eq!(left, 0);
+        assert_eq!(clear_dir(&dir.join("missing")), Ok(0));
+    }
+
+    #[test]
+    fn docs_url_links_known_topics_only() {
+        let base = "https://docs.example.com/metagate#";
+        assert_eq!(
+            docs_url(base, Some("pipelines")),
+            "https://docs.example.com/metagate#pipelines"
+        );
+        assert_eq!(
+            docs_url(base, Some("nope")),
+            "https://docs.example.com/metagate"
+        );
+        assert_eq!(docs_url(base, None), "https://docs.example.com/metagate");
+    }
+
+    #[test]
+    fn issue_url_carries_version_and_os() {
+        let info = AppInfo {
+            version: "1.2.3".into(),
+            tauri_version: "2.0.0".into(),
+            os: "linux".into(),
+            arch: "x86_64".into(),
+            cpu_cores: 4,
+        };
+        let url = issue_url(&info, None);
+        assert!(url.starts_with(&format!("{ISSUES_URL}?body=")));
+        assert!(url.contains("Metagate%201%2E2%2E3"));
+        assert!(url.contains("OS%3A%20linux%20x86%5F64"));
+        assert!(!url.contains(' ') && !url.contains('\n'));
+    }
+}
@@ -0,0 +1,75 @@
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::Utc;
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Listener, Manager, Runtime};
+
+use super::emit;
+use super::project::{ProjectState, SaveOutcome};
+use super::window::update_window_title;
+use crate::fs::preferences::Preferences;
+
+#[derive(Default)]
+pub struct AutoSave {
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl AutoSave {
+    fn lock(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.task.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.lock().is_some()
+    }
+
+    pub fn stop(&self) {
+        if let Some(task) = self.lock().take() {
+            task.abort();
+        }
+    }
+}
+
+// 환경설정 값으로 타이머를 켜고, 설정이 바뀌면 새 주기로 다시 건다
+pub fn init<R: Runtime>(app: &AppHandle<R>) {
+    restart(app, Preferences::load(app).autosave_interval_secs);
+
+    let handle = app.clone();
+    app.listen(
+        "preferences-changed",
+        move |event| match serde_json::from_str::<Preferences>(event.payload()) {
+            Ok(preferences) => restart(&handle, preferences.autosave_interval_secs),
+            Err(err) => log::warn!("ignoring malformed preferences-changed payload: {err}"),
+        },
+    );
+}
+
+// None이나 0이면 자동 저장을 끈다
+pub fn restart<R: Runtime>(app: &AppHandle<R>, interval_secs: Option<u64>) {
+    let autosave = app.state::<AutoSave>();
+    autosave.stop();
+    let Some(interval) = interval_secs
+        .filter(|secs| *secs > 0)
+        .map(Duration::from_secs)
+    else {
+        return;
+    };
+
+    let handle = app.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+            // 직접 저장 중이면 그쪽이 같은 내용을 쓰고 있으니 이번 차례는 건너뛴다
+            match handle.state::<ProjectState>().try_save() {
+                Ok(SaveOutcome::Saved) => {
+                    update_window_title(&handle);
+                    emit(&handle, "autosaved", Utc::now());
+                }
+                Ok(SaveOutcome::Unchanged | SaveOutcome::SaveInProgress) => {}
+                Err(err) => log::warn!("auto-save failed: {err}"),
+            }
+        }
+    });
+    *autosave.lock() = Some(task);
+}
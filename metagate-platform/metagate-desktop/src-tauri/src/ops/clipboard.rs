@@ -0,0 +1,251 @@
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_clipboard_manager::ClipboardExt;
+
+use super::delete::DeleteKind;
+use super::history::{self, ProjectCommand, UndoStack};
+use super::model::copy_model_file;
+use super::project::{load_project, ProjectState};
+use super::tabs::{canonical, OpenProjects};
+use crate::fs::project::{ModelRef, Project};
+
+// 다른 앱이 넣은 JSON과 구분하려고 붙이는 표시
+const CLIPBOARD_KIND: &str = "metagate/model-ref";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ClipboardModel {
+    kind: String,
+    // 모델 파일을 찾으려면 원래 프로젝트 위치가 있어야 한다
+    project: PathBuf,
+    model: ModelRef,
+    // 잘라내기는 붙여넣기가 성공한 뒤에야 원래 프로젝트에서 뺀다
+    cut: bool,
+}
+
+impl ClipboardModel {
+    // 메타게이트가 넣은 내용이 아니면 None
+    fn parse(text: &str) -> Option<Self> {
+        serde_json::from_str::<Self>(text)
+            .ok()
+            .filter(|entry| entry.kind == CLIPBOARD_KIND)
+    }
+}
+
+#[command]
+pub fn copy_model_ref(app: AppHandle, model_id: String) -> Result<(), String> {
+    write_model_ref(&app, &model_id, false)
+}
+
+#[command]
+pub fn cut_model_ref(app: AppHandle, model_id: String) -> Result<(), String> {
+    write_model_ref(&app, &model_id, true)
+}
+
+fn write_model_ref<R: Runtime>(
+    app: &AppHandle<R>,
+    model_id: &str,
+    cut: bool,
+) -> Result<(), String> {
+    let entry = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        let model = project
+            .models
+            .iter()
+            .find(|m| m.id == model_id)
+            .ok_or_else(|| format!("model {model_id} not found"))?;
+        ClipboardModel {
+            kind: CLIPBOARD_KIND.into(),
+            project: project.root.clone(),
+            model: model.clone(),
+            cut,
+        }
+    };
+    let text = serde_json::to_string(&entry).map_err(|e| e.to_string())?;
+    app.clipboard().write_text(text).map_err(|e| e.to_string())
+}
+
+// 클립보드에 메타게이트 모델이 없으면 아무것도 하지 않고 None을 돌려준다
+#[command]
+pub fn paste_model_ref(app: AppHandle, target_project: String) -> Result<Option<ModelRef>, String> {
+    let Some(entry) = app
+        .clipboard()
+        .read_text()
+        .ok()
+        .and_then(|text| ClipboardModel::parse(&text))
+    else {
+        return Ok(None);
+    };
+
+    let source = entry.project.join(&entry.model.path);
+    if !source.is_file() {
+        return Err(format!("model file is missing: {}", source.display()));
+    }
+    let target = PathBuf::from(target_project);
+    // 파일을 복사하기 전에 막아야 매니페스트에 없는 파일이 남지 않는다
+    ensure_writable(&app, &target)?;
+    if entry.cut {
+        ensure_writable(&app, &entry.project)?;
+    }
+    let id = uuid::Uuid::new_v4().to_string();
+    let relative = copy_model_file(&target, &source, &id)
+        .map_err(|e| format!("failed to copy {}: {e}", source.display()))?;
+    let model = ModelRef {
+        id,
+        path: relative,
+        imported_at: Utc::now(),
+        ..entry.model.clone()
+    };
+    add_to_project(&app, &target, &model)?;
+
+    if entry.cut {
+        // 원본을 빼지 못했으면 붙여넣기도 되돌려서 같은 모델이 두 군데 남지 않게 한다
+        if let Err(err) = remove_from_source(&app, &entry) {
+            undo_paste(&app, &target, &model);
+            return Err(err);
+        }
+        // 같은 잘라내기를 두 번 붙여넣어 원본을 또 지우는 일이 없게 비운다
+        app.clipboard().clear().map_err(|e| e.to_string())?;
+    }
+    Ok(Some(model))
+}
+
+// 열려 있는 프로젝트면 실행 취소가 되도록 history를 거치고, 아니면 매니페스트를 바로 고친다
+fn add_to_project<R: Runtime>(
+    app: &AppHandle<R>,
+    root: &Path,
+    model: &ModelRef,
+) -> Result<(), String> {
+    if is_open(app, root) {
+        return history::execute(app, ProjectCommand::AddModel(model.clone()));
+    }
+    change_on_disk(app, root, |project| project.models.push(model.clone()))
+}
+
+fn remove_from_source<R: Runtime>(
+    app: &AppHandle<R>,
+    entry: &ClipboardModel,
+) -> Result<(), String> {
+    if is_open(app, &entry.project) {
+        let index = {
+            let state = app.state::<ProjectState>();
+            let guard = state.lock();
+            guard
+                .as_ref()
+                .and_then(|p| p.models.iter().position(|m| m.id == entry.model.id))
+        };
+        // 그새 지워졌으면 할 일이 없다
+        let Some(index) = index else {
+            return Ok(());
+        };
+        return history::execute(
+            app,
+            ProjectCommand::RemoveModel {
+                index,
+                model: entry.model.clone(),
+            },
+        );
+    }
+    let id = &entry.model.id;
+    change_on_disk(app, &entry.project, |project| {
+        project.models.retain(|m| m.id != *id)
+    })
+}
+
+// 되돌리기 기록에도 남지 않게 붙여넣은 모델과 복사한 파일을 치운다
+fn undo_paste<R: Runtime>(app: &AppHandle<R>, target: &Path, model: &ModelRef) {
+    let removed = if is_open(app, target) {
+        let removed = app
+            .state::<ProjectState>()
+            .mutate(|project| project.models.retain(|m| m.id != model.id));
+        app.state::<UndoStack>()
+            .forget(DeleteKind::Model, &model.id);
+        history::sync_menu(app);
+        removed
+    } else {
+        change_on_disk(app, target, |project| {
+            project.models.retain(|m| m.id != model.id)
+        })
+    };
+    if let Err(err) = removed {
+        log::warn!("failed to roll back pasted model {}: {err}", model.id);
+        return;
+    }
+    let _ = std::fs::remove_file(target.join(&model.path));
+}
+
+// 활성 탭이 아닌 프로젝트의 매니페스트를 고친다. 뒤에 있는 탭이면 그 탭이 나중에 저장하면서
+// 덮어쓰지 않게 메모리의 프로젝트에도 같이 적용한다
+fn change_on_disk<R: Runtime>(
+    app: &AppHandle<R>,
+    root: &Path,
+    change: impl Fn(&mut Project),
+) -> Result<(), String> {
+    let mut project = load_project(root)?;
+    change(&mut project);
+    project
+        .save(&project.manifest_path())
+        .map_err(|e| e.to_string())?;
+    app.state::<OpenProjects>().with_parked(root, &change);
+    Ok(())
+}
+
+fn ensure_writable<R: Runtime>(app: &AppHandle<R>, root: &Path) -> Result<(), String> {
+    if is_open(app, root) {
+        return app.state::<ProjectState>().ensure_writable();
+    }
+    app.state::<OpenProjects>().ensure_parked_writable(root)
+}
+
+fn is_open<R: Runtime>(app: &AppHandle<R>, root: &Path) -> bool {
+    let root = canonical(root);
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    guard.as_ref().is_some_and(|p| canonical(&p.root) == root)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::project::{ModelFormat, MANIFEST_FILE};
+    use crate::testing::TempDir;
+
+    #[test]
+    fn foreign_clipboard_text_is_ignored() {
+        assert!(ClipboardModel::parse("hello").is_none());
+        assert!(ClipboardModel::parse(r#"{"kind":"other","project":"/p","cut":false}"#).is_none());
+    }
+
+    #[test]
+    fn undoing_a_paste_removes_the_model_and_its_file() {
+        let root = TempDir::new("paste");
+        std::fs::create_dir_all(root.join("models")).unwrap();
+        std::fs::write(root.join("models/a.onnx"), b"onnx").unwrap();
+        let model = ModelRef {
+            id: "a".into(),
+            name: "a".into(),
+            path: "models/a.onnx".into(),
+            format: ModelFormat::Onnx,
+            size_bytes: 4,
+            imported_at: Utc::now(),
+            sha256: None,
+            tags: Vec::new(),
+            description: None,
+            parent_id: None,
+        };
+        let mut project = Project::new(&root, "p");
+        project.models.push(model.clone());
+        project.save(&root.join(MANIFEST_FILE)).unwrap();
+
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(OpenProjects::default());
+        undo_paste(app.handle(), &root, &model);
+        assert!(load_project(&root).unwrap().models.is_empty());
+        assert!(!root.join("models/a.onnx").exists());
+    }
+}
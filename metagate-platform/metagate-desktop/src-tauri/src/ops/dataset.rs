@@ -0,0 +1,756 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::error::{guard, CommandError};
+use super::project::ProjectState;
+use crate::fs::project::DatasetRef;
+
+pub const DATASETS_DIR: &str = "datasets";
+const DEFAULT_SAMPLE_ROWS: usize = 1000;
+// 표 하나에 이보다 많이 그리면 UI가 멈춘다
+const MAX_PREVIEW_ROWS: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DatasetFormat {
+    Csv,
+    Parquet,
+}
+
+impl DatasetFormat {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_string_lossy().to_ascii_lowercase();
+        match extension.as_str() {
+            "csv" => Some(DatasetFormat::Csv),
+            "parquet" => Some(DatasetFormat::Parquet),
+            _ => None,
+        }
+    }
+}
+
+// list_datasets가 DatasetRef에 붙여 주는 파일 통계. 매니페스트에는 저장하지 않는다
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DatasetFileStats {
+    // Parquet은 아직 세지 않는다
+    pub row_count: Option<u64>,
+    pub size_bytes: u64,
+    pub format: DatasetFormat,
+}
+
+#[command]
+pub async fn list_datasets(app: AppHandle) -> Result<Vec<DatasetRef>, String> {
+    scan_datasets(app).await
+}
+
+// 행 수를 세느라 파일을 끝까지 읽으니 blocking 풀에서 돌린다
+pub async fn scan_datasets<R: Runtime>(app: AppHandle<R>) -> Result<Vec<DatasetRef>, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let (root, registered) = {
+            let state = app.state::<ProjectState>();
+            let guard = state.lock();
+            let project = guard.as_ref().ok_or("no project is open")?;
+            (project.root.clone(), project.datasets.clone())
+        };
+
+        let dir = root.join(DATASETS_DIR);
+        let entries = match fs::read_dir(&dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(format!("failed to read {}: {err}", dir.display())),
+        };
+
+        let mut datasets = Vec::new();
+        for entry in entries.flatten() {
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            if file_name.starts_with('.') {
+                continue;
+            }
+            let path = entry.path();
+            let Some(format) = DatasetFormat::from_path(&path) else {
+                continue;
+            };
+            let Ok(metadata) = entry.metadata() else {
+                continue;
+            };
+            if !metadata.is_file() {
+                continue;
+            }
+
+            // 매니페스트에 등록된 파일이면 그 id/이름을 쓰고, 아니면 파일 이름으로 대신한다
+            let relative = PathBuf::from(DATASETS_DIR).join(&file_name);
+            let known = registered.iter().find(|d| d.path == relative);
+            let row_count = match format {
+                DatasetFormat::Csv => count_csv_rows(&path).ok(),
+                DatasetFormat::Parquet => None,
+            };
+            let mut dataset = known.cloned().unwrap_or_else(|| DatasetRef {
+                id: file_name.clone(),
+                name: path
+                    .file_stem()
+                    .map(|s| s.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| file_name.clone()),
+                path: relative,
+                tags: Vec::new(),
+                stats: None,
+            });
+            dataset.stats = Some(DatasetFileStats {
+                row_count,
+                size_bytes: metadata.len(),
+                format,
+            });
+            datasets.push(dataset);
+        }
+        datasets.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(datasets)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// 데이터 행 수. 헤더가 있을 때만 한 줄을 뺀다. 파일 전체를 메모리에 올리지 않고 버퍼 단위로 센다
+fn count_csv_rows(path: &Path) -> io::Result<u64> {
+    let header = u64::from(has_header_row(path)?);
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut lines = 0u64;
+    let mut last = b'\n';
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            break;
+        }
+        lines += buf.iter().filter(|b| **b == b'\n').count() as u64;
+        last = buf[buf.len() - 1];
+        let len = buf.len();
+        reader.consume(len);
+    }
+    // 마지막 줄에 개행이 없어도 한 줄로 센다
+    if last != b'\n' {
+        lines += 1;
+    }
+    Ok(lines.saturating_sub(header))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnType {
+    Integer,
+    Float,
+    Boolean,
+    Date,
+    String,
+}
+
+impl ColumnType {
+    fn of(value: &str) -> Self {
+        if value.parse::<i64>().is_ok() {
+            ColumnType::Integer
+        } else if value.parse::<f64>().is_ok_and(f64::is_finite) {
+            ColumnType::Float
+        } else if value.eq_ignore_ascii_case("true") || value.eq_ignore_ascii_case("false") {
+            ColumnType::Boolean
+        } else if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+            || DateTime::parse_from_rfc3339(value).is_ok()
+        {
+            ColumnType::Date
+        } else {
+            ColumnType::String
+        }
+    }
+
+    // 정수와 실수가 섞이면 실수로, 그 밖에 다른 타입이 섞이면 문자열로 본다
+    fn merge(self, other: ColumnType) -> ColumnType {
+        use ColumnType::*;
+        match (self, other) {
+            (a, b) if a == b => a,
+            (Integer, Float) | (Float, Integer) => Float,
+            _ => String,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub data_type: ColumnType,
+    // 샘플에 빈 칸이 있었는지
+    pub nullable: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Schema {
+    pub columns: Vec<ColumnInfo>,
+    pub has_header: bool,
+    pub sampled_rows: usize,
+}
+
+#[command]
+pub async fn infer_schema(
+    path: String,
+    sample_rows: Option<usize>,
+) -> Result<Schema, CommandError> {
+    let sample_rows = sample_rows.unwrap_or(DEFAULT_SAMPLE_ROWS).max(1);
+    tauri::async_runtime::spawn_blocking(move || {
+        guard(|| Ok(infer_csv_schema(Path::new(&path), sample_rows)?))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+pub fn infer_csv_schema(path: &Path, sample_rows: usize) -> Result<Schema, String> {
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    // 헤더 후보까지 한 줄 더 읽는다
+    let rows = read_records(BufReader::new(file), sample_rows + 1)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let Some((first, rest)) = rows.split_first() else {
+        return Err(format!("{} is empty", path.display()));
+    };
+
+    // 첫 줄이 전부 문자열이면 헤더로 본다. 숫자나 날짜가 섞여 있으면 데이터 행이다
+    let has_header = is_header(first);
+    // 헤더가 없으면 첫 줄도 데이터이므로 타입 추론에 포함한다
+    let data = if has_header { rest } else { &rows[..] };
+    let data = &data[..data.len().min(sample_rows)];
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let mut types: Vec<Option<ColumnType>> = vec![None; width];
+    let mut nullable = vec![false; width];
+    for row in data {
+        for column in 0..width {
+            match row.get(column).map(|v| v.trim()).filter(|v| !v.is_empty()) {
+                Some(value) => {
+                    let found = ColumnType::of(value);
+                    types[column] = Some(types[column].map_or(found, |t| t.merge(found)));
+                }
+                None => nullable[column] = true,
+            }
+        }
+    }
+
+    let columns = (0..width)
+        .map(|column| ColumnInfo {
+            name: match first.get(column) {
+                Some(name) if has_header && !name.trim().is_empty() => name.trim().to_string(),
+                _ => format!("col_{column}"),
+            },
+            data_type: types[column].unwrap_or(ColumnType::String),
+            nullable: nullable[column],
+        })
+        .collect();
+    Ok(Schema {
+        columns,
+        has_header,
+        sampled_rows: data.len(),
+    })
+}
+
+fn is_header(first: &[String]) -> bool {
+    first.iter().all(|value| {
+        let value = value.trim();
+        !value.is_empty() && ColumnType::of(value) == ColumnType::String
+    })
+}
+
+// 첫 레코드가 헤더로 보이는지. 파일이 비었으면 false
+fn has_header_row(path: &Path) -> io::Result<bool> {
+    let rows = read_records(BufReader::new(File::open(path)?), 1)?;
+    Ok(rows.first().is_some_and(|first| is_header(first)))
+}
+
+// 따옴표 안의 쉼표와 줄바꿈, "" 이스케이프까지만 처리하는 간단한 CSV 리더
+fn read_records(reader: impl BufRead, limit: usize) -> io::Result<Vec<Vec<String>>> {
+    Records::new(reader).take(limit).collect()
+}
+
+// 레코드를 하나씩 읽는다. 큰 파일도 한 줄(따옴표 안 줄바꿈이면 몇 줄)만 메모리에 둔다
+struct Records<B> {
+    lines: io::Lines<B>,
+    // 지금까지 읽은 줄 수. 읽기 오류에 줄 번호를 붙일 때 쓴다
+    line: usize,
+}
+
+impl<B: BufRead> Records<B> {
+    fn new(reader: B) -> Self {
+        Records {
+            lines: reader.lines(),
+            line: 0,
+        }
+    }
+}
+
+impl<B: BufRead> Iterator for Records<B> {
+    type Item = io::Result<Vec<String>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+
+        loop {
+            let line = match self.lines.next() {
+                Some(Ok(line)) => {
+                    self.line += 1;
+                    line
+                }
+                Some(Err(err)) => {
+                    self.line += 1;
+                    let message = format!("line {}: {err}", self.line);
+                    return Some(Err(io::Error::new(err.kind(), message)));
+                }
+                // 따옴표가 닫히지 않은 채 끝나면 남은 내용을 마지막 레코드로 본다
+                None if quoted => {
+                    record.push(field);
+                    return Some(Ok(record));
+                }
+                None => return None,
+            };
+            let mut chars = line.trim_end_matches('\r').chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if quoted && chars.peek() == Some(&'"') => {
+                        field.push('"');
+                        chars.next();
+                    }
+                    '"' => quoted = !quoted,
+                    ',' if !quoted => record.push(std::mem::take(&mut field)),
+                    c => field.push(c),
+                }
+            }
+            if quoted {
+                field.push('\n');
+                continue;
+            }
+            record.push(std::mem::take(&mut field));
+            if record.len() > 1 || !record[0].is_empty() {
+                return Some(Ok(record));
+            }
+            record.clear();
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DatasetPreview {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[command]
+pub async fn preview_dataset(
+    app: AppHandle,
+    dataset_id: String,
+    limit: usize,
+) -> Result<DatasetPreview, String> {
+    let path = dataset_path(&app, &dataset_id)?;
+    let limit = limit.min(MAX_PREVIEW_ROWS);
+    tauri::async_runtime::spawn_blocking(move || match DatasetFormat::from_path(&path) {
+        Some(DatasetFormat::Csv) => csv_preview(&path, limit),
+        Some(DatasetFormat::Parquet) => parquet_preview(&path, limit),
+        _ => Err(format!("cannot preview {}", path.display())),
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// Parquet은 컬럼 타입을 따로 추론하지 않아서 None
+pub fn dataset_columns(path: &Path) -> Result<Vec<(String, Option<ColumnType>)>, String> {
+    match DatasetFormat::from_path(path) {
+        Some(DatasetFormat::Csv) => Ok(infer_csv_schema(path, DEFAULT_SAMPLE_ROWS)?
+            .columns
+            .into_iter()
+            .map(|c| (c.name, Some(c.data_type)))
+            .collect()),
+        Some(DatasetFormat::Parquet) => Ok(parquet_preview(path, 0)?
+            .columns
+            .into_iter()
+            .map(|name| (name, None))
+            .collect()),
+        _ => Err(format!("cannot read columns of {}", path.display())),
+    }
+}
+
+// 컬럼 이름은 infer_csv_schema와 같은 규칙(헤더가 없으면 col_0, col_1 ...)을 따른다
+pub fn csv_preview(path: &Path, limit: usize) -> Result<DatasetPreview, String> {
+    let schema = infer_csv_schema(path, DEFAULT_SAMPLE_ROWS)?;
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    let mut records = Records::new(BufReader::new(file));
+    if schema.has_header {
+        records.next();
+    }
+
+    let mut rows = Vec::new();
+    while rows.len() < limit {
+        match records.next() {
+            Some(Ok(row)) => rows.push(row),
+            Some(Err(err)) => return Err(format!("failed to read {}: {err}", path.display())),
+            None => break,
+        }
+    }
+    Ok(DatasetPreview {
+        columns: schema.columns.into_iter().map(|c| c.name).collect(),
+        rows,
+    })
+}
+
+// 행 단위로 읽으므로 앞쪽 row group만 디코딩한다. 중첩 컬럼은 문자열로 풀어서 보여준다
+fn parquet_preview(path: &Path, limit: usize) -> Result<DatasetPreview, String> {
+    use parquet::file::reader::{FileReader, SerializedFileReader};
+    use parquet::record::Field;
+
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    let reader = SerializedFileReader::new(file)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let columns = reader
+        .metadata()
+        .file_metadata()
+        .schema()
+        .get_fields()
+        .iter()
+        .map(|field| field.name().to_string())
+        .collect();
+    let rows = reader
+        .get_row_iter(None)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?
+        .take(limit)
+        .enumerate()
+        .map(|(index, row)| {
+            let row = row.map_err(|e| {
+                format!(
+                    "failed to read {} at row {}: {e}",
+                    path.display(),
+                    index + 1
+                )
+            })?;
+            Ok(row
+                .get_column_iter()
+                .map(|(_, field)| match field {
+                    Field::Null => String::new(),
+                    Field::Str(value) => value.clone(),
+                    other => other.to_string(),
+                })
+                .collect())
+        })
+        .collect::<Result<_, String>>()?;
+    Ok(DatasetPreview { columns, rows })
+}
+
+// 문자열 컬럼에서 보여줄 상위 값 개수
+const TOP_VALUES: usize = 10;
+// 고유값이 이만큼 넘으면 새 값은 더 세지 않는다. id 같은 컬럼 때문에 메모리가 커지지 않게 한다
+const MAX_TRACKED_VALUES: usize = 10_000;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValueCount {
+    pub value: String,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ColumnSummary {
+    Numeric {
+        min: f64,
+        max: f64,
+        mean: f64,
+        stddev: f64,
+    },
+    // distinct_truncated면 고유값이 MAX_TRACKED_VALUES에서 세기를 멈춘 것이라 distinct_count는
+    // 최소값이다
+    Categorical {
+        distinct_count: usize,
+        distinct_truncated: bool,
+    },
+    Text {
+        distinct_count: usize,
+        distinct_truncated: bool,
+        top_values: Vec<ValueCount>,
+    },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ColumnStats {
+    pub name: String,
+    pub data_type: ColumnType,
+    pub null_count: u64,
+    // 값이 하나도 없는 컬럼이면 None
+    pub summary: Option<ColumnSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DatasetStats {
+    pub row_count: u64,
+    pub columns: Vec<ColumnStats>,
+}
+
+#[command]
+pub async fn dataset_stats(app: AppHandle, dataset_id: String) -> Result<DatasetStats, String> {
+    let path = dataset_path(&app, &dataset_id)?;
+    if DatasetFormat::from_path(&path) != Some(DatasetFormat::Csv) {
+        return Err(format!(
+            "statistics are only available for CSV datasets: {}",
+            path.display()
+        ));
+    }
+    tauri::async_runtime::spawn_blocking(move || csv_stats(&path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// 매니페스트에 없는 파일은 list_datasets가 파일 이름을 id로 쓴다
+pub fn dataset_path<R: Runtime>(app: &AppHandle<R>, dataset_id: &str) -> Result<PathBuf, String> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    let project = guard.as_ref().ok_or("no project is open")?;
+    let path = match project.datasets.iter().find(|d| d.id == dataset_id) {
+        Some(dataset) => project.root.join(&dataset.path),
+        None => project.root.join(DATASETS_DIR).join(dataset_id),
+    };
+    if !path.is_file() {
+        return Err(format!("dataset {dataset_id} not found"));
+    }
+    Ok(path)
+}
+
+// 컬럼 하나의 누적 값. 숫자는 Welford 방식으로 평균/분산을 한 번에 구한다
+#[derive(Default)]
+struct Accumulator {
+    nulls: u64,
+    count: u64,
+    min: f64,
+    max: f64,
+    mean: f64,
+    m2: f64,
+    values: HashMap<String, u64>,
+    // 자리가 없어서 세지 못한 새 값이 있었다
+    truncated: bool,
+}
+
+impl Accumulator {
+    fn push_number(&mut self, value: f64) {
+        if self.count == 0 {
+            self.min = value;
+            self.max = value;
+        }
+        self.count += 1;
+        self.min = self.min.min(value);
+        self.max = self.max.max(value);
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    fn push_value(&mut self, value: &str) {
+        self.count += 1;
+        if let Some(count) = self.values.get_mut(value) {
+            *count += 1;
+        } else if self.values.len() < MAX_TRACKED_VALUES {
+            self.values.insert(value.to_string(), 1);
+        } else {
+            self.truncated = true;
+        }
+    }
+
+    fn summary(self, data_type: ColumnType) -> Option<ColumnSummary> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(match data_type {
+            ColumnType::Integer | ColumnType::Float => ColumnSummary::Numeric {
+                min: self.min,
+                max: self.max,
+                mean: self.mean,
+                stddev: (self.m2 / self.count as f64).sqrt(),
+            },
+            ColumnType::Boolean | ColumnType::Date => ColumnSummary::Categorical {
+                distinct_count: self.values.len(),
+                distinct_truncated: self.truncated,
+            },
+            ColumnType::String => {
+                let distinct_count = self.values.len();
+                let mut top_values: Vec<ValueCount> = self
+                    .values
+                    .into_iter()
+                    .map(|(value, count)| ValueCount { value, count })
+                    .collect();
+                top_values.sort_by(|a, b| b.count.cmp(&a.count).then(a.value.cmp(&b.value)));
+                top_values.truncate(TOP_VALUES);
+                ColumnSummary::Text {
+                    distinct_count,
+                    distinct_truncated: self.truncated,
+                    top_values,
+                }
+            }
+        })
+    }
+}
+
+// 타입은 infer_csv_schema의 샘플로 정하고, 통계는 파일 전체를 한 번 훑어서 구한다
+pub fn csv_stats(path: &Path) -> Result<DatasetStats, String> {
+    let empty = DatasetStats {
+        row_count: 0,
+        columns: Vec::new(),
+    };
+    if fs::metadata(path)
+        .map_err(|e| format!("failed to open {}: {e}", path.display()))?
+        .len()
+        == 0
+    {
+        return Ok(empty);
+    }
+    let schema = infer_csv_schema(path, DEFAULT_SAMPLE_ROWS)?;
+    let file = File::open(path).map_err(|e| format!("failed to open {}: {e}", path.display()))?;
+    let mut records = Records::new(BufReader::new(file));
+    if schema.has_header {
+        records.next();
+    }
+
+    let mut columns: Vec<Accumulator> = schema.columns.iter().map(|_| Default::default()).collect();
+    let mut row_count = 0u64;
+    for record in records {
+        let record = record.map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+        row_count += 1;
+        for (column, (info, acc)) in schema.columns.iter().zip(&mut columns).enumerate() {
+            let Some(value) = record
+                .get(column)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+            else {
+                acc.nulls += 1;
+                continue;
+            };
+            match info.data_type {
+                // 샘플 뒤에 숫자가 아닌 값이 나오면 통계에서만 뺀다
+                ColumnType::Integer | ColumnType::Float => {
+                    if let Some(number) = value.parse::<f64>().ok().filter(|n| n.is_finite()) {
+                        acc.push_number(number);
+                    }
+                }
+                _ => acc.push_value(value),
+            }
+        }
+    }
+    if row_count == 0 {
+        return Ok(empty);
+    }
+
+    let columns = schema
+        .columns
+        .into_iter()
+        .zip(columns)
+        .map(|(info, acc)| ColumnStats {
+            null_count: acc.nulls,
+            summary: acc.summary(info.data_type),
+            name: info.name,
+            data_type: info.data_type,
+        })
+        .collect();
+    Ok(DatasetStats { row_count, columns })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    #[test]
+    fn stats_cover_numeric_and_text_columns() {
+        let dir = TempDir::new("stats");
+        let csv = dir.join("data.csv");
+        fs::write(&csv, "score,label\n1,cat\n3,dog\n,cat\n").unwrap();
+        let header_only = dir.join("empty.csv");
+        fs::write(&header_only, "score,label\n").unwrap();
+
+        let stats = csv_stats(&csv).unwrap();
+        let empty = csv_stats(&header_only).unwrap();
+
+        assert_eq!(stats.row_count, 3);
+        assert_eq!(stats.columns[0].null_count, 1);
+        match &stats.columns[0].summary {
+            Some(ColumnSummary::Numeric { mean, stddev, .. }) => {
+                assert_eq!(*mean, 2.0);
+                assert_eq!(*stddev, 1.0);
+            }
+            other => panic!("expected numeric summary, got {other:?}"),
+        }
+        match &stats.columns[1].summary {
+            Some(ColumnSummary::Text { top_values, .. }) => {
+                assert_eq!(top_values[0].value, "cat");
+                assert_eq!(top_values[0].count, 2);
+            }
+            other => panic!("expected text summary, got {other:?}"),
+        }
+        assert_eq!(empty.row_count, 0);
+        assert!(empty.columns.is_empty());
+    }
+
+    #[test]
+    fn headerless_csv_counts_its_first_row() {
+        let dir = TempDir::new("headerless");
+        let csv = dir.join("data.csv");
+        fs::write(&csv, "1,2.5\n2,3.5\n3,4.5\n").unwrap();
+        let with_header = dir.join("header.csv");
+        fs::write(&with_header, "id,score\n1,2.5\n2,3.5\n").unwrap();
+
+        let schema = infer_csv_schema(&csv, 2).unwrap();
+        assert!(!schema.has_header);
+        assert_eq!(schema.sampled_rows, 2);
+        assert_eq!(schema.columns[0].name, "col_0");
+        assert_eq!(infer_csv_schema(&csv, 10).unwrap().sampled_rows, 3);
+        assert_eq!(infer_csv_schema(&with_header, 10).unwrap().sampled_rows, 2);
+        assert_eq!(count_csv_rows(&csv).unwrap(), 3);
+        assert_eq!(count_csv_rows(&with_header).unwrap(), 2);
+    }
+
+    #[test]
+    fn distinct_counts_say_when_they_stopped_counting() {
+        let mut column = Accumulator::default();
+        for i in 0..MAX_TRACKED_VALUES {
+            column.push_value(&format!("id-{i}"));
+        }
+        column.push_value("id-0");
+        let full = matches!(
+            column.summary(ColumnType::String),
+            Some(ColumnSummary::Text {
+                distinct_count: MAX_TRACKED_VALUES,
+                distinct_truncated: false,
+                ..
+            })
+        );
+        assert!(full);
+
+        let mut column = Accumulator::default();
+        for i in 0..=MAX_TRACKED_VALUES {
+            column.push_value(&format!("id-{i}"));
+        }
+        let truncated = matches!(
+            column.summary(ColumnType::String),
+            Some(ColumnSummary::Text {
+                distinct_count: MAX_TRACKED_VALUES,
+                distinct_truncated: true,
+                ..
+            })
+        );
+        assert!(truncated);
+    }
+
+    #[test]
+    fn preview_stops_at_limit_and_reports_bad_lines() {
+        let dir = TempDir::new("preview");
+        let csv = dir.join("data.csv");
+        fs::write(&csv, "name,note\na,\"x, y\"\nb,z\nc,w\n").unwrap();
+        let broken = dir.join("broken.csv");
+        fs::write(&broken, b"name\nok\n\xff\n").unwrap();
+
+        let preview = csv_preview(&csv, 2).unwrap();
+        let err = csv_preview(&broken, 10).unwrap_err();
+
+        assert_eq!(preview.columns, ["name", "note"]);
+        assert_eq!(preview.rows, [["a", "x, y"], ["b", "z"]]);
+        assert!(err.contains("line 3"), "{err}");
+    }
+}
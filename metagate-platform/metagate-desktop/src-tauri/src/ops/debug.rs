@@ -0,0 +1,230 @@
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::DialogExt;
+
+use super::app::{current_app_info, AppInfo};
+use super::autosave::AutoSave;
+use super::deploy::Deployments;
+use super::logs::LogStreams;
+use super::monitoring::SystemMonitor;
+use super::pipeline::PipelineRuns;
+use super::project::ProjectState;
+use super::train::TrainingRuns;
+use super::watch::ProjectWatcher;
+use crate::fs::preferences::Preferences;
+
+// 버그 제보에 붙이는 앱 내부 상태. 파일 내용은 넣지 않고 메타데이터만 담는다.
+// 경로는 프로젝트 폴더를 <project>, 홈 폴더를 ~로 바꿔서 사용자 이름 등이 드러나지 않게 한다
+#[derive(Debug, Clone, Serialize)]
+pub struct DebugSnapshot {
+    pub created_at: DateTime<Utc>,
+    pub app: AppInfo,
+    pub project: Option<ProjectSummary>,
+    pub pipeline_runs: Vec<PipelineRunSummary>,
+    pub training_runs: Vec<TrainingRunSummary>,
+    pub deployments: Vec<DeploymentSummary>,
+    pub watchers: Watchers,
+    pub preferences: Preferences,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectSummary {
+    pub name: String,
+    pub root: String,
+    pub version: String,
+    pub dirty: bool,
+    pub models: Vec<AssetSummary>,
+    pub datasets: Vec<AssetSummary>,
+    pub pipelines: Vec<AssetSummary>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetSummary {
+    pub id: String,
+    pub name: String,
+    pub path: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineRunSummary {
+    pub run_id: String,
+    pub pipeline_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingRunSummary {
+    pub run_id: String,
+    pub model_id: String,
+    pub dataset_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentSummary {
+    pub id: String,
+    pub model_id: String,
+    pub url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Watchers {
+    pub project: Option<String>,
+    pub log_streams: Vec<String>,
+    pub health_monitors: Vec<String>,
+    pub metrics_stream: bool,
+    pub autosave: bool,
+}
+
+#[command]
+pub fn debug_snapshot(app: AppHandle) -> DebugSnapshot {
+    snapshot(&app)
+}
+
+pub fn snapshot<R: Runtime>(app: &AppHandle<R>) -> DebugSnapshot {
+    let state = app.state::<ProjectState>();
+    let root = state.lock().as_ref().map(|project| project.root.clone());
+    let home = app.path().home_dir().ok();
+    let redact = |path: &Path| redact(path, root.as_deref(), home.as_deref());
+
+    let project = state.lock().as_ref().map(|project| {
+        let asset = |id: &str, name: &str, path: &Path| AssetSummary {
+            id: id.into(),
+            name: name.into(),
+            path: redact(path),
+        };
+        ProjectSummary {
+            name: project.name.clone(),
+            root: redact(&project.root),
+            version: project.version.clone(),
+            dirty: state.dirty.load(Ordering::SeqCst),
+            models: project
+                .models
+                .iter()
+                .map(|m| asset(&m.id, &m.name, &m.path))
+                .collect(),
+            datasets: project
+                .datasets
+                .iter()
+                .map(|d| asset(&d.id, &d.name, &d.path))
+                .collect(),
+            pipelines: project
+                .pipelines
+                .iter()
+                .map(|p| asset(&p.id, &p.name, &p.path))
+                .collect(),
+        }
+    });
+
+    let pipeline_runs = app
+        .state::<PipelineRuns>()
+        .lock()
+        .iter()
+        .map(|(run_id, run)| PipelineRunSummary {
+            run_id: run_id.clone(),
+            pipeline_id: run.pipeline_id.clone(),
+        })
+        .collect();
+    let training_runs = app
+        .state::<TrainingRuns>()
+        .lock()
+        .iter()
+        .map(|(run_id, run)| TrainingRunSummary {
+            run_id: run_id.clone(),
+            model_id: run.config.model_id.clone(),
+            dataset_id: run.config.dataset_id.clone(),
+        })
+        .collect();
+    let deployments = app.state::<Deployments>();
+    let watchers = Watchers {
+        project: app
+            .state::<ProjectWatcher>()
+            .watched_root()
+            .map(|root| redact(&root)),
+        log_streams: app.state::<LogStreams>().run_ids(),
+        health_monitors: deployments.monitored(),
+        metrics_stream: app.state::<SystemMonitor>().is_streaming(),
+        autosave: app.state::<AutoSave>().is_running(),
+    };
+
+    DebugSnapshot {
+        created_at: Utc::now(),
+        app: current_app_info(),
+        project,
+        pipeline_runs,
+        training_runs,
+        deployments: deployments
+            .list()
+            .into_iter()
+            .map(|d| DeploymentSummary {
+                id: d.id,
+                model_id: d.model_id,
+                url: d.url,
+            })
+            .collect(),
+        watchers,
+        preferences: Preferences::load(app),
+    }
+}
+
+// 프로젝트 폴더가 홈 폴더 아래에 있는 경우가 많으니 프로젝트부터 바꾼다
+fn redact(path: &Path, project: Option<&Path>, home: Option<&Path>) -> String {
+    let replaced = [(project, "<project>"), (home, "~")]
+        .into_iter()
+        .find_map(|(prefix, label)| {
+            let rest = path.strip_prefix(prefix?).ok()?;
+            Some(PathBuf::from(label).join(rest))
+        });
+    replaced
+        .unwrap_or_else(|| path.to_path_buf())
+        .display()
+        .to_string()
+}
+
+// Help 메뉴에서 부른다. 저장 위치를 고르지 않으면 아무것도 하지 않는다
+pub async fn save_snapshot<R: Runtime>(app: AppHandle<R>) -> Result<Option<PathBuf>, String> {
+    let dialog = app.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        dialog
+            .dialog()
+            .file()
+            .set_title("Save Debug Snapshot")
+            .add_filter("JSON", &["json"])
+            .set_file_name(format!(
+                "metagate-debug-{}.json",
+                Utc::now().format("%Y%m%d-%H%M%S")
+            ))
+            .blocking_save_file()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    let Some(picked) = picked else {
+        return Ok(None);
+    };
+    let dest = picked.into_path().map_err(|e| e.to_string())?;
+    let json = serde_json::to_vec_pretty(&snapshot(&app)).map_err(|e| e.to_string())?;
+    std::fs::write(&dest, json).map_err(|e| format!("failed to write {}: {e}", dest.display()))?;
+    Ok(Some(dest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact_hides_project_and_home_prefixes() {
+        let project = Path::new("/home/alice/work/demo");
+        let home = Path::new("/home/alice");
+        let redact = |path: &str| redact(Path::new(path), Some(project), Some(home));
+
+        assert_eq!(
+            redact("/home/alice/work/demo/models/a.onnx"),
+            "<project>/models/a.onnx"
+        );
+        assert_eq!(redact("/home/alice/data/iris.csv"), "~/data/iris.csv");
+        assert_eq!(redact("models/a.onnx"), "models/a.onnx");
+        assert_eq!(redact("/opt/shared/b.onnx"), "/opt/shared/b.onnx");
+    }
+}
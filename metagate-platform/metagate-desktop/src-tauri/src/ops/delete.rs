@@ -0,0 +1,388 @@
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::deploy::Deployments;
+use super::emit;
+use super::history::{self, UndoStack};
+use super::pipeline::{PipelineRuns, PipelineSchedules};
+use super::project::{project_info, ProjectState};
+use super::tabs::canonical;
+use super::train::TrainingRuns;
+use super::window::update_window_title;
+use crate::fs::pipeline::PipelineDefinition;
+use crate::fs::project::{AssetKind, Project};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeleteKind {
+    Model,
+    Dataset,
+    Pipeline,
+}
+
+impl From<AssetKind> for DeleteKind {
+    fn from(kind: AssetKind) -> Self {
+        match kind {
+            AssetKind::Model => DeleteKind::Model,
+            AssetKind::Dataset => DeleteKind::Dataset,
+        }
+    }
+}
+
+// confirm-delete 이벤트 내용. 프런트엔드가 이걸 보여주고 confirm_delete로 대답한다
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteConfirmation {
+    pub kind: DeleteKind,
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    pub permanent: bool,
+    // 이 항목을 가리키는 것들. 지우면 끊어진다
+    pub references: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct PendingRequest {
+    // 물어본 프로젝트. 대답하기 전에 다른 프로젝트로 바뀌었으면 지우지 않는다
+    root: PathBuf,
+    kind: DeleteKind,
+    id: String,
+    permanent: bool,
+}
+
+// 대답을 기다리는 삭제 요청. 새 요청이 오면 이전 요청은 버린다
+#[derive(Default)]
+pub struct PendingDelete(Mutex<Option<PendingRequest>>);
+
+impl PendingDelete {
+    fn lock(&self) -> MutexGuard<'_, Option<PendingRequest>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 프로젝트를 열거나 바꾸거나 닫으면 부른다
+    pub fn clear(&self) {
+        self.lock().take();
+    }
+}
+
+// 기본은 휴지통으로 보낸다. permanent면 바로 지운다
+#[command]
+pub fn delete_model(
+    app: AppHandle,
+    model_id: String,
+    permanent: Option<bool>,
+) -> Result<(), String> {
+    request_delete(
+        &app,
+        DeleteKind::Model,
+        model_id,
+        permanent.unwrap_or(false),
+    )
+}
+
+#[command]
+pub fn delete_dataset(
+    app: AppHandle,
+    dataset_id: String,
+    permanent: Option<bool>,
+) -> Result<(), String> {
+    request_delete(
+        &app,
+        DeleteKind::Dataset,
+        dataset_id,
+        permanent.unwrap_or(false),
+    )
+}
+
+#[command]
+pub fn delete_pipeline(
+    app: AppHandle,
+    pipeline_id: String,
+    permanent: Option<bool>,
+) -> Result<(), String> {
+    request_delete(
+        &app,
+        DeleteKind::Pipeline,
+        pipeline_id,
+        permanent.unwrap_or(false),
+    )
+}
+
+// 바로 지우지 않고 confirm-delete로 물어본다
+pub fn request_delete<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: DeleteKind,
+    id: String,
+    permanent: bool,
+) -> Result<(), String> {
+    let state = app.state::<ProjectState>();
+    state.ensure_writable()?;
+    let project = state.lock().clone().ok_or("no project is open")?;
+    let (name, path) = find(&project, kind, &id)?;
+    ensure_not_running(app, kind, &id)?;
+
+    let mut references = project_references(&project, kind, &id);
+    if kind == DeleteKind::Model {
+        references.extend(
+            app.state::<Deployments>()
+                .list()
+                .iter()
+                .filter(|d| d.model_id == id)
+                .map(|d| format!("deployment {}", d.id)),
+        );
+    }
+    if kind == DeleteKind::Pipeline {
+        let schedules = app
+            .state::<PipelineSchedules>()
+            .for_pipeline(&project.root, &id);
+        references.extend(schedules.iter().map(|s| format!("schedule {s}")));
+    }
+
+    *app.state::<PendingDelete>().lock() = Some(PendingRequest {
+        root: canonical(&project.root),
+        kind,
+        id: id.clone(),
+        permanent,
+    });
+    emit(
+        app,
+        "confirm-delete",
+        DeleteConfirmation {
+            kind,
+            id,
+            name,
+            path,
+            permanent,
+            references,
+        },
+    );
+    Ok(())
+}
+
+#[command]
+pub fn confirm_delete(app: AppHandle, delete: bool) -> Result<bool, String> {
+    answer_delete(&app, delete)
+}
+
+fn answer_delete<R: Runtime>(app: &AppHandle<R>, delete: bool) -> Result<bool, String> {
+    let Some(request) = app.state::<PendingDelete>().lock().take() else {
+        return Err("no delete is waiting for confirmation".into());
+    };
+    if !delete {
+        return Ok(false);
+    }
+    // 물어보는 사이에 실행이 시작됐을 수 있다
+    ensure_not_running(app, request.kind, &request.id)?;
+    let state = app.state::<ProjectState>();
+    let (root, path) = {
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        if canonical(&project.root) != request.root {
+            return Err("the project changed since the delete was requested".into());
+        }
+        let (_, path) = find(project, request.kind, &request.id)?;
+        (project.root.clone(), path)
+    };
+    // 그사이 읽기 전용으로 다시 열었으면 파일을 치우기 전에 멈춘다
+    state.ensure_writable()?;
+
+    // 파일을 먼저 치우고, 그게 안 되면 매니페스트도 건드리지 않는다
+    let file = root.join(&path);
+    if file.exists() {
+        remove_file(&file, request.permanent)?;
+    }
+    // 저장 안 된 다른 편집까지 같이 저장하지 않도록 지운 항목만 매니페스트에 쓴다
+    let removed = state.persist_change(&root, |project| match request.kind {
+        DeleteKind::Model => project.models.retain(|m| m.id != request.id),
+        DeleteKind::Dataset => project.datasets.retain(|d| d.id != request.id),
+        DeleteKind::Pipeline => project.pipelines.retain(|p| p.id != request.id),
+    })?;
+    if !removed {
+        return Err("the project changed while deleting; the manifest was not updated".into());
+    }
+    app.state::<UndoStack>().forget(request.kind, &request.id);
+    history::sync_menu(app);
+    update_window_title(app);
+    if request.kind == DeleteKind::Pipeline {
+        app.state::<PipelineSchedules>()
+            .remove_pipeline(app, &root, &request.id)?;
+    }
+
+    let info = state.lock().as_ref().map(project_info);
+    emit(app, "project-changed", info);
+    Ok(true)
+}
+
+fn remove_file(path: &Path, permanent: bool) -> Result<(), String> {
+    if permanent {
+        std::fs::remove_file(path).map_err(|e| format!("failed to delete {}: {e}", path.display()))
+    } else {
+        trash::delete(path)
+            .map_err(|e| format!("failed to move {} to the trash: {e}", path.display()))
+    }
+}
+
+fn find(project: &Project, kind: DeleteKind, id: &str) -> Result<(String, PathBuf), String> {
+    let found = match kind {
+        DeleteKind::Model => project
+            .models
+            .iter()
+            .find(|m| m.id == id)
+            .map(|m| (m.name.clone(), m.path.clone())),
+        DeleteKind::Dataset => project
+            .datasets
+            .iter()
+            .find(|d| d.id == id)
+            .map(|d| (d.name.clone(), d.path.clone())),
+        DeleteKind::Pipeline => project
+            .pipelines
+            .iter()
+            .find(|p| p.id == id)
+            .map(|p| (p.name.clone(), p.path.clone())),
+    };
+    found.ok_or_else(|| match kind {
+        DeleteKind::Model => format!("model {id} not found"),
+        DeleteKind::Dataset => format!("dataset {id} not found"),
+        DeleteKind::Pipeline => format!("pipeline {id} not found"),
+    })
+}
+
+// 돌고 있는 프로세스가 쓰는 파일은 지우지 않는다
+fn ensure_not_running<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: DeleteKind,
+    id: &str,
+) -> Result<(), String> {
+    let running = match kind {
+        DeleteKind::Model => app
+            .state::<TrainingRuns>()
+            .lock()
+            .values()
+            .any(|run| run.config.model_id == id),
+        DeleteKind::Dataset => app
+            .state::<TrainingRuns>()
+            .lock()
+            .values()
+            .any(|run| run.config.dataset_id == id),
+        DeleteKind::Pipeline => app
+            .state::<PipelineRuns>()
+            .lock()
+            .values()
+            .any(|run| run.pipeline_id == id),
+    };
+    if running {
+        return Err(format!("{id} is in use by a running job; stop it first"));
+    }
+    Ok(())
+}
+
+// 프로젝트 안에서 이 항목을 가리키는 것: 파이프라인 스테이지와 모델 버전
+fn project_references(project: &Project, kind: DeleteKind, id: &str) -> Vec<String> {
+    let mut references = Vec::new();
+    let param = match kind {
+        DeleteKind::Model => {
+            references.extend(
+                project
+                    .models
+                    .iter()
+                    .filter(|m| m.parent_id.as_deref() == Some(id))
+                    .map(|m| format!("model version {}", m.name)),
+            );
+            "model"
+        }
+        DeleteKind::Dataset => "dataset",
+        DeleteKind::Pipeline => return references,
+    };
+    let Ok((_, path)) = find(project, kind, id) else {
+        return references;
+    };
+    for pipeline in &project.pipelines {
+        let Ok(definition) = PipelineDefinition::load(&project.root.join(&pipeline.path)) else {
+            continue;
+        };
+        for stage in &definition.stages {
+            let uses = stage
+                .param_str(param)
+                .is_some_and(|value| value == id || Path::new(value) == path);
+            if uses {
+                references.push(format!("pipeline {} (stage {})", pipeline.name, stage.name));
+            }
+        }
+    }
+    references
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::project::{DatasetRef, PipelineRef};
+    use crate::testing::TempDir;
+
+    #[test]
+    fn pipelines_using_a_dataset_are_listed_as_references() {
+        let root = TempDir::new("delete");
+        std::fs::create_dir_all(root.join("pipelines")).unwrap();
+        std::fs::write(
+            root.join("pipelines/train.yaml"),
+            "name: Train\nstages:\n  - name: split\n    run: split\n    with:\n      dataset: datasets/iris.csv\n  - name: fit\n    run: train\n",
+        )
+        .unwrap();
+        let mut project = Project::new(&root, "demo");
+        project.datasets.push(DatasetRef {
+            id: "iris".into(),
+            name: "Iris".into(),
+            path: "datasets/iris.csv".into(),
+            tags: Vec::new(),
+            stats: None,
+        });
+        project.pipelines.push(PipelineRef {
+            id: "train".into(),
+            name: "Train".into(),
+            path: "pipelines/train.yaml".into(),
+            tags: Vec::new(),
+        });
+
+        let references = project_references(&project, DeleteKind::Dataset, "iris");
+        let missing = find(&project, DeleteKind::Model, "iris");
+
+        assert_eq!(references, ["pipeline Train (stage split)"]);
+        assert_eq!(missing, Err("model iris not found".to_string()));
+    }
+
+    #[test]
+    fn a_delete_confirmed_after_switching_projects_keeps_the_file() {
+        let first = TempDir::new("delete-first");
+        let second = TempDir::new("delete-second");
+        std::fs::create_dir_all(second.join("datasets")).unwrap();
+        std::fs::write(second.join("datasets/iris.csv"), "a\n1\n").unwrap();
+        let mut project = Project::new(&second, "second");
+        project.datasets.push(DatasetRef {
+            id: "iris".into(),
+            name: "Iris".into(),
+            path: "datasets/iris.csv".into(),
+            tags: Vec::new(),
+            stats: None,
+        });
+
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(PendingDelete::default());
+        app.manage(TrainingRuns::default());
+        app.manage(PipelineRuns::default());
+        let app = app.handle();
+        app.state::<ProjectState>().open(project);
+        *app.state::<PendingDelete>().lock() = Some(PendingRequest {
+            root: canonical(&first),
+            kind: DeleteKind::Dataset,
+            id: "iris".into(),
+            permanent: true,
+        });
+
+        assert!(answer_delete(app, true).is_err());
+        assert!(second.join("datasets/iris.csv").exists());
+        assert!(answer_delete(app, true).is_err());
+    }
+}
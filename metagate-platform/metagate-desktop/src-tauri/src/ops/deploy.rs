@@ -0,0 +1,712 @@
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::async_runtime::JoinHandle;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+use tokio::net::TcpStream;
+use tokio::process::{Child, Command};
+
+use super::emit;
+use super::model::{model_inputs, model_source};
+use super::notify::notify;
+use super::project::ProjectState;
+use crate::fs::menu_events::NavigationTarget;
+use crate::fs::onnx::TensorShape;
+use crate::fs::share::{parse_token, save_revoked, ShareKey, KEY_ENV, REVOKED_ENV};
+
+const SERVER_ENV: &str = "METAGATE_INFERENCE_SERVER";
+const DEFAULT_SERVER: &str = "metagate-serve";
+const STARTUP_TIMEOUT: Duration = Duration::from_secs(30);
+const STARTUP_POLL: Duration = Duration::from_millis(200);
+const HEALTH_PATH: &str = "/health";
+const HEALTH_TIMEOUT: Duration = Duration::from_secs(2);
+const MIN_HEALTH_INTERVAL: Duration = Duration::from_millis(500);
+const PREDICT_PATH: &str = "/predict";
+const INFERENCE_TIMEOUT: Duration = Duration::from_secs(30);
+// 데모용 링크라서 오래 살려 두지 않는다
+const MAX_SHARE_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+const SHARE_DIR: &str = "share";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeployTarget {
+    LocalServer { port: u16 },
+    Docker { image_tag: String },
+    Remote { endpoint: String },
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Deployment {
+    pub id: String,
+    pub model_id: String,
+    pub target: DeployTarget,
+    // 로컬 서버일 때만 채워진다
+    pub url: Option<String>,
+    pub deployed_at: DateTime<Utc>,
+}
+
+struct ActiveDeployment {
+    deployment: Deployment,
+    // 로컬 서버 프로세스. 목록에서 빠지면 kill_on_drop으로 같이 정리된다
+    server: Option<Child>,
+    // 로컬 서버만 공유 토큰을 받는다
+    share: Option<ShareLinks>,
+}
+
+struct ShareLinks {
+    key: ShareKey,
+    // 서버가 요청마다 읽는 취소 목록
+    revoked_file: PathBuf,
+    revoked: HashMap<String, i64>,
+}
+
+// 배포가 목록에서 빠지면 취소 목록도 쓸 데가 없다
+impl Drop for ShareLinks {
+    fn drop(&mut self) {
+        remove_share_file(&self.revoked_file);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ShareToken {
+    pub token: String,
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Default)]
+pub struct Deployments {
+    active: Mutex<HashMap<String, ActiveDeployment>>,
+    monitors: Mutex<HashMap<String, JoinHandle<()>>>,
+}
+
+impl Deployments {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, ActiveDeployment>> {
+        self.active.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_monitors(&self) -> MutexGuard<'_, HashMap<String, JoinHandle<()>>> {
+        self.monitors.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 표에서 빼고 abort까지 같은 락 안에서 한다. 태스크는 표에 없으면 보내지 않는다
+    pub fn stop_monitor(&self, deployment_id: &str) {
+        let mut monitors = self.lock_monitors();
+        if let Some(task) = monitors.remove(deployment_id) {
+            task.abort();
+        }
+    }
+
+    pub fn stop_all_monitors(&self) -> usize {
+        let mut monitors = self.lock_monitors();
+        let stopped = monitors.len();
+        for (_, task) in monitors.drain() {
+            task.abort();
+        }
+        stopped
+    }
+
+    pub fn monitored(&self) -> Vec<String> {
+        self.lock_monitors().keys().cloned().collect()
+    }
+
+    // 로컬 서버 프로세스가 이미 끝났으면 목록에서 빼고 None
+    fn endpoint(&self, deployment_id: &str) -> Result<Option<String>, String> {
+        let mut active = self.lock();
+        let deployment = active
+            .get_mut(deployment_id)
+            .ok_or_else(|| format!("deployment {deployment_id} not found"))?;
+        let exited = deployment
+            .server
+            .as_mut()
+            .is_some_and(|server| !matches!(server.try_wait(), Ok(None)));
+        if exited {
+            log::warn!("inference server for deployment {deployment_id} has exited");
+            active.remove(deployment_id);
+            return Ok(None);
+        }
+        let endpoint = match &deployment.deployment.target {
+            DeployTarget::Remote { endpoint } => endpoint.clone(),
+            _ => deployment
+                .deployment
+                .url
+                .clone()
+                .ok_or_else(|| format!("deployment {deployment_id} has no endpoint"))?,
+        };
+        Ok(Some(endpoint))
+    }
+
+    fn model_id(&self, deployment_id: &str) -> Option<String> {
+        self.lock()
+            .get(deployment_id)
+            .map(|active| active.deployment.model_id.clone())
+    }
+
+    pub fn list(&self) -> Vec<Deployment> {
+        self.lock()
+            .values()
+            .map(|active| active.deployment.clone())
+            .collect()
+    }
+}
+
+#[command]
+pub fn list_deployments(deployments: State<'_, Deployments>) -> Vec<Deployment> {
+    deployments.list()
+}
+
+#[command]
+pub async fn deploy_model(
+    app: AppHandle,
+    model_id: String,
+    target: DeployTarget,
+) -> Result<Deployment, String> {
+    let result = deploy(&app, &model_id, target).await;
+    let body = match &result {
+        Ok(deployment) => match &deployment.url {
+            Some(url) => format!("Model {model_id} is serving at {url}"),
+            None => format!("Model {model_id} was deployed"),
+        },
+        Err(err) => format!("Deploying model {model_id} failed: {err}"),
+    };
+    notify(&app, "Deployment", &body, NavigationTarget::Models);
+    result
+}
+
+pub async fn deploy<R: Runtime>(
+    app: &AppHandle<R>,
+    model_id: &str,
+    target: DeployTarget,
+) -> Result<Deployment, String> {
+    let (source, _) = model_source(&app.state::<ProjectState>(), model_id)?;
+    let id = uuid::Uuid::new_v4().to_string();
+
+    let (url, server, share) = match &target {
+        DeployTarget::LocalServer { port } => {
+            let share = share_links(app, &id)?;
+            let server = start_local_server(&source, *port, &share).await?;
+            (
+                Some(format!("http://127.0.0.1:{port}")),
+                Some(server),
+                Some(share),
+            )
+        }
+        DeployTarget::Docker { image_tag } => {
+            run_server_tool(&["docker", "--tag", image_tag], &source).await?;
+            (None, None, None)
+        }
+        DeployTarget::Remote { endpoint } => {
+            run_server_tool(&["push", "--endpoint", endpoint], &source).await?;
+            (None, None, None)
+        }
+    };
+
+    let deployment = Deployment {
+        id,
+        model_id: model_id.into(),
+        target,
+        url,
+        deployed_at: Utc::now(),
+    };
+    app.state::<Deployments>().lock().insert(
+        deployment.id.clone(),
+        ActiveDeployment {
+            deployment: deployment.clone(),
+            server,
+            share,
+        },
+    );
+    Ok(deployment)
+}
+
+// 배포 목록은 앱을 끄면 사라지니 시작할 때 share/에 남은 파일은 모두 지난 세션의 것이다
+pub fn clear_share_files<R: Runtime>(app: &AppHandle<R>) {
+    let Ok(dir) = app.path().app_data_dir().map(|dir| dir.join(SHARE_DIR)) else {
+        return;
+    };
+    let Ok(entries) = std::fs::read_dir(&dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        remove_share_file(&entry.path());
+    }
+}
+
+fn remove_share_file(path: &Path) {
+    match std::fs::remove_file(path) {
+        Ok(()) => {}
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+        Err(err) => log::warn!("failed to remove {}: {err}", path.display()),
+    }
+}
+
+// 취소 목록은 서버를 띄우기 전에 빈 파일로 만들어 둔다
+fn share_links<R: Runtime>(app: &AppHandle<R>, deployment_id: &str) -> Result<ShareLinks, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(SHARE_DIR);
+    let mut share = ShareLinks {
+        key: ShareKey::generate()?,
+        revoked_file: dir.join(format!("{deployment_id}.json")),
+        revoked: HashMap::new(),
+    };
+    save_revoked(
+        &share.revoked_file,
+        &mut share.revoked,
+        Utc::now().timestamp(),
+    )
+    .map_err(|e| format!("failed to create {}: {e}", share.revoked_file.display()))?;
+    Ok(share)
+}
+
+// 만든 사람 말고도 열 수 있는 읽기 전용 링크. 서버가 서명과 만료를 확인한다
+#[command]
+pub fn create_share_token(
+    deployments: State<'_, Deployments>,
+    deployment_id: String,
+    expires_in_secs: u64,
+) -> Result<ShareToken, String> {
+    if expires_in_secs == 0 || expires_in_secs > MAX_SHARE_TTL_SECS {
+        return Err(format!(
+            "share links must expire within 1 to {MAX_SHARE_TTL_SECS} seconds"
+        ));
+    }
+    let expires_at = Utc::now() + chrono::Duration::seconds(expires_in_secs as i64);
+    let active = deployments.lock();
+    let deployment = active
+        .get(&deployment_id)
+        .ok_or_else(|| format!("deployment {deployment_id} not found"))?;
+    let (Some(share), Some(url)) = (&deployment.share, &deployment.deployment.url) else {
+        return Err("share links are only available for local server deployments".into());
+    };
+    let token = share.key.sign(&deployment_id, expires_at.timestamp())?;
+    Ok(ShareToken {
+        url: format!("{}{PREDICT_PATH}?token={token}", url.trim_end_matches('/')),
+        token,
+        expires_at,
+    })
+}
+
+// 이미 만료된 토큰이면 할 일이 없다
+#[command]
+pub fn revoke_share_token(
+    deployments: State<'_, Deployments>,
+    token: String,
+) -> Result<(), String> {
+    let parsed = parse_token(&token)?;
+    let now = Utc::now().timestamp();
+    let mut active = deployments.lock();
+    let share = active
+        .get_mut(&parsed.deployment_id)
+        .and_then(|deployment| deployment.share.as_mut())
+        .ok_or_else(|| format!("deployment {} not found", parsed.deployment_id))?;
+    let parsed = match share.key.verify(&token, now, &share.revoked) {
+        Ok(parsed) => parsed,
+        Err(_) if parsed.expires_at <= now => return Ok(()),
+        Err(err) => return Err(err),
+    };
+    share.revoked.insert(parsed.nonce, parsed.expires_at);
+    save_revoked(&share.revoked_file, &mut share.revoked, now)
+        .map_err(|e| format!("failed to save {}: {e}", share.revoked_file.display()))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum HealthStatus {
+    Healthy,
+    Unhealthy { reason: String },
+    Unreachable,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentHealth {
+    pub deployment_id: String,
+    #[serde(flatten)]
+    pub health: HealthStatus,
+}
+
+#[command]
+pub async fn deployment_health(
+    app: AppHandle,
+    deployment_id: String,
+) -> Result<HealthStatus, String> {
+    check_health(&app, &deployment_id).await
+}
+
+#[command]
+pub fn start_health_monitor(
+    app: AppHandle,
+    deployment_id: String,
+    interval_ms: u64,
+) -> Result<(), String> {
+    let deployments = app.state::<Deployments>();
+    if !deployments.lock().contains_key(&deployment_id) {
+        return Err(format!("deployment {deployment_id} not found"));
+    }
+
+    let interval = Duration::from_millis(interval_ms).max(MIN_HEALTH_INTERVAL);
+    let handle = app.clone();
+    let id = deployment_id.clone();
+    // 태스크는 보내기 전에 이 표를 보므로, 등록을 마칠 때까지 락을 잡고 있어야 한다
+    let mut monitors = deployments.lock_monitors();
+    let task = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let Ok(health) = check_health(&handle, &id).await else {
+                break;
+            };
+            let deployments = handle.state::<Deployments>();
+            let gone = health == HealthStatus::Unreachable && !deployments.lock().contains_key(&id);
+            // 멈췄거나 새 모니터로 바뀌었으면 abort가 닿기 전이라도 더 보내지 않는다
+            let monitors = deployments.lock_monitors();
+            if !is_current_monitor(&monitors, &id) {
+                return;
+            }
+            emit(
+                &handle,
+                "deployment-health",
+                DeploymentHealth {
+                    deployment_id: id.clone(),
+                    health,
+                },
+            );
+            drop(monitors);
+            // 서버가 죽어서 목록에서 빠졌으면 더 물어볼 곳이 없다
+            if gone {
+                break;
+            }
+        }
+        let deployments = handle.state::<Deployments>();
+        let mut monitors = deployments.lock_monitors();
+        if is_current_monitor(&monitors, &id) {
+            monitors.remove(&id);
+        }
+    });
+    if let Some(previous) = monitors.insert(deployment_id, task) {
+        previous.abort();
+    }
+    Ok(())
+}
+
+// 지금 도는 태스크가 이 배포에 등록된 모니터인지
+fn is_current_monitor(monitors: &HashMap<String, JoinHandle<()>>, deployment_id: &str) -> bool {
+    monitors
+        .get(deployment_id)
+        .is_some_and(|task| task.inner().id() == tokio::task::id())
+}
+
+#[command]
+pub fn stop_health_monitor(deployments: State<'_, Deployments>, deployment_id: String) {
+    deployments.stop_monitor(&deployment_id);
+}
+
+// 로컬 서버 프로세스가 이미 끝났으면 요청을 보내지 않고 목록에서 뺀다
+pub async fn check_health<R: Runtime>(
+    app: &AppHandle<R>,
+    deployment_id: &str,
+) -> Result<HealthStatus, String> {
+    let Some(endpoint) = app.state::<Deployments>().endpoint(deployment_id)? else {
+        return Ok(HealthStatus::Unreachable);
+    };
+
+    let url = format!("{}{HEALTH_PATH}", endpoint.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(HEALTH_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    Ok(match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => HealthStatus::Healthy,
+        Ok(response) => HealthStatus::Unhealthy {
+            reason: format!("{url} returned {}", response.status()),
+        },
+        Err(err) if err.is_connect() || err.is_timeout() => HealthStatus::Unreachable,
+        Err(err) => HealthStatus::Unhealthy {
+            reason: err.to_string(),
+        },
+    })
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InferenceResult {
+    pub output: serde_json::Value,
+    pub latency_ms: f64,
+}
+
+// 배포한 모델에 시험 삼아 요청을 보낸다. 모양이 맞지 않는 입력은 보내기 전에 거른다.
+// 시간 초과와 서버의 에러 응답은 메시지로 구분한다
+#[command]
+pub async fn test_inference(
+    app: AppHandle,
+    deployment_id: String,
+    input: serde_json::Value,
+) -> Result<InferenceResult, String> {
+    let deployments = app.state::<Deployments>();
+    let model_id = deployments
+        .model_id(&deployment_id)
+        .ok_or_else(|| format!("deployment {deployment_id} not found"))?;
+    let endpoint = deployments
+        .endpoint(&deployment_id)?
+        .ok_or_else(|| format!("the inference server for deployment {deployment_id} has exited"))?;
+
+    // 프로젝트에서 모델이 빠졌거나 입력 모양을 읽을 수 없는 포맷이면 검사 없이 보낸다
+    if let Ok((model, _)) = model_source(&app.state::<ProjectState>(), &model_id) {
+        let inputs = tauri::async_runtime::spawn_blocking(move || model_inputs(&model))
+            .await
+            .map_err(|e| e.to_string())?;
+        if let Ok(Some(inputs)) = inputs {
+            check_input(&inputs, &input)
+                .map_err(|e| format!("input does not match the model: {e}"))?;
+        }
+    }
+
+    let url = format!("{}{PREDICT_PATH}", endpoint.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .timeout(INFERENCE_TIMEOUT)
+        .build()
+        .map_err(|e| e.to_string())?;
+    let request_err = |err: reqwest::Error| {
+        if err.is_timeout() {
+            format!(
+                "inference request timed out after {}s",
+                INFERENCE_TIMEOUT.as_secs()
+            )
+        } else {
+            format!("inference request to {url} failed: {err}")
+        }
+    };
+    let started = Instant::now();
+    let response = client
+        .post(&url)
+        .json(&input)
+        .send()
+        .await
+        .map_err(request_err)?;
+    let status = response.status();
+    let body = response.text().await.map_err(request_err)?;
+    let latency = started.elapsed();
+
+    if !status.is_success() {
+        return Err(format!(
+            "inference server returned {status}: {}",
+            body.trim()
+        ));
+    }
+    let output = serde_json::from_str(&body)
+        .map_err(|e| format!("inference server returned invalid JSON: {e}"))?;
+    Ok(InferenceResult {
+        output,
+        latency_ms: latency.as_secs_f64() * 1000.0,
+    })
+}
+
+// 입력이 하나면 값을 그대로, 여러 개면 {"입력 이름": 값} 객체로 받는다
+pub fn check_input(inputs: &[TensorShape], input: &serde_json::Value) -> Result<(), String> {
+    let keyed = match input {
+        serde_json::Value::Object(fields) => {
+            inputs.len() > 1 || inputs.iter().any(|shape| fields.contains_key(&shape.name))
+        }
+        _ => false,
+    };
+    match (inputs, keyed) {
+        ([], _) => Ok(()),
+        (_, true) => inputs.iter().try_for_each(|shape| {
+            let value = input
+                .get(&shape.name)
+                .ok_or_else(|| format!("missing input \"{}\"", shape.name))?;
+            check_tensor(shape, value)
+        }),
+        ([shape], false) => check_tensor(shape, input),
+        _ => Err(format!(
+            "the model takes {} inputs; send an object keyed by input name",
+            inputs.len()
+        )),
+    }
+}
+
+// 숫자로 정해진 차원만 비교한다. 배치 차원을 빼고 예시 하나만 보내도 받아 준다
+fn check_tensor(shape: &TensorShape, value: &serde_json::Value) -> Result<(), String> {
+    let name = &shape.name;
+    let actual = json_shape(value)
+        .ok_or_else(|| format!("input \"{name}\" must be a rectangular array of values"))?;
+    let skipped = match shape.dims.len().checked_sub(actual.len()) {
+        Some(0) => 0,
+        Some(1) => 1,
+        _ => {
+            return Err(format!(
+                "input \"{name}\" should have {} dimensions but has {}",
+                shape.dims.len(),
+                actual.len()
+            ))
+        }
+    };
+    for (i, (dim, len)) in shape.dims.iter().skip(skipped).zip(&actual).enumerate() {
+        if let Ok(expected) = dim.parse::<usize>() {
+            if expected != *len {
+                return Err(format!(
+                    "input \"{name}\" should have {expected} values in dimension {} but has {len}",
+                    i + skipped
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+// 중첩 배열의 모양. 길이가 제각각이거나 null, 객체가 섞여 있으면 None
+fn json_shape(value: &serde_json::Value) -> Option<Vec<usize>> {
+    match value {
+        serde_json::Value::Array(items) => {
+            let inner = match items.first() {
+                Some(first) => json_shape(first)?,
+                None => Vec::new(),
+            };
+            for item in items.iter().skip(1) {
+                if json_shape(item)? != inner {
+                    return None;
+                }
+            }
+            Some([vec![items.len()], inner].concat())
+        }
+        serde_json::Value::Number(_)
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::String(_) => Some(Vec::new()),
+        serde_json::Value::Null | serde_json::Value::Object(_) => None,
+    }
+}
+
+fn server_binary() -> String {
+    std::env::var(SERVER_ENV).unwrap_or_else(|_| DEFAULT_SERVER.into())
+}
+
+// 포트가 이미 쓰이고 있으면 서버를 띄우기 전에 실패시킨다.
+// 띄운 뒤에는 실제로 접속이 될 때까지 기다렸다가 돌려준다
+async fn start_local_server(model: &Path, port: u16, share: &ShareLinks) -> Result<Child, String> {
+    TcpListener::bind((Ipv4Addr::LOCALHOST, port))
+        .map_err(|_| format!("port {port} is already in use"))?;
+
+    let server = server_binary();
+    let mut child = Command::new(&server)
+        .arg("serve")
+        .arg("--model")
+        .arg(model)
+        .arg("--port")
+        .arg(port.to_string())
+        .env(KEY_ENV, share.key.hex())
+        .env(REVOKED_ENV, &share.revoked_file)
+        .stdout(Stdio::null())
+        .stderr(Stdio::inherit())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("failed to start inference server {server}: {e}"))?;
+
+    let started = tokio::time::timeout(STARTUP_TIMEOUT, async {
+        loop {
+            if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+                return Err(format!("inference server exited with {status}"));
+            }
+            if TcpStream::connect((Ipv4Addr::LOCALHOST, port))
+                .await
+                .is_ok()
+            {
+                return Ok(());
+            }
+            tokio::time::sleep(STARTUP_POLL).await;
+        }
+    })
+    .await;
+
+    match started {
+        Ok(Ok(())) => Ok(child),
+        Ok(Err(err)) => Err(err),
+        Err(_) => {
+            let _ = child.kill().await;
+            Err(format!(
+                "inference server did not start listening on port {port} within {}s",
+                STARTUP_TIMEOUT.as_secs()
+            ))
+        }
+    }
+}
+
+async fn run_server_tool(args: &[&str], model: &Path) -> Result<(), String> {
+    let server = server_binary();
+    let output = Command::new(&server)
+        .args(args)
+        .arg("--model")
+        .arg(model)
+        .output()
+        .await
+        .map_err(|e| format!("failed to run {server}: {e}"))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{server} exited with {}: {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use serde_json::json;
+
+    fn shape(name: &str, dims: &[&str]) -> TensorShape {
+        TensorShape {
+            name: name.into(),
+            dims: dims.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn input_is_checked_against_fixed_dimensions() {
+        let single = [shape("features", &["batch", "3"])];
+        assert!(check_input(&single, &json!([[1, 2, 3], [4, 5, 6]])).is_ok());
+        assert!(check_input(&single, &json!([1, 2, 3])).is_ok());
+        assert!(check_input(&single, &json!({ "features": [[1, 2, 3]] })).is_ok());
+        assert_eq!(
+            check_input(&single, &json!([[1, 2]])).unwrap_err(),
+            "input \"features\" should have 3 values in dimension 1 but has 2"
+        );
+        assert!(check_input(&single, &json!([[1, 2, 3], [4]])).is_err());
+        assert!(check_input(&single, &json!(1)).is_err());
+
+        let pair = [
+            shape("ids", &["batch", "?"]),
+            shape("mask", &["batch", "?"]),
+        ];
+        assert!(check_input(&pair, &json!({ "ids": [[1]], "mask": [[1]] })).is_ok());
+        assert_eq!(
+            check_input(&pair, &json!({ "ids": [[1]] })).unwrap_err(),
+            "missing input \"mask\""
+        );
+        assert!(check_input(&pair, &json!([[1]])).is_err());
+    }
+
+    #[test]
+    fn dropping_share_links_removes_the_revoked_file() {
+        let dir = TempDir::new("share");
+        let mut share = ShareLinks {
+            key: ShareKey::generate().unwrap(),
+            revoked_file: dir.join("d1.json"),
+            revoked: HashMap::new(),
+        };
+        save_revoked(&share.revoked_file, &mut share.revoked, 0).unwrap();
+        assert!(dir.join("d1.json").exists());
+
+        drop(share);
+        assert!(!dir.join("d1.json").exists());
+    }
+}
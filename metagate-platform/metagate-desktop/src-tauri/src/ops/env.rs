@@ -0,0 +1,94 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use tauri::{command, State};
+
+// 파이프라인과 트레이너에 넘길 비밀 값(API 키 등). 메모리에만 두고 매니페스트나
+// 실행 기록에는 쓰지 않으므로 앱을 다시 켜면 다시 넣어야 한다
+#[derive(Default)]
+pub struct SecretEnv(Mutex<BTreeMap<String, String>>);
+
+impl SecretEnv {
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<String, String>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn snapshot(&self) -> BTreeMap<String, String> {
+        self.lock().clone()
+    }
+}
+
+#[command]
+pub fn set_secret_env(
+    secrets: State<'_, SecretEnv>,
+    name: String,
+    value: String,
+) -> Result<(), String> {
+    validate_env_var(&name, &value)?;
+    secrets.lock().insert(name, value);
+    Ok(())
+}
+
+#[command]
+pub fn remove_secret_env(secrets: State<'_, SecretEnv>, name: String) {
+    secrets.lock().remove(&name);
+}
+
+// 값은 돌려주지 않는다
+#[command]
+pub fn list_secret_env(secrets: State<'_, SecretEnv>) -> Vec<String> {
+    secrets.lock().keys().cloned().collect()
+}
+
+// 물려받은 환경 위에 정의의 기본값, 비밀 값, 실행할 때 넘긴 값 순서로 덮어쓴다
+pub fn process_env(
+    defaults: &BTreeMap<String, String>,
+    secrets: &BTreeMap<String, String>,
+    overrides: &HashMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    let mut env = defaults.clone();
+    env.extend(secrets.iter().map(|(k, v)| (k.clone(), v.clone())));
+    env.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+    for (name, value) in &env {
+        validate_env_var(name, value)?;
+    }
+    Ok(env)
+}
+
+// 이름에 =나 NUL이 있으면 spawn이 실패하거나 다른 변수로 읽힌다
+pub fn validate_env_var(name: &str, value: &str) -> Result<(), String> {
+    if name.is_empty() {
+        return Err("environment variable name cannot be empty".into());
+    }
+    if name.contains(['=', '\0']) {
+        return Err(format!("invalid environment variable name {name:?}"));
+    }
+    if value.contains('\0') {
+        return Err(format!("environment variable {name} contains a NUL byte"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_win_and_invalid_names_are_rejected() {
+        let defaults = BTreeMap::from([
+            ("MODE".to_string(), "dev".to_string()),
+            ("TOKEN".to_string(), "placeholder".to_string()),
+        ]);
+        let secrets = BTreeMap::from([("TOKEN".to_string(), "s3cret".to_string())]);
+        let overrides = HashMap::from([("MODE".to_string(), "prod".to_string())]);
+
+        let env = process_env(&defaults, &secrets, &overrides).unwrap();
+        assert_eq!(env["MODE"], "prod");
+        assert_eq!(env["TOKEN"], "s3cret");
+
+        let bad = HashMap::from([("A=B".to_string(), "1".to_string())]);
+        assert!(process_env(&defaults, &secrets, &bad).is_err());
+        assert!(validate_env_var("", "1").is_err());
+        assert!(validate_env_var("PATH", "a\0b").is_err());
+    }
+}
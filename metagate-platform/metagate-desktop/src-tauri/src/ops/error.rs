@@ -0,0 +1,71 @@
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use serde::Serialize;
+
+// 프론트엔드가 일반 실패와 내부 오류(패닉)를 구분해서 보여줄 수 있게 종류를 같이 보낸다
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum CommandError {
+    Failed(String),
+    Panicked(String),
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CommandError::Failed(message) => write!(f, "{message}"),
+            CommandError::Panicked(message) => write!(f, "internal error: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl From<String> for CommandError {
+    fn from(message: String) -> Self {
+        CommandError::Failed(message)
+    }
+}
+
+impl From<&str> for CommandError {
+    fn from(message: &str) -> Self {
+        CommandError::Failed(message.into())
+    }
+}
+
+// 파일 파싱처럼 입력에 따라 패닉이 날 수 있는 부분을 감싼다.
+// 백트레이스는 logging::init이 건 패닉 훅이 로그에 남긴다
+pub fn guard<T>(f: impl FnOnce() -> Result<T, CommandError>) -> Result<T, CommandError> {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            log::error!("command panicked: {message}");
+            Err(CommandError::Panicked(message))
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        (*message).to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_become_errors() {
+        let result: Result<(), _> = guard(|| panic!("truncated file"));
+        assert_eq!(result, Err(CommandError::Panicked("truncated file".into())));
+        assert_eq!(guard(|| Ok(1)), Ok(1));
+    }
+}
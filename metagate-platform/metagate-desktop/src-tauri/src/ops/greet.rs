@@ -1,5 +1,6 @@
 use tauri::command;
 
+#[deprecated(note = "scaffold leftover, use `app_info` instead")]
 #[command]
 pub fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
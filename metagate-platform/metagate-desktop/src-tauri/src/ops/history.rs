@@ -0,0 +1,348 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, MutexGuard};
+
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::delete::DeleteKind;
+use super::emit;
+use super::project::{project_info, project_renamed, ProjectState};
+use super::window::update_window_title;
+use crate::fs::menu::find_menu_item;
+use crate::fs::preferences::Preferences;
+use crate::fs::project::{AssetKind, DatasetRef, ModelRef, PipelineRef, Project};
+
+pub const UNDO_ITEM: &str = "undo";
+pub const REDO_ITEM: &str = "redo";
+
+// 되돌릴 수 있는 프로젝트 변경. 되돌릴 때 필요한 값(원래 위치, 이전 이름)을 같이 들고 있다
+#[derive(Debug, Clone)]
+pub enum ProjectCommand {
+    AddModel(ModelRef),
+    AddPipeline(PipelineRef),
+    RemoveModel {
+        index: usize,
+        model: ModelRef,
+    },
+    RemoveDataset {
+        index: usize,
+        dataset: DatasetRef,
+    },
+    Rename {
+        from: String,
+        to: String,
+    },
+    UpdateModel {
+        before: ModelRef,
+        after: ModelRef,
+    },
+    AddTag {
+        kind: AssetKind,
+        id: String,
+        tag: String,
+    },
+    RemoveTag {
+        kind: AssetKind,
+        id: String,
+        index: usize,
+        tag: String,
+    },
+}
+
+impl ProjectCommand {
+    fn is_rename(&self) -> bool {
+        matches!(self, ProjectCommand::Rename { .. })
+    }
+
+    // 이 항목을 가리키는 기록인지. 이름 바꾸기는 어느 항목도 가리키지 않는다
+    fn refers_to(&self, kind: DeleteKind, id: &str) -> bool {
+        let (target, target_id) = match self {
+            ProjectCommand::AddModel(model)
+            | ProjectCommand::RemoveModel { model, .. }
+            | ProjectCommand::UpdateModel { after: model, .. } => (DeleteKind::Model, &model.id),
+            ProjectCommand::RemoveDataset { dataset, .. } => (DeleteKind::Dataset, &dataset.id),
+            ProjectCommand::AddPipeline(pipeline) => (DeleteKind::Pipeline, &pipeline.id),
+            ProjectCommand::AddTag { kind, id, .. }
+            | ProjectCommand::RemoveTag { kind, id, .. } => (DeleteKind::from(*kind), id),
+            ProjectCommand::Rename { .. } => return false,
+        };
+        target == kind && target_id == id
+    }
+
+    fn apply(&self, project: &mut Project) {
+        match self {
+            ProjectCommand::AddModel(model) => project.models.push(model.clone()),
+            ProjectCommand::AddPipeline(pipeline) => project.pipelines.push(pipeline.clone()),
+            ProjectCommand::RemoveModel { model, .. } => {
+                project.models.retain(|m| m.id != model.id)
+            }
+            ProjectCommand::RemoveDataset { dataset, .. } => {
+                project.datasets.retain(|d| d.id != dataset.id)
+            }
+            ProjectCommand::Rename { to, .. } => project.name = to.clone(),
+            ProjectCommand::UpdateModel { after, .. } => replace_model(project, after),
+            ProjectCommand::AddTag { kind, id, tag } => {
+                if let Some(tags) = project.tags_mut(*kind, id) {
+                    tags.push(tag.clone());
+                }
+            }
+            ProjectCommand::RemoveTag { kind, id, tag, .. } => {
+                if let Some(tags) = project.tags_mut(*kind, id) {
+                    tags.retain(|t| t != tag);
+                }
+            }
+        }
+    }
+
+    fn revert(&self, project: &mut Project) {
+        match self {
+            ProjectCommand::AddModel(model) => project.models.retain(|m| m.id != model.id),
+            // 복사한 정의 파일은 redo 때 다시 쓰이므로 지우지 않는다
+            ProjectCommand::AddPipeline(pipeline) => {
+                project.pipelines.retain(|p| p.id != pipeline.id)
+            }
+            ProjectCommand::RemoveModel { index, model } => {
+                let index = (*index).min(project.models.len());
+                project.models.insert(index, model.clone());
+            }
+            ProjectCommand::RemoveDataset { index, dataset } => {
+                let index = (*index).min(project.datasets.len());
+                project.datasets.insert(index, dataset.clone());
+            }
+            ProjectCommand::Rename { from, .. } => project.name = from.clone(),
+            ProjectCommand::UpdateModel { before, .. } => replace_model(project, before),
+            ProjectCommand::AddTag { kind, id, tag } => {
+                if let Some(tags) = project.tags_mut(*kind, id) {
+                    tags.retain(|t| t != tag);
+                }
+            }
+            ProjectCommand::RemoveTag {
+                kind,
+                id,
+                index,
+                tag,
+            } => {
+                if let Some(tags) = project.tags_mut(*kind, id) {
+                    let index = (*index).min(tags.len());
+                    tags.insert(index, tag.clone());
+                }
+            }
+        }
+    }
+}
+
+fn replace_model(project: &mut Project, model: &ModelRef) {
+    if let Some(slot) = project.models.iter_mut().find(|m| m.id == model.id) {
+        *slot = model.clone();
+    }
+}
+
+#[derive(Default)]
+pub struct History {
+    undo: VecDeque<ProjectCommand>,
+    redo: Vec<ProjectCommand>,
+}
+
+#[derive(Default)]
+pub struct UndoStack {
+    history: Mutex<History>,
+}
+
+impl UndoStack {
+    fn lock(&self) -> MutexGuard<'_, History> {
+        self.history.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.lock().undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.lock().redo.is_empty()
+    }
+
+    // 새 변경이 들어오면 redo 기록은 버린다. 오래된 기록은 depth를 넘으면 앞에서부터 지운다
+    fn record(&self, command: ProjectCommand, depth: usize) {
+        let mut history = self.lock();
+        history.redo.clear();
+        history.undo.push_back(command);
+        while history.undo.len() > depth {
+            history.undo.pop_front();
+        }
+    }
+
+    pub fn clear(&self) {
+        *self.lock() = History::default();
+    }
+
+    // 지웠거나 다른 프로젝트로 옮긴 항목의 기록을 버린다. 남겨 두면 되돌리기나 다시 실행이
+    // 이미 없는 파일을 가리키는 항목을 되살린다
+    pub fn forget(&self, kind: DeleteKind, id: &str) {
+        let mut history = self.lock();
+        history.undo.retain(|command| !command.refers_to(kind, id));
+        history.redo.retain(|command| !command.refers_to(kind, id));
+    }
+
+    // 프로젝트 탭을 바꿀 때 떼어 뒀다가 돌아오면 되돌려 놓는다
+    pub fn take(&self) -> History {
+        std::mem::take(&mut *self.lock())
+    }
+
+    pub fn restore(&self, history: History) {
+        *self.lock() = history;
+    }
+}
+
+// 프로젝트를 바꾸는 커맨드는 이걸 거쳐서 실행 취소 기록에 남긴다
+pub fn execute<R: Runtime>(app: &AppHandle<R>, command: ProjectCommand) -> Result<(), String> {
+    app.state::<ProjectState>()
+        .mutate(|project| command.apply(project))?;
+    let depth = Preferences::load(app).undo_history_depth;
+    let renamed = command.is_rename();
+    app.state::<UndoStack>().record(command, depth);
+    changed(app, renamed);
+    Ok(())
+}
+
+#[command]
+pub fn undo(app: AppHandle) -> Result<bool, String> {
+    undo_last(&app)
+}
+
+#[command]
+pub fn redo(app: AppHandle) -> Result<bool, String> {
+    redo_last(&app)
+}
+
+// 되돌릴 게 없으면 false. 프로젝트를 바꾸지 못했으면 기록을 제자리에 돌려놓는다
+pub fn undo_last<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
+    let stack = app.state::<UndoStack>();
+    let Some(command) = stack.lock().undo.pop_back() else {
+        return Ok(false);
+    };
+    if let Err(err) = app
+        .state::<ProjectState>()
+        .mutate(|project| command.revert(project))
+    {
+        stack.lock().undo.push_back(command);
+        return Err(err);
+    }
+    let renamed = command.is_rename();
+    stack.lock().redo.push(command);
+    changed(app, renamed);
+    Ok(true)
+}
+
+pub fn redo_last<R: Runtime>(app: &AppHandle<R>) -> Result<bool, String> {
+    let stack = app.state::<UndoStack>();
+    let Some(command) = stack.lock().redo.pop() else {
+        return Ok(false);
+    };
+    if let Err(err) = app
+        .state::<ProjectState>()
+        .mutate(|project| command.apply(project))
+    {
+        stack.lock().redo.push(command);
+        return Err(err);
+    }
+    let renamed = command.is_rename();
+    stack.lock().undo.push_back(command);
+    changed(app, renamed);
+    Ok(true)
+}
+
+// 다른 프로젝트를 열면 이전 프로젝트의 기록은 의미가 없다
+pub fn reset<R: Runtime>(app: &AppHandle<R>) {
+    app.state::<UndoStack>().clear();
+    sync_menu(app);
+}
+
+fn changed<R: Runtime>(app: &AppHandle<R>, renamed: bool) {
+    sync_menu(app);
+    update_window_title(app);
+    if renamed {
+        project_renamed(app);
+    }
+    let info = app
+        .state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .map(project_info);
+    emit(app, "project-changed", info);
+}
+
+pub fn sync_menu<R: Runtime>(app: &AppHandle<R>) {
+    let stack = app.state::<UndoStack>();
+    for (id, enabled) in [(UNDO_ITEM, stack.can_undo()), (REDO_ITEM, stack.can_redo())] {
+        let Some(item) = find_menu_item(app, id) else {
+            continue;
+        };
+        if let Some(Err(err)) = item.as_menuitem().map(|i| i.set_enabled(enabled)) {
+            log::warn!("failed to update {id} menu item: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::project::ModelFormat;
+
+    #[test]
+    fn forgetting_an_asset_drops_only_its_entries() {
+        let model = |id: &str| ModelRef {
+            id: id.into(),
+            name: id.into(),
+            path: format!("models/{id}.onnx").into(),
+            format: ModelFormat::Onnx,
+            size_bytes: 0,
+            imported_at: chrono::Utc::now(),
+            sha256: None,
+            tags: Vec::new(),
+            description: None,
+            parent_id: None,
+        };
+        let stack = UndoStack::default();
+        stack.record(ProjectCommand::AddModel(model("a")), 10);
+        stack.record(ProjectCommand::AddModel(model("b")), 10);
+        stack.record(
+            ProjectCommand::AddTag {
+                kind: AssetKind::Model,
+                id: "a".into(),
+                tag: "prod".into(),
+            },
+            10,
+        );
+        stack.lock().redo.push(ProjectCommand::RemoveModel {
+            index: 0,
+            model: model("a"),
+        });
+
+        stack.forget(DeleteKind::Dataset, "a");
+        assert_eq!(stack.lock().undo.len(), 3);
+        stack.forget(DeleteKind::Model, "a");
+        let history = stack.lock();
+        let left: Vec<_> = history.undo.iter().collect();
+        assert!(matches!(left[..], [ProjectCommand::AddModel(ref m)] if m.id == "b"));
+        assert!(history.redo.is_empty());
+    }
+
+    #[test]
+    fn failed_undo_and_redo_keep_their_entries() {
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(UndoStack::default());
+        let app = app.handle();
+        let rename = || ProjectCommand::Rename {
+            from: "a".into(),
+            to: "b".into(),
+        };
+        let stack = app.state::<UndoStack>();
+        stack.record(rename(), 10);
+        stack.lock().redo.push(rename());
+
+        // 열린 프로젝트가 없으니 둘 다 실패한다
+        assert!(undo_last(app).is_err());
+        assert!(redo_last(app).is_err());
+        assert!(stack.can_undo() && stack.can_redo());
+    }
+}
@@ -0,0 +1,322 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+use super::emit;
+use super::pipeline::RunId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    Pipeline,
+    Training,
+}
+
+// 자리가 나면 부른다. 프로세스를 띄우고 실행 목록에 넣는 것까지 한다
+type StartJob = Box<dyn FnOnce() -> Result<(), String> + Send>;
+
+struct QueuedJob {
+    run_id: RunId,
+    kind: JobKind,
+    // 파이프라인 id 또는 학습할 모델 id
+    target: String,
+    start: StartJob,
+}
+
+#[derive(Default)]
+struct Slots {
+    // 0이면 제한하지 않는다
+    limit: usize,
+    queue_when_full: bool,
+    running: HashMap<RunId, (JobKind, String)>,
+    queue: VecDeque<QueuedJob>,
+}
+
+impl Slots {
+    fn has_room(&self) -> bool {
+        self.limit == 0 || self.running.len() < self.limit
+    }
+
+    fn is_busy(&self, kind: JobKind, target: &str) -> bool {
+        let running = self.running.values().map(|(k, t)| (*k, t.as_str()));
+        let queued = self.queue.iter().map(|job| (job.kind, job.target.as_str()));
+        running.chain(queued).any(|job| job == (kind, target))
+    }
+}
+
+// 동시에 도는 파이프라인과 학습 수를 제한하는 세마포어. 자리가 없으면 설정에 따라
+// 큐에 넣거나 거절하고, 실행이 끝나 자리가 나면 큐의 다음 작업을 띄운다
+#[derive(Default)]
+pub struct JobSlots(Mutex<Slots>);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum Admission {
+    Started,
+    Queued,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobInfo {
+    pub run_id: RunId,
+    pub kind: JobKind,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobQueueStatus {
+    pub limit: usize,
+    pub running: usize,
+    pub queued: usize,
+    // 먼저 들어온 것부터
+    pub queue: Vec<JobInfo>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JobFailed {
+    pub run_id: RunId,
+    pub error: String,
+}
+
+impl JobSlots {
+    pub fn new(limit: usize, queue_when_full: bool) -> Self {
+        JobSlots(Mutex::new(Slots {
+            limit,
+            queue_when_full,
+            ..Slots::default()
+        }))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Slots> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 한도를 올렸으면 기다리던 작업을 바로 띄운다. 동기 커맨드에서 불리면 tokio 런타임 밖이라
+    // 프로세스를 만들 수 없으니 런타임 태스크에서 띄운다
+    pub fn set_limits<R: Runtime>(&self, app: &AppHandle<R>, limit: usize, queue_when_full: bool) {
+        {
+            let mut slots = self.lock();
+            slots.limit = limit;
+            slots.queue_when_full = queue_when_full;
+        }
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            app.state::<JobSlots>().start_queued(&app);
+        });
+    }
+
+    pub fn submit<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        run_id: RunId,
+        kind: JobKind,
+        target: String,
+        start: impl FnOnce() -> Result<(), String> + Send + 'static,
+    ) -> Result<Admission, String> {
+        let mut slots = self.lock();
+        // 확인과 자리 잡기를 같은 락 안에서 해야 같은 대상을 두 번 띄우지 않는다
+        if slots.is_busy(kind, &target) {
+            return Err(match kind {
+                JobKind::Pipeline => format!("pipeline {target} is already running"),
+                JobKind::Training => format!("model {target} is already training"),
+            });
+        }
+        if slots.has_room() {
+            slots.running.insert(run_id.clone(), (kind, target));
+            drop(slots);
+            if let Err(err) = start() {
+                self.release(app, &run_id);
+                return Err(err);
+            }
+            return Ok(Admission::Started);
+        }
+        if !slots.queue_when_full {
+            return Err(format!(
+                "{} jobs are already running (limit {})",
+                slots.running.len(),
+                slots.limit
+            ));
+        }
+        slots.queue.push_back(QueuedJob {
+            run_id: run_id.clone(),
+            kind,
+            target: target.clone(),
+            start: Box::new(start),
+        });
+        drop(slots);
+        emit(
+            app,
+            "job-queued",
+            JobInfo {
+                run_id,
+                kind,
+                target,
+            },
+        );
+        Ok(Admission::Queued)
+    }
+
+    // 실행을 지켜보는 태스크가 끝날 때 부른다. 자리를 잡지 않은 run_id면 아무 일도 없다
+    pub fn release<R: Runtime>(&self, app: &AppHandle<R>, run_id: &str) {
+        if self.lock().running.remove(run_id).is_some() {
+            self.start_queued(app);
+        }
+    }
+
+    fn start_queued<R: Runtime>(&self, app: &AppHandle<R>) {
+        loop {
+            let job = {
+                let mut slots = self.lock();
+                if !slots.has_room() {
+                    return;
+                }
+                let Some(job) = slots.queue.pop_front() else {
+                    return;
+                };
+                slots
+                    .running
+                    .insert(job.run_id.clone(), (job.kind, job.target.clone()));
+                job
+            };
+            match (job.start)() {
+                Ok(()) => emit(app, "job-started", job.run_id),
+                Err(error) => {
+                    log::warn!("queued job {} failed to start: {error}", job.run_id);
+                    self.lock().running.remove(&job.run_id);
+                    emit(
+                        app,
+                        "job-failed",
+                        JobFailed {
+                            run_id: job.run_id,
+                            error,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    // 아직 시작하지 않은 작업을 취소한다
+    pub fn dequeue(&self, run_id: &str) -> bool {
+        let mut slots = self.lock();
+        let before = slots.queue.len();
+        slots.queue.retain(|job| job.run_id != run_id);
+        slots.queue.len() != before
+    }
+
+    pub fn is_queued(&self, kind: JobKind, target: &str) -> bool {
+        self.lock()
+            .queue
+            .iter()
+            .any(|job| job.kind == kind && job.target == target)
+    }
+
+    // 종료나 초기화 때 부른다. 버린 작업 수
+    pub fn clear_queue(&self) -> usize {
+        let mut slots = self.lock();
+        let cleared = slots.queue.len();
+        slots.queue.clear();
+        cleared
+    }
+
+    pub fn status(&self) -> JobQueueStatus {
+        let slots = self.lock();
+        JobQueueStatus {
+            limit: slots.limit,
+            running: slots.running.len(),
+            queued: slots.queue.len(),
+            queue: slots
+                .queue
+                .iter()
+                .map(|job| JobInfo {
+                    run_id: job.run_id.clone(),
+                    kind: job.kind,
+                    target: job.target.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[command]
+pub fn job_queue_status(slots: State<'_, JobSlots>) -> JobQueueStatus {
+    slots.status()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[test]
+    fn full_slots_queue_and_release_starts_the_next_job() {
+        let app = tauri::test::mock_app();
+        let app = app.handle();
+        let slots = JobSlots::new(1, true);
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let start = |id: &'static str| {
+            let started = started.clone();
+            move || {
+                started.lock().unwrap().push(id);
+                Ok(())
+            }
+        };
+
+        let first = slots.submit(app, "a".into(), JobKind::Training, "m1".into(), start("a"));
+        let second = slots.submit(app, "b".into(), JobKind::Pipeline, "p1".into(), start("b"));
+        assert_eq!(first, Ok(Admission::Started));
+        assert_eq!(second, Ok(Admission::Queued));
+        assert!(slots.is_queued(JobKind::Pipeline, "p1"));
+        assert_eq!((slots.status().running, slots.status().queued), (1, 1));
+
+        slots.release(app, "a");
+        assert_eq!(*started.lock().unwrap(), ["a", "b"]);
+        assert_eq!((slots.status().running, slots.status().queued), (1, 0));
+
+        let duplicate = slots.submit(app, "c".into(), JobKind::Pipeline, "p1".into(), || Ok(()));
+        assert_eq!(duplicate, Err("pipeline p1 is already running".into()));
+        slots.release(app, "b");
+        let again = slots.submit(app, "d".into(), JobKind::Pipeline, "p1".into(), || Ok(()));
+        assert_eq!(again, Ok(Admission::Started));
+        // 큐에서 기다리는 대상도 다시 받지 않는다
+        let _ = slots.submit(app, "e".into(), JobKind::Training, "m2".into(), || Ok(()));
+        assert!(slots
+            .submit(app, "f".into(), JobKind::Training, "m2".into(), || Ok(()))
+            .is_err());
+
+        let rejecting = JobSlots::new(1, false);
+        let _ = rejecting.submit(app, "c".into(), JobKind::Training, "m2".into(), || Ok(()));
+        assert!(rejecting
+            .submit(app, "d".into(), JobKind::Training, "m3".into(), || Ok(()))
+            .is_err());
+    }
+
+    #[test]
+    fn raising_the_limit_starts_queued_jobs_inside_the_runtime() {
+        let app = tauri::test::mock_app();
+        app.manage(JobSlots::new(1, true));
+        let app = app.handle();
+        let slots = app.state::<JobSlots>();
+        let in_runtime = Arc::new(Mutex::new(None));
+
+        let _ = slots.submit(app, "a".into(), JobKind::Training, "m1".into(), || Ok(()));
+        let seen = in_runtime.clone();
+        let queued = slots.submit(app, "b".into(), JobKind::Pipeline, "p1".into(), move || {
+            *seen.lock().unwrap() = Some(tokio::runtime::Handle::try_current().is_ok());
+            Ok(())
+        });
+        assert_eq!(queued, Ok(Admission::Queued));
+
+        // 동기 커맨드처럼 런타임 밖에서 한도를 올린다
+        slots.set_limits(app, 2, true);
+        for _ in 0..200 {
+            if in_runtime.lock().unwrap().is_some() {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        assert_eq!(*in_runtime.lock().unwrap(), Some(true));
+        assert_eq!((slots.status().running, slots.status().queued), (2, 0));
+    }
+}
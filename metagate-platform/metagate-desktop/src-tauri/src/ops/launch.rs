@@ -0,0 +1,85 @@
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::emit;
+use super::project::{open_project_at, ProjectInfo};
+use crate::fs::project::MANIFEST_FILE;
+
+// 프론트엔드가 뜨기 전에 연 프로젝트는 페이지 로드가 끝난 뒤에 알린다
+#[derive(Default)]
+pub struct LaunchProject {
+    ready: AtomicBool,
+    pending: Mutex<Option<ProjectInfo>>,
+}
+
+// `metagate /path/to/project`나 `metagate /path/to/project/metagate.json`으로 실행한 경우
+pub fn open_from_args<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(path) = project_arg(std::env::args_os().skip(1)) {
+        open_launch_path(app, &path);
+    }
+}
+
+// 맞는 프로젝트가 아니면 경고만 남기고 프로젝트 없이 시작한다
+pub fn open_launch_path<R: Runtime>(app: &AppHandle<R>, path: &Path) {
+    let dir = project_dir(path);
+    let info = match open_project_at(app, &dir) {
+        Ok(info) => info,
+        Err(err) => {
+            log::warn!("ignoring launch path {}: {err}", path.display());
+            return;
+        }
+    };
+
+    let launch = app.state::<LaunchProject>();
+    if launch.ready.load(Ordering::SeqCst) {
+        emit(app, "project-opened", info);
+    } else {
+        *launch.pending.lock().unwrap_or_else(|e| e.into_inner()) = Some(info);
+    }
+}
+
+pub fn page_loaded<R: Runtime>(app: &AppHandle<R>) {
+    let launch = app.state::<LaunchProject>();
+    launch.ready.store(true, Ordering::SeqCst);
+    let pending = launch
+        .pending
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+    if let Some(info) = pending {
+        emit(app, "project-opened", info);
+    }
+}
+
+// 플래그(-로 시작하는 인자)는 건너뛰고 첫 번째 경로만 본다
+fn project_arg(args: impl IntoIterator<Item = OsString>) -> Option<PathBuf> {
+    args.into_iter()
+        .find(|arg| !arg.to_string_lossy().starts_with('-'))
+        .map(PathBuf::from)
+}
+
+fn project_dir(path: &Path) -> PathBuf {
+    match path.parent() {
+        Some(parent) if path.file_name().is_some_and(|name| name == MANIFEST_FILE) => {
+            parent.to_path_buf()
+        }
+        _ => path.to_path_buf(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manifest_argument_opens_its_directory() {
+        let arg = project_arg(["--verbose".into(), "/work/p/metagate.json".into()]).unwrap();
+        assert_eq!(project_dir(&arg), Path::new("/work/p"));
+        assert_eq!(project_dir(Path::new("/work/p")), Path::new("/work/p"));
+        assert!(project_arg(Vec::<OsString>::new()).is_none());
+    }
+}
@@ -0,0 +1,41 @@
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::emit;
+use crate::fs::locale::{available_locales, resolve, MenuLocale};
+use crate::fs::menu::refresh_menu;
+use crate::fs::preferences::Preferences;
+
+#[command]
+pub fn list_locales() -> Vec<&'static str> {
+    available_locales()
+}
+
+// 저장하고 메뉴를 그 언어로 다시 만든다. "ko-KR"처럼 넘겨도 있는 파일로 맞춘다
+#[command]
+pub fn set_locale(app: AppHandle, locale: String) -> Result<(), String> {
+    let locale = supported_locale(&locale)?;
+    let mut preferences = Preferences::load(&app);
+    preferences.locale = locale.into();
+    preferences.save(&app).map_err(|e| e.to_string())?;
+    sync_locale(&app, locale);
+    emit(&app, "preferences-changed", preferences);
+    Ok(())
+}
+
+pub fn supported_locale(locale: &str) -> Result<&'static str, String> {
+    resolve(locale).ok_or_else(|| {
+        format!(
+            "no translations for {locale}; available: {}",
+            available_locales().join(", ")
+        )
+    })
+}
+
+// 환경설정 창에서 통째로 저장한 경우에도 불러서 메뉴를 맞춘다
+pub fn sync_locale<R: Runtime>(app: &AppHandle<R>, locale: &str) {
+    let state = app.state::<MenuLocale>();
+    if state.get() != locale {
+        state.set(locale.into());
+        refresh_menu(app);
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashMap;
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::async_runtime::JoinHandle;
+use tauri::{command, AppHandle, Manager, Runtime, State, Window};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+use super::emit;
+use super::pipeline::{RunId, LOG_FILE};
+use super::project::ProjectState;
+use crate::fs::project::run_dir;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+struct LogStream {
+    window: String,
+    task: JoinHandle<()>,
+}
+
+#[derive(Default)]
+pub struct LogStreams {
+    streams: Mutex<HashMap<RunId, LogStream>>,
+}
+
+impl LogStreams {
+    fn lock(&self) -> MutexGuard<'_, HashMap<RunId, LogStream>> {
+        self.streams.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn close(&self, run_id: &str) {
+        if let Some(stream) = self.lock().remove(run_id) {
+            stream.task.abort();
+        }
+    }
+
+    pub fn close_all(&self) -> usize {
+        let streams: Vec<LogStream> = self.lock().drain().map(|(_, stream)| stream).collect();
+        for stream in &streams {
+            stream.task.abort();
+        }
+        streams.len()
+    }
+
+    pub fn run_ids(&self) -> Vec<RunId> {
+        self.lock().keys().cloned().collect()
+    }
+
+    // 뷰어 창이 닫히면 그 창이 연 스트림을 모두 정리한다
+    pub fn close_for_window(&self, label: &str) {
+        self.lock().retain(|_, stream| {
+            let owned = stream.window == label;
+            if owned {
+                stream.task.abort();
+            }
+            !owned
+        });
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    pub run_id: RunId,
+    pub line: String,
+}
+
+#[command]
+pub fn open_log_stream(app: AppHandle, window: Window, run_id: String) -> Result<(), String> {
+    let path = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        run_dir(&project.root, &run_id).join(LOG_FILE)
+    };
+    if !path.is_file() {
+        return Err(format!("no log found for run {run_id}"));
+    }
+
+    let streams = app.state::<LogStreams>();
+    let mut streams = streams.lock();
+    if streams.contains_key(&run_id) {
+        return Ok(());
+    }
+    let task = tauri::async_runtime::spawn(tail(app.clone(), run_id.clone(), path));
+    streams.insert(
+        run_id,
+        LogStream {
+            window: window.label().to_string(),
+            task,
+        },
+    );
+    Ok(())
+}
+
+#[command]
+pub fn close_log_stream(streams: State<'_, LogStreams>, run_id: String) {
+    streams.close(&run_id);
+}
+
+// 읽은 위치(offset)를 기억해 두고 새로 붙은 부분만 줄 단위로 보낸다
+async fn tail<R: Runtime>(app: AppHandle<R>, run_id: RunId, path: PathBuf) {
+    let mut offset = 0u64;
+    let mut pending = Vec::new();
+    loop {
+        match read_from(&path, offset).await {
+            Ok(chunk) if !chunk.is_empty() => {
+                offset += chunk.len() as u64;
+                pending.extend_from_slice(&chunk);
+                while let Some(end) = pending.iter().position(|b| *b == b'\n') {
+                    let line: Vec<u8> = pending.drain(..=end).collect();
+                    let line = String::from_utf8_lossy(&line);
+                    emit(
+                        &app,
+                        "log-line",
+                        LogLine {
+                            run_id: run_id.clone(),
+                            line: line.trim_end_matches(['\r', '\n']).to_string(),
+                        },
+                    );
+                }
+            }
+            Ok(_) => {}
+            Err(err) => log::warn!("failed to read log for run {run_id}: {err}"),
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn read_from(path: &Path, offset: u64) -> std::io::Result<Vec<u8>> {
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    let mut chunk = Vec::new();
+    file.read_to_end(&mut chunk).await?;
+    Ok(chunk)
+}
@@ -1 +1,44 @@
+pub mod app;
+pub mod autosave;
+pub mod clipboard;
+pub mod dataset;
+pub mod debug;
+pub mod delete;
+pub mod deploy;
+pub mod env;
+pub mod error;
 pub mod greet;
+pub mod history;
+pub mod jobs;
+pub mod launch;
+pub mod locale;
+pub mod logs;
+pub mod model;
+pub mod monitoring;
+pub mod notify;
+pub mod operation;
+pub mod palette;
+pub mod pipeline;
+pub mod preferences;
+pub mod process;
+pub mod project;
+pub mod shortcuts;
+pub mod shutdown;
+pub mod status;
+pub mod tabs;
+pub mod timing;
+pub mod train;
+pub mod update;
+pub mod view;
+pub mod watch;
+pub mod window;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Runtime};
+
+// 이벤트 전송 실패는 커맨드를 실패시킬 일은 아니라서 로그만 남긴다
+pub fn emit<R: Runtime, S: Serialize + Clone>(app: &AppHandle<R>, event: &str, payload: S) {
+    if let Err(err) = app.emit(event, payload) {
+        log::error!("failed to emit {event} event: {err}");
+    }
+}
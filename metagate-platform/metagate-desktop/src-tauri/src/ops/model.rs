@@ -0,0 +1,1067 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::DialogExt;
+
+use super::emit;
+use super::error::{guard, CommandError};
+use super::history::{self, ProjectCommand};
+use super::operation::{remove_partial, Operation, OperationKind};
+use super::project::{normalize_tag, ProjectState, READ_ONLY_ERROR};
+use crate::fs::hash::{copy_and_hash, hash_file, hash_file_with_progress};
+use crate::fs::onnx::{parse_graph, OnnxGraph, TensorShape};
+use crate::fs::project::{ModelFormat, ModelRef};
+
+pub const MODEL_EXTENSIONS: [&str; 4] = ["onnx", "pt", "pth", "safetensors"];
+pub const MODELS_DIR: &str = "models";
+
+const CANCEL_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+const CONVERTER_ENV: &str = "METAGATE_CONVERTER";
+const DEFAULT_CONVERTER: &str = "metagate-convert";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Onnx,
+    TorchScript,
+    // SavedModel은 디렉토리라서 zip으로 묶어서 내보낸다
+    SavedModel,
+}
+
+impl ExportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Onnx => "onnx",
+            ExportFormat::TorchScript => "pt",
+            ExportFormat::SavedModel => "zip",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Onnx => "ONNX",
+            ExportFormat::TorchScript => "TorchScript",
+            ExportFormat::SavedModel => "TensorFlow SavedModel",
+        }
+    }
+
+    fn converter_arg(self) -> &'static str {
+        match self {
+            ExportFormat::Onnx => "onnx",
+            ExportFormat::TorchScript => "torchscript",
+            ExportFormat::SavedModel => "savedmodel",
+        }
+    }
+}
+
+#[command]
+pub async fn export_model(
+    app: AppHandle,
+    model_id: String,
+    format: ExportFormat,
+) -> Result<PathBuf, String> {
+    export_model_as(app, model_id, format).await
+}
+
+pub async fn export_model_as<R: Runtime>(
+    app: AppHandle<R>,
+    model_id: String,
+    format: ExportFormat,
+) -> Result<PathBuf, String> {
+    let (source, name) = model_source(&app.state::<ProjectState>(), &model_id)?;
+
+    let dialog = app.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        dialog
+            .dialog()
+            .file()
+            .set_title("Export Model")
+            .add_filter(format.label(), &[format.extension()])
+            .set_file_name(format!("{name}.{}", format.extension()))
+            .blocking_save_file()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let dest = picked
+        .ok_or("export cancelled")?
+        .into_path()
+        .map_err(|e| e.to_string())?;
+    let dest = dest.with_extension(format.extension());
+    ensure_writable(dest.parent().unwrap_or(Path::new(".")))?;
+
+    let output = dest.clone();
+    let operation = Operation::start(&app, OperationKind::Export, &dest);
+    tauri::async_runtime::spawn_blocking(move || convert(&source, &output, format, &operation))
+        .await
+        .map_err(|e| e.to_string())??;
+    Ok(dest)
+}
+
+// 두 값이 다르면 changed가 켜진다. 프론트엔드는 changed인 줄만 강조한다
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldDiff<T> {
+    pub a: T,
+    pub b: T,
+    pub changed: bool,
+}
+
+impl<T: PartialEq> FieldDiff<T> {
+    fn new(a: T, b: T) -> Self {
+        let changed = a != b;
+        FieldDiff { a, b, changed }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelDiff {
+    pub a: String,
+    pub b: String,
+    pub format: FieldDiff<ModelFormat>,
+    pub size_bytes: FieldDiff<u64>,
+    pub sha256: FieldDiff<String>,
+    // ONNX 그래프의 노드 수. 다른 포맷은 알 수 없어서 None
+    pub layer_count: FieldDiff<Option<usize>>,
+    pub inputs: FieldDiff<Vec<TensorShape>>,
+    pub outputs: FieldDiff<Vec<TensorShape>>,
+}
+
+struct ModelMetadata {
+    format: ModelFormat,
+    size_bytes: u64,
+    sha256: String,
+    graph: Option<OnnxGraph>,
+}
+
+#[command]
+pub async fn diff_models(app: AppHandle, a: String, b: String) -> Result<ModelDiff, String> {
+    let state = app.state::<ProjectState>();
+    let (path_a, _) = model_source(&state, &a)?;
+    let (path_b, _) = model_source(&state, &b)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let left = inspect_model(&path_a)?;
+        let right = inspect_model(&path_b)?;
+        let (left_graph, right_graph) = (
+            left.graph.unwrap_or_default(),
+            right.graph.unwrap_or_default(),
+        );
+        Ok(ModelDiff {
+            a,
+            b,
+            format: FieldDiff::new(left.format, right.format),
+            size_bytes: FieldDiff::new(left.size_bytes, right.size_bytes),
+            sha256: FieldDiff::new(left.sha256, right.sha256),
+            layer_count: FieldDiff::new(
+                (left.format == ModelFormat::Onnx).then_some(left_graph.node_count),
+                (right.format == ModelFormat::Onnx).then_some(right_graph.node_count),
+            ),
+            inputs: FieldDiff::new(left_graph.inputs, right_graph.inputs),
+            outputs: FieldDiff::new(left_graph.outputs, right_graph.outputs),
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// 모델 상세 패널용. 그래프를 읽을 수 있는 건 ONNX뿐이다
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum GraphSummary {
+    Onnx {
+        inputs: Vec<TensorShape>,
+        outputs: Vec<TensorShape>,
+        op_counts: BTreeMap<String, usize>,
+        parameter_count: u64,
+    },
+    Unsupported {
+        format: ModelFormat,
+    },
+}
+
+#[command]
+pub async fn model_graph_summary(app: AppHandle, model_id: String) -> Result<GraphSummary, String> {
+    let (path, _) = model_source(&app.state::<ProjectState>(), &model_id)?;
+    tauri::async_runtime::spawn_blocking(move || graph_summary(&path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn graph_summary(path: &Path) -> Result<GraphSummary, String> {
+    let format = detect_format(path).map_err(|e| e.to_string())?;
+    if format != ModelFormat::Onnx {
+        return Ok(GraphSummary::Unsupported { format });
+    }
+    let bytes = fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+    let graph = parse_graph(&bytes)
+        .map_err(|e| format!("{} is not a valid ONNX model: {e}", path.display()))?;
+    Ok(GraphSummary::Onnx {
+        inputs: graph.inputs,
+        outputs: graph.outputs,
+        op_counts: graph.op_counts,
+        parameter_count: graph.parameter_count,
+    })
+}
+
+// 입력 모양은 ONNX만 알 수 있다. 다른 포맷이면 None
+pub fn model_inputs(path: &Path) -> Result<Option<Vec<TensorShape>>, String> {
+    match detect_format(path).map_err(|e| e.to_string())? {
+        ModelFormat::Onnx => {
+            let bytes =
+                fs::read(path).map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+            let graph = parse_graph(&bytes).map_err(|e| format!("{}: {e}", path.display()))?;
+            Ok(Some(graph.inputs))
+        }
+        ModelFormat::TorchScript | ModelFormat::SafeTensors => Ok(None),
+    }
+}
+
+// ONNX는 그래프까지 읽고, 나머지 포맷은 크기와 해시만 본다
+fn inspect_model(path: &Path) -> Result<ModelMetadata, String> {
+    let format = detect_format(path).map_err(|e| e.to_string())?;
+    let read_err = |e: io::Error| format!("failed to read {}: {e}", path.display());
+
+    let size_bytes = fs::metadata(path).map_err(read_err)?.len();
+    let sha256 = hash_file(path).map_err(read_err)?;
+
+    // protobuf 전체를 한 번에 읽어야 해서 ONNX만 메모리에 올린다. ONNX 파일은 2GB를 넘지 않는다
+    let graph = match format {
+        ModelFormat::Onnx => {
+            let bytes = fs::read(path).map_err(read_err)?;
+            Some(parse_graph(&bytes).map_err(|e| format!("{}: {e}", path.display()))?)
+        }
+        ModelFormat::TorchScript | ModelFormat::SafeTensors => None,
+    };
+    Ok(ModelMetadata {
+        format,
+        size_bytes,
+        sha256,
+        graph,
+    })
+}
+
+// 넘어온 필드만 바꾼다. tags는 통째로 바꾼다
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ModelPatch {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub tags: Option<Vec<String>>,
+}
+
+#[command]
+pub fn update_model_metadata(
+    app: AppHandle,
+    model_id: String,
+    patch: ModelPatch,
+) -> Result<ModelRef, String> {
+    let (before, after) = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        let before = project
+            .models
+            .iter()
+            .find(|m| m.id == model_id)
+            .cloned()
+            .ok_or_else(|| format!("model {model_id} not found"))?;
+        let after = patch_model(&project.models, &before, patch)?;
+        (before, after)
+    };
+    if before.name != after.name
+        || before.description != after.description
+        || before.tags != after.tags
+    {
+        history::execute(
+            &app,
+            ProjectCommand::UpdateModel {
+                before,
+                after: after.clone(),
+            },
+        )?;
+        emit(&app, "model-updated", after.clone());
+    }
+    Ok(after)
+}
+
+// 이름은 대소문자만 다른 것도 같은 이름으로 본다. 빈 설명은 설명을 지우는 것으로 받는다
+pub fn patch_model(
+    models: &[ModelRef],
+    model: &ModelRef,
+    patch: ModelPatch,
+) -> Result<ModelRef, String> {
+    let mut patched = model.clone();
+    if let Some(name) = patch.name {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err("model name cannot be empty".into());
+        }
+        if models
+            .iter()
+            .any(|m| m.id != model.id && m.name.eq_ignore_ascii_case(name))
+        {
+            return Err(format!("another model is already named \"{name}\""));
+        }
+        patched.name = name.to_string();
+    }
+    if let Some(description) = patch.description {
+        let description = description.trim();
+        patched.description = (!description.is_empty()).then(|| description.to_string());
+    }
+    if let Some(tags) = patch.tags {
+        let mut normalized = Vec::new();
+        for tag in tags {
+            let tag = normalize_tag(&tag)?;
+            if !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+        patched.tags = normalized;
+    }
+    Ok(patched)
+}
+
+// 모델 파일을 복사해 새 버전으로 추가한다. 태그와 설명은 그대로 가져가고
+// 이름에는 다음 버전 번호를 붙인다
+#[command]
+pub async fn create_model_version(app: AppHandle, model_id: String) -> Result<ModelRef, String> {
+    let (root, parent, name) = {
+        let state = app.state::<ProjectState>();
+        state.ensure_writable()?;
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        let parent = project
+            .models
+            .iter()
+            .find(|m| m.id == model_id)
+            .cloned()
+            .ok_or_else(|| format!("model {model_id} not found"))?;
+        let name = next_version_name(&project.models, &parent.name);
+        (project.root.clone(), parent, name)
+    };
+    let source = root.join(&parent.path);
+    if !source.is_file() {
+        return Err(format!("model file is missing: {}", source.display()));
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let relative = model_destination(&root, &source, &id);
+    let dest = root.join(&relative);
+    let sha256 = tauri::async_runtime::spawn_blocking(move || {
+        match copy_and_hash(&source, &dest, |_, _| true) {
+            Ok(sha256) => Ok(sha256),
+            Err(err) => {
+                remove_partial(&dest);
+                Err(format!("failed to copy {}: {err}", source.display()))
+            }
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+
+    let model = ModelRef {
+        id,
+        name,
+        path: relative,
+        imported_at: Utc::now(),
+        sha256,
+        parent_id: Some(parent.id.clone()),
+        ..parent
+    };
+    history::execute(&app, ProjectCommand::AddModel(model.clone()))?;
+    Ok(model)
+}
+
+// 모델 자신부터 원본 쪽으로 거슬러 올라간 목록
+#[command]
+pub fn model_lineage(app: AppHandle, model_id: String) -> Result<Vec<ModelRef>, String> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    let project = guard.as_ref().ok_or("no project is open")?;
+    lineage(&project.models, &model_id)
+}
+
+// 중간 버전이 지워졌으면 거기서 멈춘다. 매니페스트를 손으로 고쳐 생긴 순환도 끊는다
+pub fn lineage(models: &[ModelRef], model_id: &str) -> Result<Vec<ModelRef>, String> {
+    let find = |id: &str| models.iter().find(|m| m.id == id);
+    let mut chain = vec![find(model_id)
+        .cloned()
+        .ok_or_else(|| format!("model {model_id} not found"))?];
+    while let Some(parent) = chain
+        .last()
+        .and_then(|model| model.parent_id.as_deref())
+        .and_then(find)
+    {
+        if chain.iter().any(|m| m.id == parent.id) {
+            break;
+        }
+        chain.push(parent.clone());
+    }
+    Ok(chain)
+}
+
+// "ResNet"의 다음 버전은 "ResNet v2", "ResNet v2"의 다음은 "ResNet v3".
+// 같은 이름의 버전이 이미 있으면 가장 큰 번호 다음으로 간다
+pub fn next_version_name(models: &[ModelRef], name: &str) -> String {
+    let version_of = |name: &str| -> Option<(String, u32)> {
+        let (base, version) = name.rsplit_once(" v")?;
+        Some((base.to_string(), version.parse().ok()?))
+    };
+    let base = version_of(name).map_or_else(|| name.to_string(), |(base, _)| base);
+    let latest = models
+        .iter()
+        .filter_map(|m| match version_of(&m.name) {
+            Some((b, version)) if b.eq_ignore_ascii_case(&base) => Some(version),
+            _ if m.name.eq_ignore_ascii_case(&base) => Some(1),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(1);
+    format!("{base} v{}", latest + 1)
+}
+
+pub fn model_source(state: &ProjectState, model_id: &str) -> Result<(PathBuf, String), String> {
+    let guard = state.lock();
+    let project = guard.as_ref().ok_or("no project is open")?;
+    let model = project
+        .models
+        .iter()
+        .find(|m| m.id == model_id)
+        .ok_or_else(|| format!("model {model_id} not found"))?;
+    Ok((project.root.join(&model.path), model.name.clone()))
+}
+
+// 변환을 시작하기 전에 대상 디렉토리에 실제로 쓸 수 있는지 확인한다
+fn ensure_writable(dir: &Path) -> Result<(), String> {
+    let probe = dir.join(".metagate-write-test");
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&probe)
+        .map_err(|e| format!("cannot write to {}: {e}", dir.display()))?;
+    let _ = fs::remove_file(probe);
+    Ok(())
+}
+
+// 변환기는 진행률을 알려주지 않아서 취소 여부만 주기적으로 확인한다
+fn convert<R: Runtime>(
+    source: &Path,
+    dest: &Path,
+    format: ExportFormat,
+    operation: &Operation<R>,
+) -> Result<(), String> {
+    let converter = std::env::var(CONVERTER_ENV).unwrap_or_else(|_| DEFAULT_CONVERTER.into());
+    // stderr는 끝난 뒤에 읽으므로 파일로 받아서 파이프가 차서 멈추는 일이 없게 한다
+    let stderr_path = dest.with_extension("converter.log");
+    let stderr_file = File::create(&stderr_path)
+        .map_err(|e| format!("failed to create {}: {e}", stderr_path.display()))?;
+    let mut child = Command::new(&converter)
+        .arg("--input")
+        .arg(source)
+        .arg("--output")
+        .arg(dest)
+        .arg("--format")
+        .arg(format.converter_arg())
+        .stderr(stderr_file)
+        .spawn()
+        .map_err(|e| format!("failed to run converter {converter}: {e}"))?;
+
+    let status = loop {
+        if operation.is_cancelled() {
+            let _ = child.kill();
+            let _ = child.wait();
+            let _ = fs::remove_file(&stderr_path);
+            remove_partial(dest);
+            operation.cancelled();
+            return Err("export cancelled".into());
+        }
+        match child.try_wait() {
+            Ok(Some(status)) => break status,
+            Ok(None) => std::thread::sleep(CANCEL_POLL_INTERVAL),
+            Err(err) => return Err(format!("failed to wait for converter: {err}")),
+        }
+    };
+
+    let stderr = fs::read_to_string(&stderr_path).unwrap_or_default();
+    let _ = fs::remove_file(&stderr_path);
+    if !status.success() {
+        return Err(format!("converter exited with {status}: {}", stderr.trim()));
+    }
+    Ok(())
+}
+
+#[derive(Debug)]
+pub enum ImportError {
+    UnsupportedFormat(PathBuf),
+    NoProject,
+    ReadOnly,
+    Io(PathBuf, io::Error),
+    Cancelled,
+    SizeMismatch { expected: u64, actual: u64 },
+}
+
+impl fmt::Display for ImportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImportError::UnsupportedFormat(path) => {
+                write!(f, "unsupported model format: {}", path.display())
+            }
+            ImportError::NoProject => write!(f, "no project is open"),
+            ImportError::ReadOnly => write!(f, "{READ_ONLY_ERROR}"),
+            ImportError::Io(path, err) => write!(f, "failed to import {}: {err}", path.display()),
+            ImportError::Cancelled => write!(f, "import cancelled"),
+            ImportError::SizeMismatch { expected, actual } => write!(
+                f,
+                "copied file is {actual} bytes but the source is {expected} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ImportError {}
+
+// 이보다 작은 파일은 금방 끝나서 진행 이벤트를 보내지 않는다
+const IMPORT_PROGRESS_MIN_BYTES: u64 = 64 * 1024 * 1024;
+const IMPORT_PROGRESS_INTERVAL: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportProgress {
+    pub op_id: String,
+    pub bytes_copied: u64,
+    pub total_bytes: u64,
+    pub percent: f32,
+}
+
+// 청크마다 보내지 않고 일정 시간마다, 그리고 끝났을 때 한 번 보낸다
+struct ImportProgressThrottle {
+    last: Option<Instant>,
+}
+
+impl ImportProgressThrottle {
+    fn report<R: Runtime>(
+        &mut self,
+        operation: &Operation<R>,
+        bytes_copied: u64,
+        total_bytes: u64,
+    ) {
+        if total_bytes < IMPORT_PROGRESS_MIN_BYTES {
+            return;
+        }
+        let due = self
+            .last
+            .is_none_or(|last| last.elapsed() >= IMPORT_PROGRESS_INTERVAL);
+        if !due && bytes_copied != total_bytes {
+            return;
+        }
+        self.last = Some(Instant::now());
+        emit(
+            operation.app(),
+            "import-progress",
+            ImportProgress {
+                op_id: operation.id.clone(),
+                bytes_copied,
+                total_bytes,
+                percent: (bytes_copied as f64 / total_bytes as f64 * 100.0) as f32,
+            },
+        );
+    }
+}
+
+#[command]
+pub async fn import_model(app: AppHandle, path: String) -> Result<ModelRef, CommandError> {
+    import_model_path(app, PathBuf::from(path)).await
+}
+
+// 큰 파일 복사가 메인 스레드를 막지 않도록 blocking 풀에서 돌린다.
+// 매니페스트 반영은 실행 취소가 되도록 history를 거친다
+pub async fn import_model_path<R: Runtime>(
+    app: AppHandle<R>,
+    path: PathBuf,
+) -> Result<ModelRef, CommandError> {
+    let state = app.clone();
+    let model = tauri::async_runtime::spawn_blocking(move || {
+        let mut operation = Operation::start(&state, OperationKind::Import, &path);
+        guard(|| {
+            import_model_file(&state.state::<ProjectState>(), &path, &mut operation)
+                .map_err(|e| CommandError::Failed(e.to_string()))
+        })
+    })
+    .await
+    .map_err(|e| e.to_string())??;
+    history::execute(&app, ProjectCommand::AddModel(model.clone()))?;
+    Ok(model)
+}
+
+// 이보다 작은 파일은 금방 끝나서 진행 이벤트를 보내지 않는다
+const HASH_PROGRESS_MIN_BYTES: u64 = 256 * 1024 * 1024;
+const HASH_PROGRESS_STEP: u64 = 64 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HashProgress {
+    pub path: PathBuf,
+    pub hashed_bytes: u64,
+    pub total_bytes: u64,
+}
+
+// 큰 파일은 hash-progress 이벤트로 진행 상황을 알린다
+fn hash_model<R: Runtime>(app: &AppHandle<R>, path: &Path) -> Result<String, String> {
+    let mut reported = 0u64;
+    hash_file_with_progress(path, |hashed_bytes, total_bytes| {
+        if total_bytes < HASH_PROGRESS_MIN_BYTES {
+            return;
+        }
+        if hashed_bytes - reported >= HASH_PROGRESS_STEP || hashed_bytes == total_bytes {
+            reported = hashed_bytes;
+            emit(
+                app,
+                "hash-progress",
+                HashProgress {
+                    path: path.to_path_buf(),
+                    hashed_bytes,
+                    total_bytes,
+                },
+            );
+        }
+    })
+    .map_err(|e| format!("failed to hash {}: {e}", path.display()))
+}
+
+// 파일을 다시 해시해서 가져올 때 기록한 값과 비교한다
+#[command]
+pub async fn verify_model(app: AppHandle, model_id: String) -> Result<bool, String> {
+    let expected = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        project
+            .models
+            .iter()
+            .find(|m| m.id == model_id)
+            .ok_or_else(|| format!("model {model_id} not found"))?
+            .sha256
+            .clone()
+            .ok_or_else(|| {
+                format!("model {model_id} has no recorded hash; re-import it to verify")
+            })?
+    };
+    let (path, _) = model_source(&app.state::<ProjectState>(), &model_id)?;
+    let actual = tauri::async_runtime::spawn_blocking(move || hash_model(&app, &path))
+        .await
+        .map_err(|e| e.to_string())??;
+    Ok(actual == expected)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ImportRejected {
+    pub path: PathBuf,
+    pub reason: String,
+}
+
+// 창에 끌어다 놓은 파일들. 한 번에 하나씩 가져오고 파일마다 결과 이벤트를 보낸다
+pub fn import_dropped<R: Runtime>(app: &AppHandle<R>, paths: Vec<PathBuf>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        for path in paths {
+            if !has_model_extension(&path) {
+                let reason = ImportError::UnsupportedFormat(path.clone()).to_string();
+                emit(&app, "import-rejected", ImportRejected { path, reason });
+                continue;
+            }
+            match import_model_path(app.clone(), path.clone()).await {
+                Ok(model) => emit(&app, "import-complete", model),
+                Err(err) => {
+                    let reason = err.to_string();
+                    emit(&app, "import-rejected", ImportRejected { path, reason });
+                }
+            }
+        }
+    });
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportSummary {
+    pub imported: Vec<ModelRef>,
+    // 이미 프로젝트에 있는 것과 내용(SHA-256)이 같은 파일
+    pub skipped: Vec<PathBuf>,
+    pub errors: Vec<ImportRejected>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchImportProgress {
+    pub path: PathBuf,
+    pub index: usize,
+    pub total: usize,
+}
+
+// 파일 하나가 실패해도 나머지는 계속 가져온다
+#[command]
+pub async fn import_models_from_dir(
+    app: AppHandle,
+    dir: String,
+    recursive: Option<bool>,
+) -> Result<ImportSummary, String> {
+    let dir = PathBuf::from(dir);
+    let recursive = recursive.unwrap_or(false);
+    let scan_dir = dir.clone();
+    let files = tauri::async_runtime::spawn_blocking(move || model_files(&scan_dir, recursive))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("failed to read {}: {e}", dir.display()))?;
+
+    // 크기가 같은 모델이 있을 때만 해시를 구해서 비교한다
+    let mut known: Vec<(u64, String)> = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        project
+            .models
+            .iter()
+            .filter_map(|m| Some((m.size_bytes, m.sha256.clone()?)))
+            .collect()
+    };
+
+    let mut summary = ImportSummary::default();
+    let total = files.len();
+    for (index, path) in files.into_iter().enumerate() {
+        emit(
+            &app,
+            "batch-import-progress",
+            BatchImportProgress {
+                path: path.clone(),
+                index,
+                total,
+            },
+        );
+        match find_duplicate(&path, &known).await {
+            Ok(true) => {
+                summary.skipped.push(path);
+                continue;
+            }
+            Ok(false) => {}
+            Err(reason) => {
+                summary.errors.push(ImportRejected { path, reason });
+                continue;
+            }
+        }
+        match import_model_path(app.clone(), path.clone()).await {
+            Ok(model) => {
+                if let Some(sha256) = &model.sha256 {
+                    known.push((model.size_bytes, sha256.clone()));
+                }
+                summary.imported.push(model);
+            }
+            Err(err) => summary.errors.push(ImportRejected {
+                path,
+                reason: err.to_string(),
+            }),
+        }
+    }
+    Ok(summary)
+}
+
+async fn find_duplicate(path: &Path, known: &[(u64, String)]) -> Result<bool, String> {
+    let size = fs::metadata(path)
+        .map_err(|e| format!("failed to read {}: {e}", path.display()))?
+        .len();
+    if !known.iter().any(|(known_size, _)| *known_size == size) {
+        return Ok(false);
+    }
+    let source = path.to_path_buf();
+    let sha256 = tauri::async_runtime::spawn_blocking(move || hash_file(&source))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("failed to hash {}: {e}", path.display()))?;
+    Ok(known
+        .iter()
+        .any(|(known_size, known_sha)| *known_size == size && *known_sha == sha256))
+}
+
+// 확장자로만 고르고 포맷 검사는 가져올 때 한다. 심볼릭 링크는 따라가지 않는다
+fn model_files(dir: &Path, recursive: bool) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let kind = entry.file_type()?;
+            let path = entry.path();
+            if kind.is_dir() && recursive {
+                pending.push(path);
+            } else if kind.is_file() && has_model_extension(&path) {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn has_model_extension(path: &Path) -> bool {
+    path.extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .is_some_and(|e| MODEL_EXTENSIONS.contains(&e.as_str()))
+}
+
+pub async fn pick_model_file<R: Runtime>(app: AppHandle<R>) -> Result<Option<PathBuf>, String> {
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        app.dialog()
+            .file()
+            .set_title("Import Model")
+            .add_filter("Model", &MODEL_EXTENSIONS)
+            .blocking_pick_file()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    picked
+        .map(|file| file.into_path().map_err(|e| e.to_string()))
+        .transpose()
+}
+
+// 해시는 복사하면서 같이 구한다. 취소되면 덜 복사된 파일을 지운다
+pub fn import_model_file<R: Runtime>(
+    state: &ProjectState,
+    source: &Path,
+    operation: &mut Operation<R>,
+) -> Result<ModelRef, ImportError> {
+    if state.is_read_only() {
+        return Err(ImportError::ReadOnly);
+    }
+    let io_err = |e| ImportError::Io(source.to_path_buf(), e);
+    let format = detect_format(source)?;
+    let size_bytes = fs::metadata(source).map_err(io_err)?.len();
+
+    let root = state
+        .lock()
+        .as_ref()
+        .map(|p| p.root.clone())
+        .ok_or(ImportError::NoProject)?;
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let name = source
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| id.clone());
+    let relative = model_destination(&root, source, &id);
+    let dest = root.join(&relative);
+    fs::create_dir_all(root.join(MODELS_DIR)).map_err(io_err)?;
+    let mut throttle = ImportProgressThrottle { last: None };
+    let copied = copy_and_hash(source, &dest, |done, total| {
+        operation.progress(done, total);
+        throttle.report(operation, done, total);
+        !operation.is_cancelled()
+    });
+    let sha256 = match copied {
+        Ok(Some(sha256)) => sha256,
+        Ok(None) => {
+            remove_partial(&dest);
+            operation.cancelled();
+            return Err(ImportError::Cancelled);
+        }
+        Err(err) => {
+            remove_partial(&dest);
+            return Err(io_err(err));
+        }
+    };
+    // 복사 중에 원본이 바뀌었거나 디스크가 가득 찬 경우를 잡는다
+    let actual = fs::metadata(&dest).map(|m| m.len()).unwrap_or(0);
+    if actual != size_bytes {
+        remove_partial(&dest);
+        return Err(ImportError::SizeMismatch {
+            expected: size_bytes,
+            actual,
+        });
+    }
+
+    Ok(ModelRef {
+        id,
+        name,
+        path: relative,
+        format,
+        size_bytes,
+        imported_at: Utc::now(),
+        sha256: Some(sha256),
+        tags: Vec::new(),
+        description: None,
+        parent_id: None,
+    })
+}
+
+// 프로젝트의 models/ 아래로 복사하고 프로젝트 기준 상대 경로를 돌려준다
+pub fn copy_model_file(root: &Path, source: &Path, id: &str) -> io::Result<PathBuf> {
+    let relative = model_destination(root, source, id);
+    fs::create_dir_all(root.join(MODELS_DIR))?;
+    fs::copy(source, root.join(&relative))?;
+    Ok(relative)
+}
+
+fn model_destination(root: &Path, source: &Path, id: &str) -> PathBuf {
+    asset_destination(root, MODELS_DIR, source, id)
+}
+
+// root/dir 아래에 source 파일을 둘 상대 경로
+pub fn asset_destination(root: &Path, dir: &str, source: &Path, id: &str) -> PathBuf {
+    let file_name = source
+        .file_name()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| id.to_string());
+
+    // 같은 이름의 파일이 이미 있으면 id를 붙여서 덮어쓰지 않게 한다
+    let relative = PathBuf::from(dir).join(&file_name);
+    if root.join(&relative).exists() {
+        PathBuf::from(dir).join(format!("{id}-{file_name}"))
+    } else {
+        relative
+    }
+}
+
+// 확장자를 먼저 보고, 파일 앞부분 바이트로 한 번 더 확인한다
+pub fn detect_format(path: &Path) -> Result<ModelFormat, ImportError> {
+    let unsupported = || ImportError::UnsupportedFormat(path.to_path_buf());
+    let extension = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_ascii_lowercase())
+        .ok_or_else(unsupported)?;
+
+    let mut header = [0u8; 9];
+    let read = File::open(path)
+        .and_then(|mut file| file.read(&mut header))
+        .map_err(|e| ImportError::Io(path.to_path_buf(), e))?;
+    let header = &header[..read];
+
+    let format = match extension.as_str() {
+        // ONNX ModelProto는 보통 ir_version(field 1, varint) 태그 0x08로 시작한다
+        "onnx" if header.first() == Some(&0x08) => ModelFormat::Onnx,
+        // TorchScript 아카이브는 zip 파일
+        "pt" | "pth" if header.starts_with(b"PK\x03\x04") => ModelFormat::TorchScript,
+        // SafeTensors는 8바이트 헤더 길이 다음에 JSON 헤더가 온다
+        "safetensors" if header.get(8) == Some(&b'{') => ModelFormat::SafeTensors,
+        _ => return Err(unsupported()),
+    };
+    Ok(format)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn model(id: &str, name: &str) -> ModelRef {
+        ModelRef {
+            id: id.into(),
+            name: name.into(),
+            path: PathBuf::from(format!("models/{id}.onnx")),
+            format: ModelFormat::Onnx,
+            size_bytes: 0,
+            imported_at: Utc::now(),
+            sha256: None,
+            tags: vec!["old".into()],
+            description: Some("first run".into()),
+            parent_id: None,
+        }
+    }
+
+    #[test]
+    fn graph_summary_rejects_truncated_onnx_and_skips_other_formats() {
+        let dir = TempDir::new("graph");
+        // ir_version 다음에 길이만 있고 내용이 잘린 graph 필드
+        let onnx = dir.join("broken.onnx");
+        fs::write(&onnx, [0x08, 0x07, 0x3a, 0x10, 0x0a]).unwrap();
+        let safetensors = dir.join("weights.safetensors");
+        fs::write(&safetensors, b"\x02\0\0\0\0\0\0\0{}").unwrap();
+
+        let broken = graph_summary(&onnx);
+        let other = graph_summary(&safetensors);
+
+        assert!(broken.unwrap_err().contains("not a valid ONNX model"));
+        assert_eq!(
+            other,
+            Ok(GraphSummary::Unsupported {
+                format: ModelFormat::SafeTensors
+            })
+        );
+    }
+
+    #[test]
+    fn patch_model_changes_only_given_fields_and_rejects_taken_names() {
+        let models = [model("a", "ResNet"), model("b", "MobileNet")];
+        let patched = patch_model(
+            &models,
+            &models[0],
+            ModelPatch {
+                name: Some("  ResNet-50 ".into()),
+                ..ModelPatch::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(patched.name, "ResNet-50");
+        assert_eq!(patched.description.as_deref(), Some("first run"));
+        assert_eq!(patched.tags, ["old"]);
+
+        let patched = patch_model(
+            &models,
+            &models[0],
+            ModelPatch {
+                description: Some(" ".into()),
+                tags: Some(vec!["Vision".into(), "vision".into()]),
+                ..ModelPatch::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(patched.description, None);
+        assert_eq!(patched.tags, ["vision"]);
+
+        let taken = ModelPatch {
+            name: Some("mobilenet".into()),
+            ..ModelPatch::default()
+        };
+        let err = patch_model(&models, &models[0], taken).unwrap_err();
+        assert_eq!(err, "another model is already named \"mobilenet\"");
+    }
+
+    #[test]
+    fn model_files_filters_by_extension_and_recurses_on_request() {
+        let dir = TempDir::new("batch");
+        fs::create_dir_all(dir.join("epoch-2")).unwrap();
+        fs::write(dir.join("a.onnx"), "").unwrap();
+        fs::write(dir.join("notes.txt"), "").unwrap();
+        fs::write(dir.join("epoch-2/b.PT"), "").unwrap();
+
+        let flat = model_files(&dir, false).unwrap();
+        let deep = model_files(&dir, true).unwrap();
+
+        assert_eq!(flat, [dir.join("a.onnx")]);
+        assert_eq!(deep, [dir.join("a.onnx"), dir.join("epoch-2/b.PT")]);
+    }
+
+    #[test]
+    fn versions_get_the_next_number_and_link_back_to_their_parent() {
+        let mut models = vec![model("a", "ResNet"), model("b", "ResNet v2")];
+        assert_eq!(next_version_name(&models, "ResNet"), "ResNet v3");
+        assert_eq!(next_version_name(&models, "resnet v2"), "resnet v3");
+        assert_eq!(next_version_name(&models, "MobileNet"), "MobileNet v2");
+
+        models[1].parent_id = Some("a".into());
+        models.push(ModelRef {
+            parent_id: Some("b".into()),
+            ..model("c", "ResNet v3")
+        });
+        let ids = |chain: Vec<ModelRef>| chain.into_iter().map(|m| m.id).collect::<Vec<_>>();
+        assert_eq!(ids(lineage(&models, "c").unwrap()), ["c", "b", "a"]);
+
+        // 중간 버전이 지워졌거나 순환하면 거기서 멈춘다
+        models[0].parent_id = Some("c".into());
+        assert_eq!(ids(lineage(&models, "c").unwrap()), ["c", "b", "a"]);
+        models.remove(1);
+        assert_eq!(ids(lineage(&models, "c").unwrap()), ["c"]);
+        assert!(lineage(&models, "missing").is_err());
+    }
+}
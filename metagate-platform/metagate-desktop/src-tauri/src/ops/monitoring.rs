@@ -0,0 +1,656 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::Serialize;
+use serde_json::Value;
+use sysinfo::System;
+use tauri::async_runtime::JoinHandle;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+use super::emit;
+use super::pipeline::PipelineRuns;
+use super::project::{entry_size, ProjectState};
+use super::train::TrainingRuns;
+use crate::fs::project::{run_dir, ARCHIVES_DIR, RUNS_DIR};
+use crate::fs::run::{read_metric_rows, read_metrics, MetricPoint, RunKind, RunRecord, RunStatus};
+
+// 너무 짧으면 CPU 사용률이 의미가 없어서 하한을 둔다
+const MIN_INTERVAL: Duration = sysinfo::MINIMUM_CPU_UPDATE_INTERVAL;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemMetrics {
+    pub cpu_percent: f32,
+    pub memory_used_bytes: u64,
+    pub memory_total_bytes: u64,
+    // NVML을 못 쓰는 환경에서는 None
+    pub gpu_utilization: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GpuInfo {
+    pub index: u32,
+    pub name: String,
+    pub memory_total: u64,
+    pub memory_free: u64,
+    // 드라이버가 알려주지 않는 장치도 있다
+    pub utilization: Option<f32>,
+}
+
+pub struct SystemMonitor {
+    system: Mutex<System>,
+    #[cfg(feature = "nvml")]
+    nvml: Option<nvml_wrapper::Nvml>,
+    stream: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl Default for SystemMonitor {
+    // CPU 사용률은 이전 측정값과 비교하므로 시작할 때 한 번 읽어 둔다
+    fn default() -> Self {
+        let mut system = System::new();
+        system.refresh_cpu_usage();
+        SystemMonitor {
+            system: Mutex::new(system),
+            #[cfg(feature = "nvml")]
+            nvml: nvml_wrapper::Nvml::init()
+                .inspect_err(|err| log::info!("NVML unavailable, GPU metrics disabled: {err}"))
+                .ok(),
+            stream: Mutex::new(None),
+        }
+    }
+}
+
+impl SystemMonitor {
+    fn lock_stream(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        self.stream.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn sample(&self) -> SystemMetrics {
+        let mut system = self.system.lock().unwrap_or_else(|e| e.into_inner());
+        system.refresh_cpu_usage();
+        system.refresh_memory();
+        SystemMetrics {
+            cpu_percent: system.global_cpu_usage(),
+            memory_used_bytes: system.used_memory(),
+            memory_total_bytes: system.total_memory(),
+            gpu_utilization: self.gpu_utilization(),
+        }
+    }
+
+    // 여러 장이면 평균을 낸다
+    #[cfg(feature = "nvml")]
+    fn gpu_utilization(&self) -> Option<f32> {
+        let nvml = self.nvml.as_ref()?;
+        let count = nvml.device_count().ok()?;
+        let rates: Vec<f32> = (0..count)
+            .filter_map(|i| nvml.device_by_index(i).ok()?.utilization_rates().ok())
+            .map(|rates| rates.gpu as f32)
+            .collect();
+        if rates.is_empty() {
+            return None;
+        }
+        Some(rates.iter().sum::<f32>() / rates.len() as f32)
+    }
+
+    #[cfg(not(feature = "nvml"))]
+    fn gpu_utilization(&self) -> Option<f32> {
+        None
+    }
+
+    // 이름이나 메모리를 못 읽는 장치는 학습에 고를 수 없으니 목록에서 뺀다
+    #[cfg(feature = "nvml")]
+    pub fn gpus(&self) -> Vec<GpuInfo> {
+        let Some(nvml) = self.nvml.as_ref() else {
+            return Vec::new();
+        };
+        let count = nvml.device_count().unwrap_or(0);
+        (0..count)
+            .filter_map(|index| {
+                let device = nvml.device_by_index(index).ok()?;
+                let memory = device.memory_info().ok()?;
+                Some(GpuInfo {
+                    index,
+                    name: device.name().ok()?,
+                    memory_total: memory.total,
+                    memory_free: memory.free,
+                    utilization: device
+                        .utilization_rates()
+                        .ok()
+                        .map(|rates| rates.gpu as f32),
+                })
+            })
+            .collect()
+    }
+
+    #[cfg(not(feature = "nvml"))]
+    pub fn gpus(&self) -> Vec<GpuInfo> {
+        Vec::new()
+    }
+
+    pub fn is_streaming(&self) -> bool {
+        self.lock_stream().is_some()
+    }
+
+    // 돌고 있던 스트림을 멈췄으면 true
+    pub fn stop_stream(&self) -> bool {
+        let Some(task) = self.lock_stream().take() else {
+            return false;
+        };
+        task.abort();
+        true
+    }
+}
+
+#[command]
+pub fn system_metrics(monitor: State<'_, SystemMonitor>) -> SystemMetrics {
+    monitor.sample()
+}
+
+// GPU가 없거나 NVML을 못 쓰면 빈 목록
+#[command]
+pub fn list_gpus(monitor: State<'_, SystemMonitor>) -> Vec<GpuInfo> {
+    monitor.gpus()
+}
+
+// 이미 돌고 있으면 새 주기로 다시 시작한다
+#[command]
+pub fn start_metrics_stream(app: AppHandle, interval_ms: u64) {
+    start_stream(&app, Duration::from_millis(interval_ms).max(MIN_INTERVAL));
+}
+
+#[command]
+pub fn stop_metrics_stream(monitor: State<'_, SystemMonitor>) {
+    monitor.stop_stream();
+}
+
+pub fn start_stream<R: Runtime>(app: &AppHandle<R>, interval: Duration) {
+    let monitor = app.state::<SystemMonitor>();
+    monitor.stop_stream();
+
+    let handle = app.clone();
+    let task = tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let metrics = handle.state::<SystemMonitor>().sample();
+            emit(&handle, "system-metrics", metrics);
+        }
+    });
+    *monitor.lock_stream() = Some(task);
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunSummary {
+    pub run_id: String,
+    pub kind: RunKind,
+    pub subject: String,
+    pub status: RunStatus,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+    // 학습 실행의 마지막 epoch 값
+    pub final_metrics: Option<MetricPoint>,
+}
+
+#[command]
+pub fn training_history(app: AppHandle, run_id: String) -> Result<Vec<MetricPoint>, String> {
+    let dir = run_dir(&project_root(&app).ok_or("no project is open")?, &run_id);
+    if !dir.is_dir() {
+        return Err(format!("run {run_id} not found"));
+    }
+    read_metrics(&dir).map_err(|e| format!("failed to read metrics for run {run_id}: {e}"))
+}
+
+// 기록이 없어도 헤더만 있는 파일을 만든다. 받는 쪽 도구가 빈 파일을 다루지 않아도 되게 한다
+#[command]
+pub fn export_metrics_csv(app: AppHandle, run_id: String, dest: String) -> Result<PathBuf, String> {
+    let dir = run_dir(&project_root(&app).ok_or("no project is open")?, &run_id);
+    if !dir.is_dir() {
+        return Err(format!("run {run_id} not found"));
+    }
+    let rows = read_metric_rows(&dir)
+        .map_err(|e| format!("failed to read metrics for run {run_id}: {e}"))?;
+    let dest = PathBuf::from(dest);
+    fs::write(&dest, metrics_csv(&rows))
+        .map_err(|e| format!("failed to write {}: {e}", dest.display()))?;
+    Ok(dest)
+}
+
+// 늘 있는 컬럼을 앞에 두고 트레이너가 더 보낸 값은 뒤에 붙인다. 어떤 epoch에 없는 값은 빈 칸
+const METRIC_COLUMNS: [&str; 4] = ["epoch", "timestamp", "loss", "accuracy"];
+
+pub fn metrics_csv(rows: &[serde_json::Map<String, Value>]) -> String {
+    let mut columns: Vec<&str> = METRIC_COLUMNS.to_vec();
+    for row in rows {
+        for key in row.keys() {
+            if !columns.contains(&key.as_str()) {
+                columns.push(key);
+            }
+        }
+    }
+
+    let mut csv = csv_line(columns.iter().map(|c| c.to_string()));
+    for row in rows {
+        csv.push_str(&csv_line(columns.iter().map(
+            |column| match row.get(*column) {
+                None | Some(Value::Null) => String::new(),
+                Some(Value::String(s)) => s.clone(),
+                Some(value) => value.to_string(),
+            },
+        )));
+    }
+    csv
+}
+
+fn csv_line(cells: impl Iterator<Item = String>) -> String {
+    let cells: Vec<String> = cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell
+            }
+        })
+        .collect();
+    format!("{}\n", cells.join(","))
+}
+
+// 최근 실행이 먼저 온다. 프로젝트가 없거나 실행 기록이 없으면 빈 목록
+#[command]
+pub fn list_runs(app: AppHandle) -> Vec<RunSummary> {
+    let Some(root) = project_root(&app) else {
+        return Vec::new();
+    };
+    let Ok(entries) = std::fs::read_dir(root.join(RUNS_DIR)) else {
+        return Vec::new();
+    };
+
+    let pipelines = app.state::<PipelineRuns>();
+    let trainings = app.state::<TrainingRuns>();
+    let mut runs: Vec<RunSummary> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let dir = entry.path();
+            let record = RunRecord::load(&dir)?;
+            let active = pipelines.lock().contains_key(&record.run_id)
+                || trainings.lock().contains_key(&record.run_id);
+            let status = match record.status {
+                RunStatus::Running | RunStatus::Paused if !active => RunStatus::Interrupted,
+                status => status,
+            };
+            let final_metrics = match record.kind {
+                RunKind::Training => read_metrics(&dir).ok().and_then(|mut m| m.pop()),
+                RunKind::Pipeline => None,
+            };
+            Some(RunSummary {
+                run_id: record.run_id,
+                kind: record.kind,
+                subject: record.subject,
+                status,
+                started_at: record.started_at,
+                finished_at: record.finished_at,
+                final_metrics,
+            })
+        })
+        .collect();
+    runs.sort_by_key(|run| std::cmp::Reverse(run.started_at));
+    runs
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ArchiveSummary {
+    // 묶을 실행이 없으면 파일을 만들지 않아서 None
+    pub archive: Option<PathBuf>,
+    pub archived: usize,
+    pub freed_bytes: u64,
+}
+
+// before보다 먼저 시작한 실행을 archives/runs-<시각>.tar.gz 하나로 묶고 원본을 지운다.
+// 아직 돌고 있는 실행은 시작 시각과 상관없이 남긴다
+#[command]
+pub async fn archive_runs(app: AppHandle, before: DateTime<Utc>) -> Result<ArchiveSummary, String> {
+    let root = project_root(&app).ok_or("no project is open")?;
+    app.state::<ProjectState>().ensure_writable()?;
+    let active: Vec<String> = app
+        .state::<PipelineRuns>()
+        .lock()
+        .keys()
+        .chain(app.state::<TrainingRuns>().lock().keys())
+        .cloned()
+        .collect();
+    tauri::async_runtime::spawn_blocking(move || archive_before(&root, before, &active))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn archive_before(
+    root: &Path,
+    before: DateTime<Utc>,
+    active: &[String],
+) -> Result<ArchiveSummary, String> {
+    let runs = root.join(RUNS_DIR);
+    let entries = match fs::read_dir(&runs) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(ArchiveSummary::default()),
+        Err(err) => return Err(format!("failed to read {}: {err}", runs.display())),
+    };
+    let mut run_ids: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| RunRecord::load(&entry.path()))
+        .filter(|record| record.started_at < before && !active.contains(&record.run_id))
+        .map(|record| record.run_id)
+        .collect();
+    if run_ids.is_empty() {
+        return Ok(ArchiveSummary::default());
+    }
+    run_ids.sort();
+
+    let dir = root.join(ARCHIVES_DIR);
+    fs::create_dir_all(&dir).map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+    let archive = dir.join(format!(
+        "runs-{}.tar.gz",
+        Utc::now().format("%Y%m%d-%H%M%S")
+    ));
+    bundle_runs(&runs, &run_ids, &archive)
+        .map_err(|e| format!("failed to write {}: {e}", archive.display()))?;
+
+    // 묶기가 끝난 뒤에만 지운다. 지우다 실패한 실행은 다음에 다시 묶일 수 있게 남겨 둔다
+    let mut summary = ArchiveSummary {
+        archive: Some(archive),
+        ..ArchiveSummary::default()
+    };
+    for run_id in &run_ids {
+        let dir = runs.join(run_id);
+        let size = entry_size(&dir);
+        match fs::remove_dir_all(&dir) {
+            Ok(()) => {
+                summary.archived += 1;
+                summary.freed_bytes += size;
+            }
+            Err(err) => log::warn!("failed to remove archived run {run_id}: {err}"),
+        }
+    }
+    Ok(summary)
+}
+
+// 임시 파일에 다 쓴 뒤 이름을 바꿔서 반쯤 쓰인 묶음이 남지 않게 한다
+pub fn bundle_runs(runs: &Path, run_ids: &[String], archive: &Path) -> io::Result<()> {
+    let tmp = archive.with_extension("gz.tmp");
+    let result = (|| {
+        let encoder = GzEncoder::new(File::create(&tmp)?, Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        builder.follow_symlinks(false);
+        for run_id in run_ids {
+            builder.append_dir_all(run_id, runs.join(run_id))?;
+        }
+        builder.into_inner()?.finish()?.sync_all()?;
+        fs::rename(&tmp, archive)
+    })();
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp);
+    }
+    result
+}
+
+// 묶음을 runs/ 아래로 풀고 푼 실행 수를 돌려준다. 같은 id의 실행이 이미 있으면 하나도 풀지 않는다
+#[command]
+pub async fn restore_archive(app: AppHandle, path: String) -> Result<usize, String> {
+    let root = project_root(&app).ok_or("no project is open")?;
+    app.state::<ProjectState>().ensure_writable()?;
+    let archive = PathBuf::from(path);
+    tauri::async_runtime::spawn_blocking(move || unpack_runs(&archive, &root.join(RUNS_DIR)))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+pub fn unpack_runs(archive: &Path, runs: &Path) -> Result<usize, String> {
+    let open = || {
+        File::open(archive)
+            .map(|file| tar::Archive::new(GzDecoder::new(file)))
+            .map_err(|e| format!("failed to open {}: {e}", archive.display()))
+    };
+    let read_err = |e: io::Error| format!("failed to read {}: {e}", archive.display());
+
+    let mut run_ids = Vec::new();
+    for entry in open()?.entries().map_err(read_err)? {
+        let entry = entry.map_err(read_err)?;
+        let path = entry.path().map_err(read_err)?;
+        let Some(std::path::Component::Normal(run_id)) = path.components().next() else {
+            return Err(format!("{} is not a run archive", archive.display()));
+        };
+        let run_id = run_id.to_string_lossy().into_owned();
+        if !run_ids.contains(&run_id) {
+            run_ids.push(run_id);
+        }
+    }
+    if let Some(existing) = run_ids.iter().find(|id| runs.join(id).exists()) {
+        return Err(format!("run {existing} already exists"));
+    }
+
+    fs::create_dir_all(runs).map_err(|e| format!("failed to create {}: {e}", runs.display()))?;
+    // unpack_in은 ..이나 절대 경로로 runs/ 밖에 쓰려는 항목을 건너뛴다
+    for entry in open()?.entries().map_err(read_err)? {
+        entry
+            .map_err(read_err)?
+            .unpack_in(runs)
+            .map_err(|e| format!("failed to extract {}: {e}", archive.display()))?;
+    }
+    Ok(run_ids.len())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigDiff {
+    // 중첩된 키는 "optimizer.lr"처럼 점으로 잇는다
+    pub key: String,
+    // 한쪽에만 있는 키는 다른 쪽이 None
+    pub a: Option<Value>,
+    pub b: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricDelta {
+    pub name: String,
+    pub a: Option<f64>,
+    pub b: Option<f64>,
+    // b - a. 한쪽에만 있는 지표는 None
+    pub delta: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RunComparison {
+    pub a: String,
+    pub b: String,
+    // 값이 다른 설정만 담는다
+    pub config: Vec<ConfigDiff>,
+    // 마지막 epoch 값. 양쪽 중 한 곳에라도 있는 지표는 모두 담는다
+    pub metrics: Vec<MetricDelta>,
+}
+
+#[command]
+pub fn compare_runs(app: AppHandle, a: String, b: String) -> Result<RunComparison, String> {
+    let root = project_root(&app).ok_or("no project is open")?;
+    let (config_a, metrics_a) = run_results(&root, &a)?;
+    let (config_b, metrics_b) = run_results(&root, &b)?;
+    Ok(RunComparison {
+        config: diff_config(config_a.as_ref(), config_b.as_ref()),
+        metrics: metric_deltas(&metrics_a, &metrics_b),
+        a,
+        b,
+    })
+}
+
+fn run_results(
+    root: &Path,
+    run_id: &str,
+) -> Result<(Option<Value>, BTreeMap<String, f64>), String> {
+    let dir = run_dir(root, run_id);
+    let record = RunRecord::load(&dir).ok_or_else(|| format!("run {run_id} not found"))?;
+    let last = read_metrics(&dir)
+        .map_err(|e| format!("failed to read metrics for run {run_id}: {e}"))?
+        .pop();
+    Ok((record.config, last.map(final_metrics).unwrap_or_default()))
+}
+
+fn final_metrics(point: MetricPoint) -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::from([
+        ("epoch".to_string(), point.epoch as f64),
+        ("loss".to_string(), point.loss),
+    ]);
+    if let Some(accuracy) = point.accuracy {
+        metrics.insert("accuracy".into(), accuracy);
+    }
+    metrics
+}
+
+pub fn diff_config(a: Option<&Value>, b: Option<&Value>) -> Vec<ConfigDiff> {
+    let (mut left, mut right) = (BTreeMap::new(), BTreeMap::new());
+    if let Some(a) = a {
+        flatten(a, String::new(), &mut left);
+    }
+    if let Some(b) = b {
+        flatten(b, String::new(), &mut right);
+    }
+    let mut keys: Vec<&String> = left.keys().chain(right.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| left.get(*key) != right.get(*key))
+        .map(|key| ConfigDiff {
+            key: key.clone(),
+            a: left.get(key).cloned(),
+            b: right.get(key).cloned(),
+        })
+        .collect()
+}
+
+// 배열은 통째로 비교한다. 원소 순서가 의미 있는 경우가 많아서
+fn flatten(value: &Value, prefix: String, out: &mut BTreeMap<String, Value>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, value) in map {
+                let key = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                flatten(value, key, out);
+            }
+        }
+        value => {
+            out.insert(prefix, value.clone());
+        }
+    }
+}
+
+pub fn metric_deltas(a: &BTreeMap<String, f64>, b: &BTreeMap<String, f64>) -> Vec<MetricDelta> {
+    let mut names: Vec<&String> = a.keys().chain(b.keys()).collect();
+    names.sort();
+    names.dedup();
+    names
+        .into_iter()
+        .map(|name| {
+            let (a, b) = (a.get(name).copied(), b.get(name).copied());
+            MetricDelta {
+                name: name.clone(),
+                a,
+                b,
+                delta: a.zip(b).map(|(a, b)| b - a),
+            }
+        })
+        .collect()
+}
+
+fn project_root<R: Runtime>(app: &AppHandle<R>) -> Option<PathBuf> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    guard.as_ref().map(|project| project.root.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+    use serde_json::json;
+
+    #[test]
+    fn metrics_csv_keeps_columns_aligned() {
+        let rows: Vec<serde_json::Map<String, Value>> = [
+            r#"{"epoch":1,"loss":0.9,"accuracy":null,"timestamp":"t1"}"#,
+            r#"{"epoch":2,"loss":0.5,"accuracy":0.8,"timestamp":"t2","f1":0.7,"note":"a,b"}"#,
+        ]
+        .iter()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+        assert_eq!(
+            metrics_csv(&rows),
+            "epoch,timestamp,loss,accuracy,f1,note\n1,t1,0.9,,,\n2,t2,0.5,0.8,0.7,\"a,b\"\n"
+        );
+        assert_eq!(metrics_csv(&[]), "epoch,timestamp,loss,accuracy\n");
+    }
+
+    #[test]
+    fn bundled_runs_restore_and_refuse_to_overwrite() {
+        let base = TempDir::new("archive");
+        let runs = base.join(RUNS_DIR);
+        fs::create_dir_all(runs.join("r1/artifacts")).unwrap();
+        fs::write(runs.join("r1/run.json"), "{}").unwrap();
+        fs::write(runs.join("r1/artifacts/model.onnx"), "weights").unwrap();
+        let archive = base.join("runs.tar.gz");
+
+        bundle_runs(&runs, &["r1".to_string()], &archive).unwrap();
+        let conflict = unpack_runs(&archive, &runs);
+        fs::remove_dir_all(runs.join("r1")).unwrap();
+        let restored = unpack_runs(&archive, &runs);
+        let weights = fs::read_to_string(runs.join("r1/artifacts/model.onnx"));
+
+        assert_eq!(conflict.unwrap_err(), "run r1 already exists");
+        assert_eq!(restored, Ok(1));
+        assert_eq!(weights.unwrap(), "weights");
+    }
+
+    #[test]
+    fn comparing_a_run_with_itself_has_no_differences() {
+        let config = json!({"epochs": 10, "optimizer": {"lr": 0.01}});
+        let metrics = BTreeMap::from([("loss".to_string(), 0.25)]);
+
+        assert!(diff_config(Some(&config), Some(&config)).is_empty());
+        let deltas = metric_deltas(&metrics, &metrics);
+        assert_eq!(deltas[0].delta, Some(0.0));
+    }
+
+    #[test]
+    fn differences_keep_keys_and_metrics_missing_on_one_side() {
+        let a = json!({"epochs": 10, "optimizer": {"lr": 0.01}});
+        let b = json!({"epochs": 10, "optimizer": {"lr": 0.001, "momentum": 0.9}});
+        let diffs = diff_config(Some(&a), Some(&b));
+        assert_eq!(
+            diffs,
+            [
+                ConfigDiff {
+                    key: "optimizer.lr".into(),
+                    a: Some(json!(0.01)),
+                    b: Some(json!(0.001)),
+                },
+                ConfigDiff {
+                    key: "optimizer.momentum".into(),
+                    a: None,
+                    b: Some(json!(0.9)),
+                },
+            ]
+        );
+
+        let a = BTreeMap::from([("accuracy".to_string(), 0.5), ("loss".to_string(), 1.0)]);
+        let b = BTreeMap::from([("loss".to_string(), 0.75)]);
+        let deltas = metric_deltas(&a, &b);
+        assert_eq!(deltas[0].name, "accuracy");
+        assert_eq!((deltas[0].b, deltas[0].delta), (None, None));
+        assert_eq!(deltas[1].delta, Some(-0.25));
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::{Mutex, MutexGuard};
+
+use tauri::{AppHandle, Manager, Runtime, Window, WindowEvent};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::fs::menu_events::{navigate, NavigationTarget};
+use crate::fs::preferences::Preferences;
+
+// 데스크톱에서는 알림 클릭을 직접 받을 수 없다. 클릭하면 OS가 앱을 앞으로 가져오므로
+// 알림을 보낸 뒤 처음 포커스를 받을 때 해당 화면으로 보낸다
+#[derive(Default)]
+pub struct PendingNavigation(Mutex<Option<NavigationTarget>>);
+
+impl PendingNavigation {
+    fn lock(&self) -> MutexGuard<'_, Option<NavigationTarget>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// 창이 이미 앞에 있으면 화면에서 결과를 보고 있을 테니 보내지 않는다
+pub fn notify<R: Runtime>(app: &AppHandle<R>, title: &str, body: &str, target: NavigationTarget) {
+    if !Preferences::load(app).notifications_enabled || app_focused(app) {
+        return;
+    }
+    if let Err(err) = app.notification().builder().title(title).body(body).show() {
+        log::warn!("failed to show notification: {err}");
+        return;
+    }
+    *app.state::<PendingNavigation>().lock() = Some(target);
+}
+
+pub fn track_focus<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    if !matches!(event, WindowEvent::Focused(true)) {
+        return;
+    }
+    let app = window.app_handle();
+    let Some(target) = app.state::<PendingNavigation>().lock().take() else {
+        return;
+    };
+    if let Err(err) = window.set_focus() {
+        log::warn!("failed to focus window {}: {err}", window.label());
+    }
+    navigate(app, target);
+}
+
+fn app_focused<R: Runtime>(app: &AppHandle<R>) -> bool {
+    app.webview_windows()
+        .values()
+        .any(|window| window.is_focused().unwrap_or(false))
+}
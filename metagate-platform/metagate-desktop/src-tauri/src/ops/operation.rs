@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+use super::emit;
+
+pub type OperationId = String;
+
+// 진행 이벤트를 청크마다 보내면 너무 많아서 이만큼 진행될 때마다 보낸다
+const PROGRESS_STEP: u64 = 8 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Import,
+    Export,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationStarted {
+    pub op_id: OperationId,
+    pub kind: OperationKind,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationProgress {
+    pub op_id: OperationId,
+    pub done_bytes: u64,
+    pub total_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationCancelled {
+    pub op_id: OperationId,
+}
+
+// 진행 중인 가져오기/내보내기의 취소 플래그
+#[derive(Default)]
+pub struct Operations {
+    tokens: Mutex<HashMap<OperationId, Arc<AtomicBool>>>,
+}
+
+impl Operations {
+    fn lock(&self) -> MutexGuard<'_, HashMap<OperationId, Arc<AtomicBool>>> {
+        self.tokens.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 작업 쪽이 플래그를 보고 멈춘 뒤 스스로 목록에서 빠진다. 아직 취소되지 않았던 개수
+    pub fn cancel_all(&self) -> usize {
+        self.lock()
+            .values()
+            .filter(|token| !token.swap(true, Ordering::SeqCst))
+            .count()
+    }
+}
+
+// 드롭되면 목록에서 빠지므로, 끝난 작업을 취소해도 아무 일도 일어나지 않는다
+pub struct Operation<R: Runtime> {
+    pub id: OperationId,
+    app: AppHandle<R>,
+    cancelled: Arc<AtomicBool>,
+    reported: u64,
+}
+
+impl<R: Runtime> Operation<R> {
+    pub fn start(app: &AppHandle<R>, kind: OperationKind, path: &Path) -> Self {
+        let id: OperationId = uuid::Uuid::new_v4().to_string();
+        let cancelled = Arc::new(AtomicBool::new(false));
+        app.state::<Operations>()
+            .lock()
+            .insert(id.clone(), cancelled.clone());
+        emit(
+            app,
+            "operation-started",
+            OperationStarted {
+                op_id: id.clone(),
+                kind,
+                path: path.to_path_buf(),
+            },
+        );
+        Operation {
+            id,
+            app: app.clone(),
+            cancelled,
+            reported: 0,
+        }
+    }
+
+    pub fn app(&self) -> &AppHandle<R> {
+        &self.app
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    pub fn progress(&mut self, done_bytes: u64, total_bytes: u64) {
+        if done_bytes - self.reported < PROGRESS_STEP && done_bytes != total_bytes {
+            return;
+        }
+        self.reported = done_bytes;
+        emit(
+            &self.app,
+            "operation-progress",
+            OperationProgress {
+                op_id: self.id.clone(),
+                done_bytes,
+                total_bytes,
+            },
+        );
+    }
+
+    // 덜 만든 결과물을 지운 다음에 부른다
+    pub fn cancelled(&self) {
+        emit(
+            &self.app,
+            "operation-cancelled",
+            OperationCancelled {
+                op_id: self.id.clone(),
+            },
+        );
+    }
+}
+
+impl<R: Runtime> Drop for Operation<R> {
+    fn drop(&mut self) {
+        self.app.state::<Operations>().lock().remove(&self.id);
+    }
+}
+
+// 모르는 id거나 이미 끝난 작업이면 그냥 Ok
+#[command]
+pub fn cancel_operation(operations: State<'_, Operations>, op_id: String) -> Result<(), String> {
+    if let Some(token) = operations.lock().get(&op_id) {
+        token.store(true, Ordering::SeqCst);
+    }
+    Ok(())
+}
+
+// 파일이든 디렉토리(SavedModel)든 남은 것을 지운다
+pub fn remove_partial(path: &Path) {
+    let result = if path.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    };
+    if let Err(err) = result {
+        if err.kind() != std::io::ErrorKind::NotFound {
+            log::warn!("failed to remove partial output {}: {err}", path.display());
+        }
+    }
+}
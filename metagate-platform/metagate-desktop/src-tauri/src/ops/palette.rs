@@ -0,0 +1,271 @@
+use serde::Serialize;
+use tauri::menu::MenuItemKind;
+use tauri::{command, AppHandle, Runtime};
+
+use crate::fs::menu::{menu_tree, MenuTree};
+use crate::fs::menu_events::run_menu_action;
+use crate::fs::shortcuts::{app_shortcuts, ShortcutEntry};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Command {
+    pub id: String,
+    pub title: String,
+    pub accelerator: Option<String>,
+    // 항목이 들어 있는 최상위 메뉴 이름
+    pub category: String,
+}
+
+// 실제 메뉴를 훑어서 만들기 때문에 팔레트에만 있거나 메뉴에만 있는 항목이 생기지 않는다.
+// 단축키는 메뉴와 같은 shortcuts() 목록에서 가져온다
+#[command]
+pub fn list_commands(app: AppHandle) -> Vec<Command> {
+    palette_commands(&app)
+}
+
+// 앱 안에 메뉴를 그리거나 메뉴 설정 화면을 만들 때 쓴다. 네이티브 메뉴와 같은 구조다
+#[command]
+pub fn menu_structure(app: AppHandle) -> MenuTree {
+    menu_tree(&app)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CommandMatch {
+    pub command: Command,
+    pub score: i32,
+    // 제목에서 일치한 글자의 위치(바이트가 아니라 char 단위). 강조 표시에 쓴다
+    pub indices: Vec<usize>,
+}
+
+// 팔레트 입력창에서 부른다. 빈 입력이면 메뉴 순서 그대로 모두 돌려준다
+#[command]
+pub fn fuzzy_match_commands(app: AppHandle, query: String) -> Vec<CommandMatch> {
+    fuzzy_match(palette_commands(&app), &query)
+}
+
+#[command]
+pub fn run_command(app: AppHandle, id: String) -> Result<(), String> {
+    let commands = palette_commands(&app);
+    if !commands.iter().any(|c| c.id == id) {
+        let valid: Vec<&str> = commands.iter().map(|c| c.id.as_str()).collect();
+        return Err(format!(
+            "unknown command {id}; valid commands: {}",
+            valid.join(", ")
+        ));
+    }
+    run_menu_action(&app, &id);
+    Ok(())
+}
+
+pub fn palette_commands<R: Runtime>(app: &AppHandle<R>) -> Vec<Command> {
+    fn collect<R: Runtime>(
+        items: Vec<MenuItemKind<R>>,
+        category: &str,
+        shortcuts: &[ShortcutEntry],
+        out: &mut Vec<Command>,
+    ) {
+        for item in items {
+            let title = match &item {
+                MenuItemKind::Submenu(submenu) => {
+                    if let Ok(children) = submenu.items() {
+                        collect(children, category, shortcuts, out);
+                    }
+                    continue;
+                }
+                MenuItemKind::MenuItem(item) => item.text(),
+                MenuItemKind::Check(item) => item.text(),
+                MenuItemKind::Icon(item) => item.text(),
+                // 구분선이나 OS 기본 항목은 팔레트에서 실행할 수 없다
+                MenuItemKind::Predefined(_) => continue,
+            };
+            let Ok(title) = title else {
+                continue;
+            };
+            let id = item.id().as_ref().to_string();
+            out.push(Command {
+                accelerator: shortcuts
+                    .iter()
+                    .find(|s| s.id == id)
+                    .map(|s| s.accelerator.clone()),
+                id,
+                title: title.trim_end_matches("...").to_string(),
+                category: category.to_string(),
+            });
+        }
+    }
+
+    let mut commands = Vec::new();
+    let shortcuts = app_shortcuts(app);
+    let Some(menu) = app.menu() else {
+        return commands;
+    };
+    for top in menu.items().unwrap_or_default() {
+        if let MenuItemKind::Submenu(submenu) = top {
+            let category = submenu.text().unwrap_or_default();
+            collect(
+                submenu.items().unwrap_or_default(),
+                &category,
+                &shortcuts,
+                &mut commands,
+            );
+        }
+    }
+    commands
+}
+
+const MATCH_SCORE: i32 = 16;
+const BOUNDARY_BONUS: i32 = 10;
+const CONSECUTIVE_BONUS: i32 = 12;
+const GAP_PENALTY: i32 = 1;
+// 앞쪽 건너뛴 글자는 이 정도까지만 깎는다. 긴 제목 뒤쪽의 단어도 찾을 수 있어야 한다
+const MAX_LEADING_PENALTY: i32 = 3;
+
+// 점수가 같으면 sort_by_key가 안정 정렬이라 메뉴 순서가 유지된다
+pub fn fuzzy_match(commands: Vec<Command>, query: &str) -> Vec<CommandMatch> {
+    let mut matches: Vec<CommandMatch> = commands
+        .into_iter()
+        .filter_map(|command| {
+            let (score, indices) = fuzzy_score(&command.title, query)?;
+            Some(CommandMatch {
+                command,
+                score,
+                indices,
+            })
+        })
+        .collect();
+    matches.sort_by_key(|m| std::cmp::Reverse(m.score));
+    matches
+}
+
+// 대소문자를 가리지 않는 부분 수열 일치. 연속으로 맞거나 단어 첫 글자에 맞으면 가산점을
+// 주고 사이에 건너뛴 글자만큼 깎는다. 맞출 수 있는 배치 중 점수가 가장 높은 것을 고른다
+pub fn fuzzy_score(text: &str, query: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let boundary = |j: usize| {
+        j == 0
+            || !chars[j - 1].is_alphanumeric()
+            || (chars[j - 1].is_lowercase() && chars[j].is_uppercase())
+    };
+    let char_score = |j: usize| MATCH_SCORE + if boundary(j) { BOUNDARY_BONUS } else { 0 };
+
+    // best[i][j]: query[..=i]를 맞추고 query[i]가 text[j]에 올 때의 최고 점수와 앞 글자 위치
+    let mut best: Vec<Vec<Option<(i32, usize)>>> = vec![vec![None; chars.len()]; query.len()];
+    for j in 0..chars.len() {
+        if lower[j] == query[0] {
+            let leading = (j as i32 * GAP_PENALTY).min(MAX_LEADING_PENALTY);
+            best[0][j] = Some((char_score(j) - leading, 0));
+        }
+    }
+    for i in 1..query.len() {
+        for j in i..chars.len() {
+            if lower[j] != query[i] {
+                continue;
+            }
+            for k in i - 1..j {
+                let Some((prev, _)) = best[i - 1][k] else {
+                    continue;
+                };
+                let link = if k + 1 == j {
+                    CONSECUTIVE_BONUS
+                } else {
+                    -((j - k - 1) as i32 * GAP_PENALTY)
+                };
+                let score = prev + char_score(j) + link;
+                if best[i][j].is_none_or(|(s, _)| score > s) {
+                    best[i][j] = Some((score, k));
+                }
+            }
+        }
+    }
+
+    let last = query.len() - 1;
+    let (mut j, (score, _)) = best[last]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, cell)| Some((j, (*cell)?)))
+        .max_by_key(|(j, (score, _))| (*score, std::cmp::Reverse(*j)))?;
+    let mut indices = vec![0; query.len()];
+    for i in (0..query.len()).rev() {
+        indices[i] = j;
+        if let Some((_, prev)) = best[i][j] {
+            j = prev;
+        }
+    }
+    Some((score, indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::menu::create_menu;
+
+    #[test]
+    fn commands_mirror_the_menu() {
+        let app = tauri::test::mock_app();
+        app.set_menu(create_menu(&app).unwrap()).unwrap();
+        let commands = palette_commands(app.handle());
+
+        let save = commands.iter().find(|c| c.id == "save_project").unwrap();
+        assert_eq!(save.category, "File");
+        assert_eq!(save.accelerator.as_deref(), Some("CmdOrCtrl+S"));
+        let import = commands.iter().find(|c| c.id == "import_model").unwrap();
+        assert_eq!(import.title, "Import Model");
+        assert_eq!(import.accelerator, None);
+    }
+
+    fn command(title: &str) -> Command {
+        Command {
+            id: title.to_lowercase().replace(' ', "_"),
+            title: title.into(),
+            accelerator: None,
+            category: "MLOps".into(),
+        }
+    }
+
+    #[test]
+    fn fuzzy_matches_rank_word_starts_and_runs_first() {
+        let commands: Vec<Command> = [
+            "Stop Pipeline",
+            "Import Model",
+            "Train Model",
+            "Run Pipeline",
+        ]
+        .into_iter()
+        .map(command)
+        .collect();
+        let titles = |query: &str| -> Vec<String> {
+            fuzzy_match(commands.clone(), query)
+                .into_iter()
+                .map(|m| m.command.title)
+                .collect()
+        };
+
+        assert_eq!(titles("tm"), ["Train Model", "Import Model"]);
+        assert_eq!(titles("pipe"), ["Stop Pipeline", "Run Pipeline"]);
+        assert_eq!(titles("runp"), ["Run Pipeline"]);
+        assert_eq!(
+            titles(""),
+            [
+                "Stop Pipeline",
+                "Import Model",
+                "Train Model",
+                "Run Pipeline"
+            ]
+        );
+
+        let (_, indices) = fuzzy_score("Train Model", "TM").unwrap();
+        assert_eq!(indices, [0, 6]);
+        assert_eq!(fuzzy_score("Train Model", "xyz"), None);
+    }
+}
@@ -0,0 +1,1432 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::DialogExt;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, Command};
+
+use super::emit;
+use super::env::{process_env, SecretEnv};
+use super::history::{self, ProjectCommand};
+use super::jobs::{JobKind, JobSlots};
+use super::notify::notify;
+use super::operation::{remove_partial, Operation, OperationKind};
+use super::process::{run_handle, RunHandle, RunSignals};
+use super::project::ProjectState;
+use super::train::TrainingRuns;
+use crate::fs::menu_events::NavigationTarget;
+use crate::fs::pipeline::{clone_definition, PipelineDefinition, Stage};
+use crate::fs::preferences::Preferences;
+use crate::fs::project::{run_dir, PipelineRef, Project};
+use crate::fs::run::{
+    finish_run, read_metrics, MetricPoint, RunKind, RunRecord, RunStatus, ARTIFACTS_DIR,
+};
+use crate::fs::schedule::{Schedule, ScheduleId, ScheduledRun, Schedules};
+
+const RUNNER_ENV: &str = "METAGATE_PIPELINE_RUNNER";
+// 등록된 플러그인 스테이지를 {id: 실행 파일} JSON으로 러너에 넘긴다
+const PLUGINS_ENV: &str = "METAGATE_STAGE_PLUGINS";
+const ARTIFACTS_ENV: &str = "METAGATE_ARTIFACTS_DIR";
+const COPY_CHUNK: usize = 1024 * 1024;
+const DEFAULT_RUNNER: &str = "metagate-pipeline";
+pub const LOG_FILE: &str = "log.txt";
+
+pub type RunId = String;
+
+// 스테이지 종류별로 꼭 있어야 하는 파라미터
+const REQUIRED_PARAMS: [(&str, &[&str]); 6] = [
+    ("split", &["dataset"]),
+    ("tokenize", &["dataset"]),
+    ("train", &["epochs"]),
+    ("evaluate", &["metrics"]),
+    ("export", &["format"]),
+    ("deploy", &["target"]),
+];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SchemaField {
+    pub name: String,
+    #[serde(rename = "type", default)]
+    pub kind: Option<String>,
+    #[serde(default = "default_required")]
+    pub required: bool,
+}
+
+fn default_required() -> bool {
+    true
+}
+
+// 플러그인 매니페스트(JSON/YAML). executable은 매니페스트 기준 상대 경로도 된다
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StagePlugin {
+    pub id: String,
+    pub name: String,
+    pub executable: PathBuf,
+    #[serde(default)]
+    pub inputs: Vec<SchemaField>,
+    #[serde(default)]
+    pub outputs: Vec<SchemaField>,
+}
+
+impl StagePlugin {
+    fn required_inputs(&self) -> Vec<&str> {
+        self.inputs
+            .iter()
+            .filter(|field| field.required)
+            .map(|field| field.name.as_str())
+            .collect()
+    }
+}
+
+// 앱이 떠 있는 동안만 유지된다. 같은 id로 다시 등록하면 덮어쓴다
+#[derive(Default)]
+pub struct StagePlugins(Mutex<HashMap<String, StagePlugin>>);
+
+impl StagePlugins {
+    pub fn lock(&self) -> MutexGuard<'_, HashMap<String, StagePlugin>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+pub struct PipelineRun {
+    pub pipeline_id: String,
+    handle: RunHandle,
+    pub started_at: DateTime<Utc>,
+    // 러너가 마지막으로 알려 온 진행 상황
+    pub progress: Option<PipelineProgress>,
+}
+
+#[derive(Default)]
+pub struct PipelineRuns {
+    runs: Mutex<HashMap<RunId, PipelineRun>>,
+}
+
+impl PipelineRuns {
+    pub fn lock(&self) -> MutexGuard<'_, HashMap<RunId, PipelineRun>> {
+        self.runs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 패닉 훅에서 쓴다. 락을 잡은 채로 패닉했을 수 있으므로 기다리지 않는다
+    pub fn try_ids(&self) -> Vec<RunId> {
+        self.runs
+            .try_lock()
+            .map(|runs| runs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    // 목록에서 모두 빼므로 watch_run은 pipeline-finished를 보내지 않는다
+    pub fn drain_handles(&self) -> Vec<RunHandle> {
+        self.lock().drain().map(|(_, run)| run.handle).collect()
+    }
+}
+
+#[derive(Debug)]
+pub enum PipelineError {
+    NoProject,
+    NotFound(String),
+    AlreadyRunning(String),
+    InvalidEnv(String),
+    // 동시 실행 한도에 걸렸거나, 큐에서 기다리다 시작하지 못했다
+    Unavailable(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for PipelineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PipelineError::NoProject => write!(f, "no project is open"),
+            PipelineError::NotFound(id) => write!(f, "pipeline {id} not found"),
+            PipelineError::AlreadyRunning(id) => write!(f, "pipeline {id} is already running"),
+            PipelineError::InvalidEnv(err) | PipelineError::Unavailable(err) => write!(f, "{err}"),
+            PipelineError::Io(err) => write!(f, "failed to start pipeline: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for PipelineError {}
+
+impl From<io::Error> for PipelineError {
+    fn from(err: io::Error) -> Self {
+        PipelineError::Io(err)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineProgress {
+    pub run_id: RunId,
+    pub stage: String,
+    pub percent: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineStopped {
+    pub run_id: RunId,
+    pub forced: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineFinished {
+    pub run_id: RunId,
+    pub success: bool,
+    pub code: Option<i32>,
+}
+
+// 러너가 stdout으로 한 줄씩 내보내는 진행 상황
+#[derive(Deserialize)]
+struct ProgressLine {
+    stage: String,
+    percent: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ValidationIssue {
+    pub severity: Severity,
+    // 파이프라인 전체에 대한 문제면 None
+    pub stage: Option<String>,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    fn error(stage: Option<&str>, message: impl Into<String>) -> Self {
+        ValidationIssue {
+            severity: Severity::Error,
+            stage: stage.map(Into::into),
+            message: message.into(),
+        }
+    }
+
+    fn warning(stage: Option<&str>, message: impl Into<String>) -> Self {
+        ValidationIssue {
+            severity: Severity::Warning,
+            stage: stage.map(Into::into),
+            message: message.into(),
+        }
+    }
+}
+
+// 빈 목록이면 실행해도 되는 파이프라인
+#[command]
+pub fn validate_pipeline(
+    app: AppHandle,
+    pipeline_id: String,
+) -> Result<Vec<ValidationIssue>, String> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    let project = guard.as_ref().ok_or("no project is open")?;
+    let pipeline = project
+        .pipelines
+        .iter()
+        .find(|p| p.id == pipeline_id)
+        .ok_or_else(|| format!("pipeline {pipeline_id} not found"))?;
+    let definition =
+        PipelineDefinition::load(&project.root.join(&pipeline.path)).map_err(|e| e.to_string())?;
+    let plugins = app.state::<StagePlugins>();
+    let issues = validate_definition(&definition, project, &plugins.lock());
+    Ok(issues)
+}
+
+pub fn validate_definition(
+    definition: &PipelineDefinition,
+    project: &Project,
+    plugins: &HashMap<String, StagePlugin>,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    if definition.stages.is_empty() {
+        issues.push(ValidationIssue::warning(None, "pipeline has no stages"));
+    }
+
+    let mut names = HashSet::new();
+    for stage in &definition.stages {
+        if !names.insert(stage.name.as_str()) {
+            issues.push(ValidationIssue::error(
+                Some(&stage.name),
+                format!("duplicate stage name {}", stage.name),
+            ));
+        }
+    }
+
+    for stage in &definition.stages {
+        let name = Some(stage.name.as_str());
+        for dependency in &stage.depends_on {
+            if !names.contains(dependency.as_str()) {
+                issues.push(ValidationIssue::error(
+                    name,
+                    format!("depends on unknown stage {dependency}"),
+                ));
+            }
+        }
+
+        match stage.run.as_deref() {
+            None => issues.push(ValidationIssue::error(name, "stage has no run step")),
+            Some(run) => match required_params(run, plugins) {
+                Some(required) => {
+                    for param in required.iter().filter(|p| !stage.with.contains_key(**p)) {
+                        issues.push(ValidationIssue::error(
+                            name,
+                            format!("{run} stage requires parameter {param}"),
+                        ));
+                    }
+                }
+                None => issues.push(ValidationIssue::warning(
+                    name,
+                    format!("unknown run step {run}"),
+                )),
+            },
+        }
+
+        if let Some(model) = stage.param_str("model") {
+            let known = project
+                .models
+                .iter()
+                .any(|m| m.id == model || m.path == Path::new(model));
+            if !known {
+                issues.push(ValidationIssue::error(
+                    name,
+                    format!("model {model} is not in the project"),
+                ));
+            }
+        }
+        if let Some(dataset) = stage.param_str("dataset") {
+            let known = project
+                .datasets
+                .iter()
+                .any(|d| d.id == dataset || d.path == Path::new(dataset))
+                || project.root.join(dataset).exists();
+            if !known {
+                issues.push(ValidationIssue::error(
+                    name,
+                    format!("dataset {dataset} is not in the project"),
+                ));
+            }
+        }
+    }
+
+    if let Some(cycle) = find_cycle(&definition.stages) {
+        issues.push(ValidationIssue::error(
+            cycle.first().map(String::as_str),
+            format!("stages form a cycle: {}", cycle.join(" -> ")),
+        ));
+    }
+    issues
+}
+
+// 순환이 있으면 "a -> b -> a"처럼 읽을 수 있게 스테이지 이름 경로를 돌려준다
+fn find_cycle(stages: &[Stage]) -> Option<Vec<String>> {
+    fn visit(
+        node: usize,
+        edges: &[Vec<usize>],
+        visiting: &mut [u8],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        visiting[node] = 1;
+        path.push(node);
+        for &next in &edges[node] {
+            if visiting[next] == 1 {
+                let start = path.iter().position(|&n| n == next).unwrap_or(0);
+                let mut cycle = path[start..].to_vec();
+                cycle.push(next);
+                return Some(cycle);
+            }
+            if visiting[next] == 0 {
+                if let Some(cycle) = visit(next, edges, visiting, path) {
+                    return Some(cycle);
+                }
+            }
+        }
+        path.pop();
+        visiting[node] = 2;
+        None
+    }
+
+    let index: HashMap<&str, usize> = stages
+        .iter()
+        .enumerate()
+        .map(|(i, stage)| (stage.name.as_str(), i))
+        .collect();
+    let edges: Vec<Vec<usize>> = stages
+        .iter()
+        .map(|stage| {
+            stage
+                .depends_on
+                .iter()
+                .filter_map(|d| index.get(d.as_str()).copied())
+                .collect()
+        })
+        .collect();
+
+    let mut visiting = vec![0u8; stages.len()];
+    for node in 0..stages.len() {
+        if visiting[node] == 0 {
+            if let Some(cycle) = visit(node, &edges, &mut visiting, &mut Vec::new()) {
+                return Some(cycle.into_iter().map(|i| stages[i].name.clone()).collect());
+            }
+        }
+    }
+    None
+}
+
+fn builtin_params(run: &str) -> Option<&'static [&'static str]> {
+    REQUIRED_PARAMS
+        .iter()
+        .find(|(kind, _)| *kind == run)
+        .map(|(_, required)| *required)
+}
+
+fn required_params<'a>(
+    run: &str,
+    plugins: &'a HashMap<String, StagePlugin>,
+) -> Option<Vec<&'a str>> {
+    builtin_params(run)
+        .map(|required| required.to_vec())
+        .or_else(|| plugins.get(run).map(StagePlugin::required_inputs))
+}
+
+#[command]
+pub fn register_stage_plugin(app: AppHandle, manifest_path: String) -> Result<StagePlugin, String> {
+    let plugin = load_stage_plugin(Path::new(&manifest_path))?;
+    app.state::<StagePlugins>()
+        .lock()
+        .insert(plugin.id.clone(), plugin.clone());
+    log::info!("registered stage plugin {}", plugin.id);
+    Ok(plugin)
+}
+
+#[command]
+pub fn list_stage_plugins(plugins: tauri::State<'_, StagePlugins>) -> Vec<StagePlugin> {
+    let mut plugins: Vec<StagePlugin> = plugins.lock().values().cloned().collect();
+    plugins.sort_by(|a, b| a.id.cmp(&b.id));
+    plugins
+}
+
+pub fn load_stage_plugin(manifest: &Path) -> Result<StagePlugin, String> {
+    let raw = fs::read_to_string(manifest)
+        .map_err(|e| format!("failed to read {}: {e}", manifest.display()))?;
+    let mut plugin: StagePlugin = serde_yaml::from_str(&raw)
+        .map_err(|e| format!("malformed plugin manifest {}: {e}", manifest.display()))?;
+    if plugin.id.trim().is_empty() {
+        return Err("plugin id must not be empty".into());
+    }
+    if let Some(dir) = manifest.parent() {
+        plugin.executable = dir.join(&plugin.executable);
+    }
+    if !is_runnable(&plugin.executable) {
+        return Err(format!(
+            "{} is not an executable file",
+            plugin.executable.display()
+        ));
+    }
+
+    // 내장 스테이지를 대신하는 플러그인은 필수 입력이 똑같아야 기존 파이프라인이 깨지지 않는다
+    if let Some(builtin) = builtin_params(&plugin.id) {
+        let mut declared = plugin.required_inputs();
+        declared.sort_unstable();
+        let mut expected = builtin.to_vec();
+        expected.sort_unstable();
+        if declared != expected {
+            return Err(format!(
+                "plugin {} conflicts with the built-in stage: expected inputs [{}], got [{}]",
+                plugin.id,
+                expected.join(", "),
+                declared.join(", ")
+            ));
+        }
+    }
+    Ok(plugin)
+}
+
+#[cfg(unix)]
+fn is_runnable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    fs::metadata(path).is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_runnable(path: &Path) -> bool {
+    path.is_file()
+}
+
+// 정의 파일만 복사하므로 원본이 실행 중이어도 된다
+#[command]
+pub fn clone_pipeline(
+    app: AppHandle,
+    pipeline_id: String,
+    new_name: String,
+) -> Result<PipelineRef, String> {
+    let new_name = new_name.trim().to_string();
+    if new_name.is_empty() {
+        return Err("pipeline name cannot be empty".into());
+    }
+    let (root, original) = {
+        let state = app.state::<ProjectState>();
+        state.ensure_writable()?;
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        let pipeline = project
+            .pipelines
+            .iter()
+            .find(|p| p.id == pipeline_id)
+            .ok_or_else(|| format!("pipeline {pipeline_id} not found"))?;
+        (project.root.clone(), pipeline.clone())
+    };
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let path = original.path.with_file_name(format!("{id}.yaml"));
+    clone_definition(&root.join(&original.path), &root.join(&path), &new_name)
+        .map_err(|e| e.to_string())?;
+    let clone = PipelineRef {
+        id,
+        name: new_name,
+        path,
+        tags: original.tags,
+    };
+    // 매니페스트에 넣지 못했으면 방금 쓴 YAML이 어디에도 안 걸린 채 남지 않게 지운다
+    if let Err(err) = history::execute(&app, ProjectCommand::AddPipeline(clone.clone())) {
+        let _ = fs::remove_file(root.join(&clone.path));
+        return Err(err);
+    }
+    Ok(clone)
+}
+
+#[command]
+pub async fn run_pipeline(
+    app: AppHandle,
+    pipeline_id: String,
+    env: Option<HashMap<String, String>>,
+) -> Result<RunId, String> {
+    start_pipeline(&app, &pipeline_id, &env.unwrap_or_default()).map_err(|e| e.to_string())
+}
+
+// 동시 실행 한도가 차 있으면 큐에 넣고 run_id만 먼저 돌려준다. 실제로 뜰 때 job-started가 온다
+pub fn start_pipeline<R: Runtime>(
+    app: &AppHandle<R>,
+    pipeline_id: &str,
+    env: &HashMap<String, String>,
+) -> Result<RunId, PipelineError> {
+    let (root, definition) = pipeline_definition(app, pipeline_id)?;
+    let slots = app.state::<JobSlots>();
+    let running = app
+        .state::<PipelineRuns>()
+        .lock()
+        .values()
+        .any(|run| run.pipeline_id == pipeline_id);
+    if running || slots.is_queued(JobKind::Pipeline, pipeline_id) {
+        return Err(PipelineError::AlreadyRunning(pipeline_id.into()));
+    }
+
+    let run_id: RunId = uuid::Uuid::new_v4().to_string();
+    let start = {
+        let (app, run_id, pipeline_id, env) = (
+            app.clone(),
+            run_id.clone(),
+            pipeline_id.to_string(),
+            env.clone(),
+        );
+        move || {
+            spawn_pipeline(&app, &run_id, &root, &definition, &pipeline_id, &env)
+                .map_err(|e| e.to_string())
+        }
+    };
+    slots
+        .submit(
+            app,
+            run_id.clone(),
+            JobKind::Pipeline,
+            pipeline_id.into(),
+            start,
+        )
+        .map_err(PipelineError::Unavailable)?;
+    Ok(run_id)
+}
+
+fn pipeline_definition<R: Runtime>(
+    app: &AppHandle<R>,
+    pipeline_id: &str,
+) -> Result<(PathBuf, PathBuf), PipelineError> {
+    let guard = app.state::<ProjectState>();
+    let guard = guard.lock();
+    let project = guard.as_ref().ok_or(PipelineError::NoProject)?;
+    let pipeline = project
+        .pipelines
+        .iter()
+        .find(|p| p.id == pipeline_id)
+        .ok_or_else(|| PipelineError::NotFound(pipeline_id.into()))?;
+    Ok((project.root.clone(), project.root.join(&pipeline.path)))
+}
+
+// env는 실행 기록에 남기지 않는다. 비밀 값이 섞여 있을 수 있다
+fn spawn_pipeline<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    root: &Path,
+    definition: &Path,
+    pipeline_id: &str,
+    env: &HashMap<String, String>,
+) -> Result<(), PipelineError> {
+    // 큐에서 기다리는 사이에 다른 프로젝트를 열었을 수 있다
+    if pipeline_definition(app, pipeline_id)? != (root.to_path_buf(), definition.to_path_buf()) {
+        return Err(PipelineError::Unavailable(format!(
+            "pipeline {pipeline_id} changed before its queued run started"
+        )));
+    }
+    let runs = app.state::<PipelineRuns>();
+    let mut runs = runs.lock();
+    if runs.values().any(|run| run.pipeline_id == pipeline_id) {
+        return Err(PipelineError::AlreadyRunning(pipeline_id.into()));
+    }
+    let snapshot = PipelineDefinition::load(definition).ok();
+    let defaults = snapshot.as_ref().map(|d| d.env.clone()).unwrap_or_default();
+    let env = process_env(&defaults, &app.state::<SecretEnv>().snapshot(), env)
+        .map_err(PipelineError::InvalidEnv)?;
+
+    let dir = run_dir(root, run_id);
+    fs::create_dir_all(&dir)?;
+    let log = File::create(dir.join(LOG_FILE))?;
+    let mut record = RunRecord::start(run_id, RunKind::Pipeline, pipeline_id);
+    if let Some(snapshot) = &snapshot {
+        record = record.with_config(snapshot);
+    }
+    record.save(&dir)?;
+
+    let plugins: HashMap<String, PathBuf> = app
+        .state::<StagePlugins>()
+        .lock()
+        .values()
+        .map(|plugin| (plugin.id.clone(), plugin.executable.clone()))
+        .collect();
+    let runner = std::env::var(RUNNER_ENV).unwrap_or_else(|_| DEFAULT_RUNNER.into());
+    let child = Command::new(runner)
+        .arg("run")
+        .arg(definition)
+        .envs(&env)
+        .env(
+            PLUGINS_ENV,
+            serde_json::to_string(&plugins).unwrap_or_default(),
+        )
+        .env(ARTIFACTS_ENV, dir.join(ARTIFACTS_DIR))
+        .current_dir(root)
+        .stdout(Stdio::piped())
+        .stderr(log.try_clone()?)
+        .kill_on_drop(true)
+        .spawn()?;
+
+    let (handle, signals) = run_handle(&child);
+    runs.insert(
+        run_id.into(),
+        PipelineRun {
+            pipeline_id: pipeline_id.into(),
+            handle,
+            started_at: Utc::now(),
+            progress: None,
+        },
+    );
+    drop(runs);
+
+    tauri::async_runtime::spawn(watch_run(
+        app.clone(),
+        run_id.into(),
+        dir,
+        child,
+        log,
+        signals,
+    ));
+    Ok(())
+}
+
+// 시계가 바뀌거나 절전에서 깨어난 뒤에도 크게 늦지 않게 이보다 오래 자지 않는다
+const SCHEDULER_MAX_SLEEP: Duration = Duration::from_secs(60);
+
+#[derive(Default)]
+pub struct PipelineSchedules {
+    schedules: Mutex<Schedules>,
+    // 예약이 바뀌면 스케줄러가 다음 실행 시각을 다시 계산하게 깨운다
+    changed: tokio::sync::Notify,
+}
+
+impl PipelineSchedules {
+    fn lock(&self) -> MutexGuard<'_, Schedules> {
+        self.schedules.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn update<R: Runtime, T>(
+        &self,
+        app: &AppHandle<R>,
+        f: impl FnOnce(&mut Schedules) -> T,
+    ) -> Result<T, String> {
+        let mut schedules = self.lock();
+        let result = f(&mut schedules);
+        schedules
+            .save(app)
+            .map_err(|e| format!("failed to save schedules: {e}"))?;
+        drop(schedules);
+        self.changed.notify_one();
+        Ok(result)
+    }
+
+    pub fn for_pipeline(&self, project: &Path, pipeline_id: &str) -> Vec<ScheduleId> {
+        self.lock()
+            .list()
+            .iter()
+            .filter(|s| s.project == project && s.pipeline_id == pipeline_id)
+            .map(|s| s.id.clone())
+            .collect()
+    }
+
+    pub fn remove_pipeline<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        project: &Path,
+        pipeline_id: &str,
+    ) -> Result<(), String> {
+        self.update(app, |schedules| {
+            schedules.remove_pipeline(project, pipeline_id)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledRunEvent {
+    pub schedule_id: ScheduleId,
+    pub pipeline_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_id: Option<RunId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+// 지금 열린 프로젝트의 파이프라인만 예약할 수 있다. 실행할 때 그 프로젝트가 열려 있지 않으면 건너뛴다
+#[command]
+pub fn schedule_pipeline(
+    app: AppHandle,
+    pipeline_id: String,
+    schedule: Schedule,
+) -> Result<ScheduleId, String> {
+    let project = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        if !project.pipelines.iter().any(|p| p.id == pipeline_id) {
+            return Err(PipelineError::NotFound(pipeline_id).to_string());
+        }
+        project.root.clone()
+    };
+    let now = Utc::now();
+    let next_run = schedule
+        .next_after(now)?
+        .ok_or("the scheduled time is in the past")?;
+
+    let id: ScheduleId = uuid::Uuid::new_v4().to_string();
+    let run = ScheduledRun {
+        id: id.clone(),
+        pipeline_id,
+        project,
+        schedule,
+        next_run: Some(next_run),
+        last_run: None,
+        created_at: now,
+    };
+    app.state::<PipelineSchedules>()
+        .update(&app, |schedules| schedules.push(run))?;
+    Ok(id)
+}
+
+#[command]
+pub fn list_schedules(app: AppHandle) -> Vec<ScheduledRun> {
+    let mut schedules = app.state::<PipelineSchedules>().lock().list().to_vec();
+    schedules.sort_by_key(|s| s.next_run);
+    schedules
+}
+
+#[command]
+pub fn cancel_schedule(app: AppHandle, schedule_id: String) -> Result<(), String> {
+    app.state::<PipelineSchedules>()
+        .update(&app, |schedules| schedules.remove(&schedule_id))?
+        .map(|_| ())
+        .ok_or_else(|| format!("schedule {schedule_id} not found"))
+}
+
+// 저장된 예약을 읽고 앱이 끝날 때까지 돈다
+pub fn start_scheduler<R: Runtime>(app: &AppHandle<R>) {
+    *app.state::<PipelineSchedules>().lock() = Schedules::load(app);
+    let handle = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = handle.state::<PipelineSchedules>();
+        loop {
+            fire_due_schedules(&handle, Utc::now());
+            let open = open_root(&handle);
+            let wait = state
+                .lock()
+                .next_due(open.as_deref())
+                .map_or(SCHEDULER_MAX_SLEEP, |at| {
+                    (at - Utc::now())
+                        .to_std()
+                        .unwrap_or_default()
+                        .min(SCHEDULER_MAX_SLEEP)
+                });
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = state.changed.notified() => {}
+            }
+        }
+    });
+}
+
+fn fire_due_schedules<R: Runtime>(app: &AppHandle<R>, now: DateTime<Utc>) {
+    let schedules = app.state::<PipelineSchedules>();
+    let open = open_root(app);
+    let due = {
+        let mut guard = schedules.lock();
+        let due = guard.take_due(now, open.as_deref());
+        if !due.is_empty() {
+            if let Err(err) = guard.save(app) {
+                log::warn!("failed to save schedules: {err}");
+            }
+        }
+        due
+    };
+
+    for run in due {
+        let result = if open.as_deref() == Some(run.project.as_path()) {
+            start_pipeline(app, &run.pipeline_id, &HashMap::new()).map_err(|e| e.to_string())
+        } else {
+            Err(format!("project {} is not open", run.project.display()))
+        };
+        let (event, run_id, error) = match result {
+            Ok(run_id) => ("scheduled-run-started", Some(run_id), None),
+            Err(err) => {
+                log::warn!(
+                    "scheduled run {} of {} skipped: {err}",
+                    run.id,
+                    run.pipeline_id
+                );
+                ("scheduled-run-failed", None, Some(err))
+            }
+        };
+        emit(
+            app,
+            event,
+            ScheduledRunEvent {
+                schedule_id: run.id,
+                pipeline_id: run.pipeline_id,
+                run_id,
+                error,
+            },
+        );
+    }
+}
+
+fn open_root<R: Runtime>(app: &AppHandle<R>) -> Option<PathBuf> {
+    app.state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .map(|project| project.root.clone())
+}
+
+#[command]
+pub async fn stop_pipeline(app: AppHandle, run_id: String) -> Result<(), String> {
+    let grace = Duration::from_secs(Preferences::load(&app).stop_grace_period_secs);
+    stop_run(&app, &run_id, grace).await
+}
+
+pub async fn stop_run<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    grace: Duration,
+) -> Result<(), String> {
+    if app.state::<JobSlots>().dequeue(run_id) {
+        emit(
+            app,
+            "pipeline-stopped",
+            PipelineStopped {
+                run_id: run_id.into(),
+                forced: false,
+            },
+        );
+        return Ok(());
+    }
+    let run = app
+        .state::<PipelineRuns>()
+        .lock()
+        .remove(run_id)
+        .ok_or("no such run")?;
+
+    let forced = run.handle.stop(grace).await;
+    notify(
+        app,
+        "Pipeline stopped",
+        &format!("Pipeline run {run_id} was stopped"),
+        NavigationTarget::Pipelines,
+    );
+    emit(
+        app,
+        "pipeline-stopped",
+        PipelineStopped {
+            run_id: run_id.into(),
+            forced,
+        },
+    );
+    Ok(())
+}
+
+async fn watch_run<R: Runtime>(
+    app: AppHandle<R>,
+    run_id: RunId,
+    dir: PathBuf,
+    mut child: Child,
+    log: File,
+    mut signals: RunSignals,
+) {
+    let output = child.stdout.take().map(|stdout| {
+        tauri::async_runtime::spawn(forward_output(app.clone(), run_id.clone(), stdout, log))
+    });
+
+    let status = signals.wait(&mut child).await;
+    if let Some(output) = output {
+        let _ = output.await;
+    }
+    signals.mark_exited();
+    app.state::<JobSlots>().release(&app, &run_id);
+
+    // stop_run이 이미 목록에서 뺐으면 pipeline-stopped를 보냈으니 finished는 생략한다
+    if app.state::<PipelineRuns>().lock().remove(&run_id).is_some() {
+        let finished = finished(run_id, status);
+        let outcome = if finished.success {
+            RunStatus::Succeeded
+        } else {
+            RunStatus::Failed
+        };
+        finish_run(&dir, outcome, finished.code);
+        let body = if finished.success {
+            format!("Pipeline run {} finished", finished.run_id)
+        } else {
+            format!("Pipeline run {} failed", finished.run_id)
+        };
+        notify(
+            &app,
+            "Pipeline finished",
+            &body,
+            NavigationTarget::Pipelines,
+        );
+        emit(&app, "pipeline-finished", finished);
+    } else {
+        finish_run(&dir, RunStatus::Stopped, None);
+    }
+}
+
+// 출력은 로그 파일에 남기고, 진행 상황 줄은 이벤트로도 보낸다
+async fn forward_output<R: Runtime>(
+    app: AppHandle<R>,
+    run_id: RunId,
+    stdout: tokio::process::ChildStdout,
+    mut log: File,
+) {
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let _ = writeln!(log, "{line}");
+        if let Ok(progress) = serde_json::from_str::<ProgressLine>(&line) {
+            let progress = PipelineProgress {
+                run_id: run_id.clone(),
+                stage: progress.stage,
+                percent: progress.percent,
+            };
+            if let Some(run) = app.state::<PipelineRuns>().lock().get_mut(&run_id) {
+                run.progress = Some(progress.clone());
+            }
+            emit(&app, "pipeline-progress", progress);
+        }
+    }
+}
+
+fn finished(run_id: RunId, status: io::Result<ExitStatus>) -> PipelineFinished {
+    match status {
+        Ok(status) => PipelineFinished {
+            run_id,
+            success: status.success(),
+            code: status.code(),
+        },
+        Err(err) => {
+            log::error!("failed to wait for pipeline run {run_id}: {err}");
+            PipelineFinished {
+                run_id,
+                success: false,
+                code: None,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+}
+
+impl ReportFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "md",
+            ReportFormat::Html => "html",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ReportFormat::Markdown => "Markdown",
+            ReportFormat::Html => "HTML",
+        }
+    }
+}
+
+// 보고서 한 장에 들어가는 내용. 실행 기록과 metrics.jsonl에서 모은다
+struct RunReport {
+    record: RunRecord,
+    metrics: Vec<MetricPoint>,
+}
+
+#[command]
+pub async fn export_run_report(
+    app: AppHandle,
+    run_id: String,
+    format: ReportFormat,
+) -> Result<PathBuf, String> {
+    let report = gather_report(&app, &run_id)?;
+
+    let dialog = app.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        dialog
+            .dialog()
+            .file()
+            .set_title("Export Run Report")
+            .add_filter(format.label(), &[format.extension()])
+            .set_file_name(format!("run-{run_id}.{}", format.extension()))
+            .blocking_save_file()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let dest = picked
+        .ok_or("export cancelled")?
+        .into_path()
+        .map_err(|e| e.to_string())?
+        .with_extension(format.extension());
+    let rendered = match format {
+        ReportFormat::Markdown => report.to_markdown(),
+        ReportFormat::Html => report.to_html(),
+    };
+    fs::write(&dest, rendered).map_err(|e| format!("failed to write {}: {e}", dest.display()))?;
+    Ok(dest)
+}
+
+// 실패한 실행은 결과물이 불완전할 수 있어서 force 없이는 막는다.
+// 대상에 같은 파일이 하나라도 있으면 overwrite 없이는 아무것도 복사하지 않는다
+#[command]
+pub async fn export_artifacts(
+    app: AppHandle,
+    run_id: String,
+    dest: String,
+    force: Option<bool>,
+    overwrite: Option<bool>,
+) -> Result<usize, String> {
+    let record = gather_report(&app, &run_id)?.record;
+    if matches!(record.status, RunStatus::Failed | RunStatus::Interrupted)
+        && !force.unwrap_or(false)
+    {
+        return Err(format!(
+            "run {run_id} did not succeed; pass force to export its artifacts anyway"
+        ));
+    }
+
+    let root = current_root(&app)?;
+    let source = run_dir(&root, &run_id).join(ARTIFACTS_DIR);
+    let dest = PathBuf::from(dest);
+    let files = artifact_files(&source).map_err(|e| format!("failed to read artifacts: {e}"))?;
+    if !overwrite.unwrap_or(false) {
+        if let Some(existing) = files.iter().map(|f| dest.join(f)).find(|f| f.exists()) {
+            return Err(format!(
+                "{} already exists; pass overwrite to replace it",
+                existing.display()
+            ));
+        }
+    }
+
+    let operation = Operation::start(&app, OperationKind::Export, &dest);
+    tauri::async_runtime::spawn_blocking(move || copy_artifacts(&source, &dest, &files, operation))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+fn current_root<R: Runtime>(app: &AppHandle<R>) -> Result<PathBuf, String> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    guard
+        .as_ref()
+        .map(|p| p.root.clone())
+        .ok_or_else(|| "no project is open".into())
+}
+
+// artifacts 폴더 기준 상대 경로. 심볼릭 링크는 건너뛴다
+fn artifact_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if dir.is_dir() {
+        collect_files(dir, Path::new(""), &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(root: &Path, relative: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(root.join(relative))? {
+        let entry = entry?;
+        let kind = entry.file_type()?;
+        let path = relative.join(entry.file_name());
+        if kind.is_dir() {
+            collect_files(root, &path, files)?;
+        } else if kind.is_file() {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+// 취소되면 지금 복사 중인 파일만 지운다. 이미 끝난 파일은 남긴다
+fn copy_artifacts<R: Runtime>(
+    source: &Path,
+    dest: &Path,
+    files: &[PathBuf],
+    mut operation: Operation<R>,
+) -> Result<usize, String> {
+    let total: u64 = files
+        .iter()
+        .filter_map(|f| fs::metadata(source.join(f)).ok())
+        .map(|meta| meta.len())
+        .sum();
+    let mut done = 0u64;
+    for (copied, file) in files.iter().enumerate() {
+        let target = dest.join(file);
+        if let Some(dir) = target.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|e| format!("failed to create {}: {e}", dir.display()))?;
+        }
+        let finished = copy_chunked(&source.join(file), &target, |n| {
+            done += n;
+            operation.progress(done, total);
+            !operation.is_cancelled()
+        })
+        .map_err(|e| format!("failed to copy {}: {e}", file.display()))?;
+        if !finished {
+            remove_partial(&target);
+            operation.cancelled();
+            return Err(format!("export cancelled after {copied} files"));
+        }
+    }
+    operation.progress(total, total);
+    Ok(files.len())
+}
+
+// on_chunk가 false를 돌려주면 멈추고 Ok(false)
+fn copy_chunked(
+    source: &Path,
+    dest: &Path,
+    mut on_chunk: impl FnMut(u64) -> bool,
+) -> io::Result<bool> {
+    use std::io::Read;
+    let mut reader = File::open(source)?;
+    let mut writer = File::create(dest)?;
+    let mut buf = vec![0u8; COPY_CHUNK];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            return Ok(true);
+        }
+        writer.write_all(&buf[..n])?;
+        if !on_chunk(n as u64) {
+            return Ok(false);
+        }
+    }
+}
+
+fn gather_report<R: Runtime>(app: &AppHandle<R>, run_id: &str) -> Result<RunReport, String> {
+    let root = current_root(app)?;
+    let dir = run_dir(&root, run_id);
+    let mut record = RunRecord::load(&dir).ok_or_else(|| format!("run {run_id} not found"))?;
+
+    if matches!(record.status, RunStatus::Running | RunStatus::Paused) {
+        let active = app.state::<PipelineRuns>().lock().contains_key(run_id)
+            || app.state::<TrainingRuns>().lock().contains_key(run_id);
+        if active {
+            return Err(format!(
+                "run {run_id} is still in progress; wait for it to finish before exporting a report"
+            ));
+        }
+        record.status = RunStatus::Interrupted;
+    }
+    let metrics = read_metrics(&dir).map_err(|e| format!("failed to read metrics: {e}"))?;
+    Ok(RunReport { record, metrics })
+}
+
+impl RunReport {
+    fn duration(&self) -> String {
+        let Some(finished) = self.record.finished_at else {
+            return "-".into();
+        };
+        let secs = (finished - self.record.started_at).num_seconds().max(0);
+        format!("{}h {:02}m {:02}s", secs / 3600, secs / 60 % 60, secs % 60)
+    }
+
+    // (항목, 값) 순서대로 두 포맷이 같은 요약 표를 쓴다
+    fn summary(&self) -> Vec<(&'static str, String)> {
+        let record = &self.record;
+        let status = serde_json::to_value(record.status)
+            .ok()
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default();
+        let kind = match record.kind {
+            RunKind::Pipeline => "pipeline",
+            RunKind::Training => "training",
+        };
+        vec![
+            ("Run", record.run_id.clone()),
+            ("Kind", kind.into()),
+            ("Subject", record.subject.clone()),
+            ("Status", status),
+            ("Started", record.started_at.to_rfc3339()),
+            (
+                "Finished",
+                record
+                    .finished_at
+                    .map(|t| t.to_rfc3339())
+                    .unwrap_or_else(|| "-".into()),
+            ),
+            ("Duration", self.duration()),
+            (
+                "Exit code",
+                record
+                    .exit_code
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "-".into()),
+            ),
+        ]
+    }
+
+    fn config_json(&self) -> Option<String> {
+        self.record
+            .config
+            .as_ref()
+            .and_then(|c| serde_json::to_string_pretty(c).ok())
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut out = format!("# Run report: {}\n\n", self.record.subject);
+        out.push_str("| | |\n|---|---|\n");
+        for (key, value) in self.summary() {
+            out.push_str(&format!("| {key} | {value} |\n"));
+        }
+
+        if let Some(last) = self.metrics.last() {
+            out.push_str(&format!(
+                "\n## Final metrics\n\n- Epoch: {}\n- Loss: {}\n- Accuracy: {}\n",
+                last.epoch,
+                last.loss,
+                accuracy(last)
+            ));
+            out.push_str("\n| Epoch | Loss | Accuracy |\n|---|---|---|\n");
+            for point in &self.metrics {
+                out.push_str(&format!(
+                    "| {} | {} | {} |\n",
+                    point.epoch,
+                    point.loss,
+                    accuracy(point)
+                ));
+            }
+        }
+
+        if let Some(config) = self.config_json() {
+            out.push_str(&format!("\n## Configuration\n\n```json\n{config}\n```\n"));
+        }
+        out.push_str(&format!("\n_Generated {}_\n", Utc::now().to_rfc3339()));
+        out
+    }
+
+    // 다른 파일 없이 열리도록 스타일까지 한 파일에 넣는다
+    fn to_html(&self) -> String {
+        let title = escape_html(&self.record.subject);
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Run report: {title}</title>\n\
+             <style>body{{font-family:sans-serif;margin:2em}}table{{border-collapse:collapse;margin:1em 0}}\
+             th,td{{border:1px solid #ccc;padding:4px 8px;text-align:left}}th{{background:#f4f4f4}}</style>\n\
+             </head>\n<body>\n<h1>Run report: {title}</h1>\n<table>\n"
+        );
+        for (key, value) in self.summary() {
+            out.push_str(&format!(
+                "<tr><th>{key}</th><td>{}</td></tr>\n",
+                escape_html(&value)
+            ));
+        }
+        out.push_str("</table>\n");
+
+        if !self.metrics.is_empty() {
+            out.push_str("<h2>Metrics</h2>\n<table>\n<tr><th>Epoch</th><th>Loss</th><th>Accuracy</th></tr>\n");
+            for point in &self.metrics {
+                out.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    point.epoch,
+                    point.loss,
+                    accuracy(point)
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+
+        if let Some(config) = self.config_json() {
+            out.push_str(&format!(
+                "<h2>Configuration</h2>\n<pre>{}</pre>\n",
+                escape_html(&config)
+            ));
+        }
+        out.push_str(&format!(
+            "<p><small>Generated {}</small></p>\n</body>\n</html>\n",
+            Utc::now().to_rfc3339()
+        ));
+        out
+    }
+}
+
+fn accuracy(point: &MetricPoint) -> String {
+    point
+        .accuracy
+        .map(|a| a.to_string())
+        .unwrap_or_else(|| "-".into())
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TempDir;
+
+    fn definition(yaml: &str) -> PipelineDefinition {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn cycle_names_the_stages_involved() {
+        let definition = definition(
+            "name: p
+stages:
+  - { name: prepare, run: split, with: { dataset: data.csv } }
+  - { name: train, run: train, depends_on: [evaluate], with: { epochs: 1 } }
+  - { name: evaluate, run: evaluate, depends_on: [train], with: { metrics: [loss] } }
+",
+        );
+        let cycle = find_cycle(&definition.stages).unwrap();
+        assert_eq!(cycle, ["train", "evaluate", "train"]);
+
+        let project = Project::new(Path::new("/nonexistent"), "p");
+        let issues = validate_definition(&definition, &project, &HashMap::new());
+        assert!(issues
+            .iter()
+            .any(|i| i.message == "stages form a cycle: train -> evaluate -> train"));
+    }
+
+    #[test]
+    fn plugin_overriding_a_builtin_must_keep_its_inputs() {
+        let dir = TempDir::new("plugin");
+        let exe = std::env::current_exe().unwrap();
+        let manifest = |id: &str, input: &str| {
+            let path = dir.join(format!("{id}-{input}.yaml"));
+            let raw = format!(
+                "id: {id}\nname: Custom\nexecutable: {}\ninputs:\n  - {{ name: {input} }}\n",
+                exe.display()
+            );
+            fs::write(&path, raw).unwrap();
+            path
+        };
+        let compatible = load_stage_plugin(&manifest("split", "dataset"));
+        let conflicting = load_stage_plugin(&manifest("train", "dataset"));
+        let custom = load_stage_plugin(&manifest("augment", "images"));
+
+        assert!(compatible.is_ok());
+        assert!(conflicting
+            .unwrap_err()
+            .contains("conflicts with the built-in stage"));
+        assert_eq!(custom.unwrap().required_inputs(), ["images"]);
+    }
+
+    #[test]
+    fn artifact_files_keep_their_layout_and_skip_symlinks() {
+        let dir = TempDir::new("artifacts");
+        fs::create_dir_all(dir.join("eval/plots")).unwrap();
+        fs::write(dir.join("model.onnx"), "m").unwrap();
+        fs::write(dir.join("eval/plots/loss.png"), "p").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&dir, dir.join("eval/loop")).unwrap();
+
+        let files = artifact_files(&dir).unwrap();
+        let missing = artifact_files(&dir.join("nope")).unwrap();
+
+        assert_eq!(
+            files,
+            [
+                PathBuf::from("eval/plots/loss.png"),
+                PathBuf::from("model.onnx")
+            ]
+        );
+        assert!(missing.is_empty());
+    }
+
+    #[test]
+    fn acyclic_stages_have_no_cycle() {
+        let definition = definition(
+            "name: p
+stages:
+  - { name: a }
+  - { name: b, depends_on: [a] }
+  - { name: c, depends_on: [a, b] }
+",
+        );
+        assert!(find_cycle(&definition.stages).is_none());
+    }
+
+    #[test]
+    fn html_report_escapes_subject_and_tabulates_metrics() {
+        let mut record = RunRecord::start("r1", RunKind::Training, "<model>");
+        record.status = RunStatus::Succeeded;
+        record.finished_at = Some(record.started_at + chrono::Duration::seconds(3725));
+        let metrics = (1..=2)
+            .map(|epoch| MetricPoint {
+                epoch,
+                loss: 0.5 / epoch as f64,
+                accuracy: None,
+                timestamp: Utc::now(),
+            })
+            .collect();
+        let report = RunReport { record, metrics };
+
+        let html = report.to_html();
+        assert!(html.contains("Run report: &lt;model&gt;"));
+        assert!(html.contains("<tr><td>2</td><td>0.25</td><td>-</td></tr>"));
+        assert!(report.to_markdown().contains("| Duration | 1h 02m 05s |"));
+    }
+}
@@ -0,0 +1,55 @@
+use tauri::{command, AppHandle, Manager, Runtime, WebviewUrl, WebviewWindowBuilder};
+
+use super::emit;
+use super::jobs::JobSlots;
+use super::locale::{supported_locale, sync_locale};
+use super::shortcuts::sync_accelerators;
+use super::timing::CommandTimings;
+use super::view::{apply_saved_theme, apply_theme};
+use crate::fs::preferences::Preferences;
+use crate::fs::shortcuts::remap_all;
+
+const PREFERENCES_WINDOW: &str = "preferences";
+
+#[command]
+pub fn load_preferences(app: AppHandle) -> Preferences {
+    Preferences::load(&app)
+}
+
+#[command]
+pub fn save_preferences(app: AppHandle, mut preferences: Preferences) -> Result<(), String> {
+    // "ko-KR"처럼 와도 있는 파일의 코드로 바꿔서 저장한다
+    preferences.locale = supported_locale(&preferences.locale)?.into();
+    // 단축키 창과 같은 규칙으로 확인하고, 기본값과 같은 항목은 빼고 저장한다
+    preferences.accelerators = remap_all(&preferences.accelerators)?;
+    preferences.save(&app).map_err(|e| e.to_string())?;
+    apply_theme(&app, preferences.theme);
+    sync_accelerators(&app, preferences.accelerators.clone());
+    sync_locale(&app, &preferences.locale);
+    app.state::<CommandTimings>()
+        .set_slow_threshold(preferences.slow_command_ms);
+    app.state::<JobSlots>().set_limits(
+        &app,
+        preferences.max_concurrent_jobs,
+        preferences.queue_jobs_when_busy,
+    );
+    emit(&app, "preferences-changed", preferences);
+    Ok(())
+}
+
+// 이미 열려 있으면 새로 만들지 않고 앞으로 가져온다
+pub fn open_preferences_window<R: Runtime>(app: &AppHandle<R>) -> Result<(), tauri::Error> {
+    if let Some(window) = app.get_webview_window(PREFERENCES_WINDOW) {
+        return window.set_focus();
+    }
+    WebviewWindowBuilder::new(
+        app,
+        PREFERENCES_WINDOW,
+        WebviewUrl::App("index.html#preferences".into()),
+    )
+    .title("Preferences")
+    .inner_size(560.0, 480.0)
+    .resizable(false)
+    .build()
+    .and_then(|window| apply_saved_theme(&window))
+}
@@ -0,0 +1,94 @@
+use std::io;
+use std::process::ExitStatus;
+use std::time::Duration;
+
+use tokio::process::Child;
+use tokio::sync::{oneshot, watch};
+
+// 실행 중인 프로세스를 밖에서 멈추기 위한 손잡이. 목록(managed state)에 넣어 둔다
+pub struct RunHandle {
+    pub pid: Option<u32>,
+    kill: oneshot::Sender<()>,
+    exited: watch::Receiver<bool>,
+}
+
+// 프로세스를 지켜보는 태스크 쪽 반대편
+pub struct RunSignals {
+    kill: oneshot::Receiver<()>,
+    exited: watch::Sender<bool>,
+}
+
+pub fn run_handle(child: &Child) -> (RunHandle, RunSignals) {
+    let (kill_tx, kill_rx) = oneshot::channel();
+    let (exited_tx, exited_rx) = watch::channel(false);
+    (
+        RunHandle {
+            pid: child.id(),
+            kill: kill_tx,
+            exited: exited_rx,
+        },
+        RunSignals {
+            kill: kill_rx,
+            exited: exited_tx,
+        },
+    )
+}
+
+impl RunHandle {
+    // SIGTERM을 보내고 유예 시간 안에 안 끝나면 강제로 죽인다. 강제로 죽였으면 true
+    pub async fn stop(self, grace: Duration) -> bool {
+        let mut exited = self.exited;
+        let terminated = self.pid.is_some_and(terminate);
+        let exited_in_time = terminated
+            && tokio::time::timeout(grace, exited.wait_for(|done| *done))
+                .await
+                .is_ok();
+        if !exited_in_time {
+            let _ = self.kill.send(());
+            let _ = exited.wait_for(|done| *done).await;
+        }
+        !exited_in_time
+    }
+}
+
+impl RunSignals {
+    pub async fn wait(&mut self, child: &mut Child) -> io::Result<ExitStatus> {
+        tokio::select! {
+            status = child.wait() => status,
+            _ = &mut self.kill => {
+                let _ = child.start_kill();
+                child.wait().await
+            }
+        }
+    }
+
+    // 출력까지 다 정리한 뒤에 불러야 stop 쪽이 끝났다고 판단한다
+    pub fn mark_exited(&self) {
+        let _ = self.exited.send(true);
+    }
+}
+
+// 자식 프로세스에 보내는 시그널. Windows에는 SIGTERM이 없어서 호출 쪽에서 바로 kill 한다
+#[cfg(unix)]
+pub fn terminate(pid: u32) -> bool {
+    signal(pid, libc::SIGTERM)
+}
+
+#[cfg(not(unix))]
+pub fn terminate(_pid: u32) -> bool {
+    false
+}
+
+// SIGSTOP/SIGCONT로 멈추고 다시 돌린다. Windows에서는 호출 쪽이 다른 방법을 쓴다
+#[cfg(unix)]
+pub fn suspend(pid: u32, paused: bool) -> bool {
+    signal(pid, if paused { libc::SIGSTOP } else { libc::SIGCONT })
+}
+
+#[cfg(unix)]
+fn signal(pid: u32, sig: libc::c_int) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    unsafe { libc::kill(pid, sig) == 0 }
+}
@@ -0,0 +1,2013 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard, TryLockError};
+
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::DialogExt;
+use tauri_plugin_opener::OpenerExt;
+
+use super::dataset::{DatasetFormat, DATASETS_DIR};
+use super::delete::PendingDelete;
+use super::emit;
+use super::error::{guard, CommandError};
+use super::history::{self, ProjectCommand, UndoStack};
+use super::model::{asset_destination, detect_format, MODELS_DIR};
+use super::operation::{remove_partial, Operation, OperationKind};
+use super::tabs::{self, OpenProjects};
+use super::watch::{start_watching, ProjectWatcher};
+use super::window::update_window_title;
+use crate::fs::archive::{extract_project, make_portable, write_project};
+use crate::fs::hash::{copy_and_hash, hash_file};
+use crate::fs::menu::{find_menu_item, refresh_menu};
+use crate::fs::pipeline::PipelineDefinition;
+use crate::fs::project::{
+    AssetKind, DatasetRef, ModelRef, PipelineRef, Project, ProjectTemplate, MANIFEST_FILE,
+    PROJECT_DIRS, RUNS_DIR,
+};
+use crate::fs::recent::RecentProjects;
+
+#[derive(Default)]
+pub struct ProjectState {
+    pub current: Mutex<Option<Project>>,
+    pub dirty: AtomicBool,
+    // 다른 사람의 프로젝트를 훑어볼 때. 매니페스트와 프로젝트 폴더를 바꾸는 커맨드는 모두 거절한다
+    pub read_only: AtomicBool,
+    // 자동 저장과 직접 저장이 같은 임시 파일에 동시에 쓰지 않게 한 번에 하나만 저장한다
+    saving: Mutex<()>,
+}
+
+pub const READ_ONLY_ERROR: &str = "the project is open read-only";
+// 읽기 전용일 때 꺼 두는 메뉴 항목
+pub const MUTATING_MENU_ITEMS: [&str; 3] = ["save_project", "revert_project", "import_model"];
+
+// 활성 탭이 아닌 프로젝트. 메모리에만 있고 자동 저장도 하지 않으니 종료할 때 저장 안 된 탭이
+// 있으면 물어본다
+pub struct ParkedProject {
+    pub project: Project,
+    pub dirty: bool,
+    pub read_only: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    Saved,
+    Unchanged,
+    SaveInProgress,
+}
+
+impl ProjectState {
+    pub fn lock(&self) -> MutexGuard<'_, Option<Project>> {
+        self.current.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    #[cfg(test)]
+    pub fn open(&self, project: Project) {
+        self.open_with(project, false);
+    }
+
+    pub fn open_with(&self, project: Project, read_only: bool) {
+        *self.lock() = Some(project);
+        self.dirty.store(false, Ordering::SeqCst);
+        self.read_only.store(read_only, Ordering::SeqCst);
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        self.read_only.load(Ordering::SeqCst)
+    }
+
+    pub fn ensure_writable(&self) -> Result<(), String> {
+        if self.is_read_only() {
+            return Err(READ_ONLY_ERROR.into());
+        }
+        Ok(())
+    }
+
+    // 창 제목에 쓰는 이름
+    pub fn title(&self) -> Option<String> {
+        let read_only = self.is_read_only();
+        self.lock().as_ref().map(|project| match read_only {
+            true => format!("{} (Read-Only)", project.name),
+            false => project.name.clone(),
+        })
+    }
+
+    pub fn close(&self) -> Option<Project> {
+        self.dirty.store(false, Ordering::SeqCst);
+        self.read_only.store(false, Ordering::SeqCst);
+        self.lock().take()
+    }
+
+    // 다른 탭으로 넘어갈 때. 저장 안 된 변경은 그대로 들고 간다
+    pub fn park(&self) -> Option<ParkedProject> {
+        let _saving = self.lock_saving();
+        let dirty = self.dirty.load(Ordering::SeqCst);
+        let read_only = self.is_read_only();
+        self.close().map(|project| ParkedProject {
+            project,
+            dirty,
+            read_only,
+        })
+    }
+
+    pub fn resume(&self, parked: ParkedProject) {
+        self.open_with(parked.project, parked.read_only);
+        self.dirty.store(parked.dirty, Ordering::SeqCst);
+    }
+
+    // 프로젝트를 바꾸는 커맨드는 모두 이걸 거쳐서 dirty 플래그가 세워지게 한다
+    pub fn mutate<T>(&self, f: impl FnOnce(&mut Project) -> T) -> Result<T, String> {
+        self.ensure_writable()?;
+        let mut guard = self.lock();
+        let project = guard.as_mut().ok_or("no project is open")?;
+        let out = f(project);
+        self.dirty.store(true, Ordering::SeqCst);
+        Ok(out)
+    }
+
+    // 저장 안 된 다른 편집은 그대로 두고 이 변경만 디스크의 매니페스트에 바로 쓴다. 메모리의
+    // 프로젝트에도 같이 적용하지만 dirty 플래그는 건드리지 않는다. 그사이 root가 아닌
+    // 프로젝트로 바뀌었으면 아무것도 하지 않고 false
+    pub fn persist_change(
+        &self,
+        root: &Path,
+        change: impl Fn(&mut Project),
+    ) -> Result<bool, String> {
+        self.ensure_writable()?;
+        let _saving = self.lock_saving();
+        let is_open = |project: &Option<Project>| project.as_ref().is_some_and(|p| p.root == root);
+        if !is_open(&self.lock()) {
+            return Ok(false);
+        }
+        let manifest = root.join(MANIFEST_FILE);
+        let mut saved = Project::load(&manifest).map_err(|e| e.to_string())?;
+        change(&mut saved);
+        saved.save(&manifest).map_err(|e| e.to_string())?;
+        let mut current = self.lock();
+        if is_open(&current) {
+            current.as_mut().map(change);
+        }
+        Ok(true)
+    }
+
+    // 다른 저장이 진행 중이면 끝날 때까지 기다린다
+    pub fn save(&self) -> Result<bool, String> {
+        self.ensure_writable()?;
+        let _saving = self.lock_saving();
+        self.write_snapshot()
+    }
+
+    // 자동 저장처럼 기다릴 필요가 없는 쪽은 이걸 쓴다
+    pub fn try_save(&self) -> Result<SaveOutcome, String> {
+        if self.is_read_only() {
+            return Ok(SaveOutcome::Unchanged);
+        }
+        let _saving = match self.saving.try_lock() {
+            Ok(saving) => saving,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return Ok(SaveOutcome::SaveInProgress),
+        };
+        Ok(if self.write_snapshot()? {
+            SaveOutcome::Saved
+        } else {
+            SaveOutcome::Unchanged
+        })
+    }
+
+    fn lock_saving(&self) -> MutexGuard<'_, ()> {
+        self.saving.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 파일에 쓰는 동안 편집이 막히지 않게 복사본을 쓴다. 복사는 저장 잠금 안에서 하므로
+    // 먼저 복사한 오래된 내용이 나중 것을 덮어쓰는 일은 없다
+    fn write_snapshot(&self) -> Result<bool, String> {
+        let project = {
+            let guard = self.lock();
+            let Some(project) = guard.as_ref() else {
+                return Ok(false);
+            };
+            if !self.dirty.swap(false, Ordering::SeqCst) {
+                return Ok(false);
+            }
+            project.clone()
+        };
+        if let Err(err) = project.save(&project.manifest_path()) {
+            self.dirty.store(true, Ordering::SeqCst);
+            return Err(err.to_string());
+        }
+        Ok(true)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInfo {
+    pub name: String,
+    pub path: PathBuf,
+    pub version: String,
+}
+
+#[command]
+pub async fn open_project(app: AppHandle) -> Result<Option<ProjectInfo>, CommandError> {
+    pick_project(app).await
+}
+
+pub async fn pick_project<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Option<ProjectInfo>, CommandError> {
+    let dialog = app.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        dialog
+            .dialog()
+            .file()
+            .set_title("Open Project")
+            .blocking_pick_folder()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let Some(folder) = picked else {
+        return Ok(None);
+    };
+    let dir = folder.into_path().map_err(|e| e.to_string())?;
+    open_project_at(&app, &dir).map(Some)
+}
+
+// 매니페스트가 깨져 있어도 창이 죽지 않게 읽는 부분은 guard로 감싼다
+pub fn open_project_at<R: Runtime>(
+    app: &AppHandle<R>,
+    dir: &Path,
+) -> Result<ProjectInfo, CommandError> {
+    open_project_with(app, dir, false)
+}
+
+// 동료의 프로젝트를 건드리지 않고 볼 때. 고치려면 Save As로 복사본을 만든다
+#[command]
+pub fn open_project_readonly(app: AppHandle, path: String) -> Result<ProjectInfo, CommandError> {
+    open_project_with(&app, Path::new(&path), true)
+}
+
+fn open_project_with<R: Runtime>(
+    app: &AppHandle<R>,
+    dir: &Path,
+    read_only: bool,
+) -> Result<ProjectInfo, CommandError> {
+    // 다른 탭에 이미 열려 있으면 그 탭으로 넘어간다
+    if let Some(id) = app.state::<OpenProjects>().parked_at(dir) {
+        return Ok(tabs::switch_to(app, &id)?.info);
+    }
+    let project = guard(|| Ok(load_project(dir)?))?;
+    let info = project_info(&project);
+    switch_project(app, project, read_only);
+    remember_recent(app, dir);
+    Ok(info)
+}
+
+pub fn remember_recent<R: Runtime>(app: &AppHandle<R>, dir: &Path) {
+    let mut recent = RecentProjects::load(app);
+    recent.push(dir);
+    if let Err(err) = recent.save(app) {
+        log::warn!("failed to save recent projects: {err}");
+    }
+    refresh_menu(app);
+}
+
+// 템플릿 저장소를 받아 오는 동안 창이 멈추지 않게 따로 돌린다
+#[command]
+pub async fn create_project(
+    app: AppHandle,
+    name: String,
+    location: String,
+    template: ProjectTemplate,
+) -> Result<ProjectInfo, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        create_project_at(&app, &name, Path::new(&location), template)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+pub fn create_project_at<R: Runtime>(
+    app: &AppHandle<R>,
+    name: &str,
+    location: &Path,
+    template: ProjectTemplate,
+) -> Result<ProjectInfo, String> {
+    let name = name.trim();
+    validate_project_name(name)?;
+    let dir = location.join(name);
+    ensure_empty_dir(&dir)?;
+
+    let project = Project::create(&dir, name, template).map_err(|e| e.to_string())?;
+    let info = project_info(&project);
+    switch_project(app, project, false);
+    remember_recent(app, &dir);
+    Ok(info)
+}
+
+// 프로젝트 이름이 그대로 디렉토리 이름이 되므로 어느 OS에서도 쓸 수 있는 이름만 받는다
+pub fn validate_project_name(name: &str) -> Result<(), String> {
+    const ILLEGAL: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
+    const RESERVED: [&str; 4] = ["CON", "PRN", "AUX", "NUL"];
+
+    if name.is_empty() {
+        return Err("project name cannot be empty".into());
+    }
+    if name == "." || name == ".." {
+        return Err(format!("\"{name}\" is not a valid project name"));
+    }
+    if let Some(c) = name.chars().find(|c| ILLEGAL.contains(c) || c.is_control()) {
+        return Err(format!("project name cannot contain {c:?}"));
+    }
+    if name.ends_with('.') {
+        return Err("project name cannot end with a dot".into());
+    }
+    let stem = name.split('.').next().unwrap_or(name).to_ascii_uppercase();
+    let is_device = |prefix: &str| {
+        stem.strip_prefix(prefix)
+            .is_some_and(|n| n.len() == 1 && n.chars().all(|c| c.is_ascii_digit()))
+    };
+    if RESERVED.contains(&stem.as_str()) || is_device("COM") || is_device("LPT") {
+        return Err(format!("\"{name}\" is a reserved name on Windows"));
+    }
+    Ok(())
+}
+
+// 없는 디렉토리나 빈 디렉토리에만 만든다. 기존 파일을 덮어쓰지 않기 위함
+fn ensure_empty_dir(dir: &Path) -> Result<(), String> {
+    match std::fs::read_dir(dir) {
+        Ok(mut entries) => {
+            if entries.next().is_some() {
+                return Err(format!("{} already exists and is not empty", dir.display()));
+            }
+            Ok(())
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(format!("cannot use {}: {err}", dir.display())),
+    }
+}
+
+// 이전 프로젝트의 실행 취소 기록과 파일 감시는 새 프로젝트로 넘어가지 않는다
+fn switch_project<R: Runtime>(app: &AppHandle<R>, project: Project, read_only: bool) {
+    app.state::<ProjectWatcher>().stop();
+    app.state::<PendingDelete>().clear();
+    let state = app.state::<ProjectState>();
+    state.open_with(project, read_only);
+    app.state::<OpenProjects>().replace_active();
+    update_window_title(app);
+    sync_read_only_menu(app);
+    history::reset(app);
+    scan_in_background(app);
+}
+
+pub fn sync_read_only_menu<R: Runtime>(app: &AppHandle<R>) {
+    let enabled = !app.state::<ProjectState>().is_read_only();
+    for id in MUTATING_MENU_ITEMS {
+        let Some(item) = find_menu_item(app, id) else {
+            continue;
+        };
+        if let Some(Err(err)) = item.as_menuitem().map(|i| i.set_enabled(enabled)) {
+            log::warn!("failed to update {id} menu item: {err}");
+        }
+    }
+}
+
+// 저장 안 된 변경이 있으면 닫지 않고 프론트엔드에 물어본다. 대답은 confirm_discard로 온다
+#[command]
+pub fn close_project(app: AppHandle) -> Result<bool, String> {
+    Ok(request_close_project(&app))
+}
+
+pub fn request_close_project<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let state = app.state::<ProjectState>();
+    if state.lock().is_some() && state.dirty.load(Ordering::SeqCst) {
+        emit(app, "confirm-discard", ());
+        return false;
+    }
+    discard_project(app);
+    true
+}
+
+#[command]
+pub fn confirm_discard(app: AppHandle, discard: bool) -> Result<bool, String> {
+    if discard {
+        discard_project(&app);
+    }
+    Ok(discard)
+}
+
+fn discard_project<R: Runtime>(app: &AppHandle<R>) {
+    app.state::<ProjectWatcher>().stop();
+    app.state::<PendingDelete>().clear();
+    if app.state::<ProjectState>().close().is_some() {
+        update_window_title(app);
+        sync_read_only_menu(app);
+        history::reset(app);
+        emit(app, "project-closed", ());
+        // 다른 탭은 그대로 두고 옆 탭으로 넘어간다
+        tabs::close_active(app);
+    }
+}
+
+// 대답을 기다리는 되돌리기. 물어본 프로젝트 폴더를 들고 있다
+#[derive(Default)]
+pub struct PendingRevert(Mutex<Option<PathBuf>>);
+
+impl PendingRevert {
+    fn lock(&self) -> MutexGuard<'_, Option<PathBuf>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+// 마지막 저장 이후의 변경을 모두 버린다. 변경이 없으면 아무것도 하지 않고,
+// 있으면 confirm-revert로 물어본 뒤 confirm_revert로 대답을 받는다
+#[command]
+pub fn revert_project(app: AppHandle) -> Result<(), String> {
+    request_revert_project(&app)
+}
+
+pub fn request_revert_project<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    let state = app.state::<ProjectState>();
+    let root = state
+        .lock()
+        .as_ref()
+        .map(|project| tabs::canonical(&project.root))
+        .ok_or("no project is open")?;
+    if state.dirty.load(Ordering::SeqCst) {
+        *app.state::<PendingRevert>().lock() = Some(root);
+        emit(app, "confirm-revert", ());
+    }
+    Ok(())
+}
+
+#[command]
+pub fn confirm_revert(app: AppHandle, revert: bool) -> Result<bool, String> {
+    answer_revert(&app, revert)
+}
+
+fn answer_revert<R: Runtime>(app: &AppHandle<R>, revert: bool) -> Result<bool, String> {
+    let Some(asked) = app.state::<PendingRevert>().lock().take() else {
+        return Err("no revert is waiting for confirmation".into());
+    };
+    if !revert {
+        return Ok(false);
+    }
+    let root = current(app, |project| project.root.clone())?;
+    if tabs::canonical(&root) != asked {
+        return Err("the project changed since the revert was requested".into());
+    }
+    let project = load_project(&root)?;
+    let info = project_info(&project);
+    // 읽기 전용으로 연 프로젝트는 되돌린 뒤에도 읽기 전용이다
+    let state = app.state::<ProjectState>();
+    state.open_with(project, state.is_read_only());
+    update_window_title(app);
+    history::reset(app);
+    emit(app, "project-reverted", info);
+    Ok(true)
+}
+
+#[command]
+pub fn save_project(app: AppHandle) -> Result<bool, String> {
+    let saved = app.state::<ProjectState>().save()?;
+    update_window_title(&app);
+    Ok(saved)
+}
+
+// 현재 프로젝트를 고른 폴더 아래 <프로젝트 이름>으로 복사하고 그쪽을 연다.
+// 원래 파일은 건드리지 않고, 저장 안 된 변경은 새 위치의 매니페스트에만 써진다
+#[command]
+pub async fn save_project_as(app: AppHandle) -> Result<PathBuf, String> {
+    pick_save_as(app)
+        .await?
+        .ok_or_else(|| "no destination was chosen".into())
+}
+
+// 폴더 선택을 취소하면 None
+pub async fn pick_save_as<R: Runtime>(app: AppHandle<R>) -> Result<Option<PathBuf>, String> {
+    let (source, name) = current(&app, |project| (project.root.clone(), project.name.clone()))?;
+    let dialog = app.clone();
+    let picked = tauri::async_runtime::spawn_blocking(move || {
+        dialog
+            .dialog()
+            .file()
+            .set_title("Save Project As")
+            .blocking_pick_folder()
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    let Some(picked) = picked else {
+        return Ok(None);
+    };
+    let dest = picked.into_path().map_err(|e| e.to_string())?.join(&name);
+    check_destination(&source, &dest)?;
+    ensure_empty_dir(&dest)?;
+
+    let (from, to) = (source.clone(), dest.clone());
+    tauri::async_runtime::spawn_blocking(move || copy_project(&from, &to))
+        .await
+        .map_err(|e| e.to_string())??;
+
+    let state = app.state::<ProjectState>();
+    let _saving = state.lock_saving();
+    let info = {
+        let mut guard = state.lock();
+        let project = guard.as_mut().ok_or("no project is open")?;
+        let mut moved = project.clone();
+        moved.root = dest.clone();
+        moved
+            .save(&moved.manifest_path())
+            .map_err(|e| e.to_string())?;
+        *project = moved;
+        state.dirty.store(false, Ordering::SeqCst);
+        project_info(project)
+    };
+    // 읽기 전용으로 연 프로젝트도 복사본은 내 것이니 고칠 수 있게 한다
+    if state.read_only.swap(false, Ordering::SeqCst) {
+        sync_read_only_menu(&app);
+    }
+    // 원래 폴더를 감시하고 있었으면 새 폴더를 감시한다. 실패하면 감시 없이 둔다
+    if app.state::<ProjectWatcher>().is_watching(&source) {
+        if let Err(err) = start_watching(&app, &dest) {
+            log::warn!("{err}");
+        }
+    }
+    update_window_title(&app);
+    remember_recent(&app, &dest);
+    emit(&app, "project-saved-as", info);
+    Ok(Some(dest))
+}
+
+// dest가 폴더면 그 안에 <프로젝트 이름>.zip으로 만든다. 아직 저장하지 않은 변경도 매니페스트에 들어간다
+#[command]
+pub async fn export_project_archive(
+    app: AppHandle,
+    dest: String,
+    include_runs: bool,
+) -> Result<PathBuf, String> {
+    let mut project = current(&app, Project::clone)?;
+    let dest = PathBuf::from(dest);
+    let dest = if dest.is_dir() {
+        dest.join(format!("{}.zip", project.name))
+    } else {
+        dest
+    };
+    if dest.exists() {
+        return Err(format!("{} already exists", dest.display()));
+    }
+    check_destination(&project.root, &dest)?;
+    make_portable(&mut project);
+
+    let mut operation = Operation::start(&app, OperationKind::Export, &dest);
+    let out = dest.clone();
+    let finished = tauri::async_runtime::spawn_blocking(move || {
+        let finished = write_project(&project, &out, include_runs, |done, total| {
+            operation.progress(done, total);
+            !operation.is_cancelled()
+        });
+        if matches!(finished, Ok(false)) {
+            operation.cancelled();
+        }
+        finished
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| format!("failed to write {}: {e}", dest.display()))?;
+    if !finished {
+        return Err("export cancelled".into());
+    }
+    Ok(dest)
+}
+
+// 압축 파일이 있는 폴더에 풀고 최근 프로젝트에 올린다. 여는 건 프론트엔드가 고른다
+#[command]
+pub async fn import_project_archive(app: AppHandle, path: String) -> Result<ProjectInfo, String> {
+    let archive = PathBuf::from(path);
+    let parent = archive
+        .parent()
+        .ok_or_else(|| format!("{} has no parent folder", archive.display()))?
+        .to_path_buf();
+    let dir = tauri::async_runtime::spawn_blocking(move || extract_project(&archive, &parent))
+        .await
+        .map_err(|e| e.to_string())??;
+    let project = load_project(&dir)?;
+    remember_recent(&app, &dir);
+    Ok(project_info(&project))
+}
+
+// 원본 안으로 복사하면 복사본을 다시 복사하게 되므로 막는다.
+// 대상은 아직 없을 수 있어서 존재하는 가장 가까운 상위 디렉토리로 비교한다
+pub fn check_destination(source: &Path, dest: &Path) -> Result<(), String> {
+    let source = source
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve {}: {e}", source.display()))?;
+    let existing = dest
+        .ancestors()
+        .find(|dir| dir.exists())
+        .unwrap_or(dest)
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve {}: {e}", dest.display()))?;
+    if existing.starts_with(&source) {
+        return Err(format!(
+            "{} is inside the project; choose a folder outside it",
+            dest.display()
+        ));
+    }
+    Ok(())
+}
+
+// 중간에 실패하면 만들던 복사본을 지운다. 심볼릭 링크는 복사하지 않는다
+fn copy_project(source: &Path, dest: &Path) -> Result<(), String> {
+    let created = !dest.exists();
+    let result = copy_dir(source, dest);
+    if let Err(err) = &result {
+        log::warn!("save as to {} failed: {err}", dest.display());
+        if created {
+            let _ = std::fs::remove_dir_all(dest);
+        }
+    }
+    result.map_err(|e| format!("failed to copy the project to {}: {e}", dest.display()))
+}
+
+fn copy_dir(source: &Path, dest: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dest)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let kind = entry.file_type()?;
+        let target = dest.join(entry.file_name());
+        if kind.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if kind.is_file() {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+// 매니페스트의 이름만 바꾸고 디렉토리는 그대로 둔다. 다른 편집처럼 저장할 때 파일에 써진다
+#[command]
+pub fn rename_project(app: AppHandle, name: String) -> Result<(), String> {
+    let name = name.trim().to_string();
+    validate_project_name(&name)?;
+    let from = current(&app, |project| project.name.clone())?;
+    if from == name {
+        return Ok(());
+    }
+    history::execute(&app, ProjectCommand::Rename { from, to: name })
+}
+
+// 이름을 바꾸거나 그걸 되돌린 뒤에 부른다
+pub fn project_renamed<R: Runtime>(app: &AppHandle<R>) {
+    let Some(info) = app
+        .state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .map(project_info)
+    else {
+        return;
+    };
+    remember_recent(app, &info.path);
+    emit(app, "project-renamed", info);
+}
+
+#[command]
+pub fn remove_model(app: AppHandle, model_id: String) -> Result<(), String> {
+    let (index, model) = current(&app, |project| {
+        project
+            .models
+            .iter()
+            .position(|m| m.id == model_id)
+            .map(|index| (index, project.models[index].clone()))
+    })?
+    .ok_or_else(|| format!("model {model_id} not found"))?;
+    history::execute(&app, ProjectCommand::RemoveModel { index, model })
+}
+
+#[command]
+pub fn remove_dataset(app: AppHandle, dataset_id: String) -> Result<(), String> {
+    let (index, dataset) = current(&app, |project| {
+        project
+            .datasets
+            .iter()
+            .position(|d| d.id == dataset_id)
+            .map(|index| (index, project.datasets[index].clone()))
+    })?
+    .ok_or_else(|| format!("dataset {dataset_id} not found"))?;
+    history::execute(&app, ProjectCommand::RemoveDataset { index, dataset })
+}
+
+#[derive(Debug, Clone)]
+enum Asset {
+    Model(ModelRef),
+    Dataset(DatasetRef),
+}
+
+impl Asset {
+    fn id(&self) -> &str {
+        match self {
+            Asset::Model(model) => &model.id,
+            Asset::Dataset(dataset) => &dataset.id,
+        }
+    }
+
+    fn is_in(&self, project: &Project) -> bool {
+        match self {
+            Asset::Model(model) => project.models.iter().any(|m| m.id == model.id),
+            Asset::Dataset(dataset) => project.datasets.iter().any(|d| d.id == dataset.id),
+        }
+    }
+
+    fn add_to(self, project: &mut Project) {
+        match self {
+            Asset::Model(model) => project.models.push(model),
+            Asset::Dataset(dataset) => project.datasets.push(dataset),
+        }
+    }
+}
+
+// 열린 프로젝트의 모델이나 데이터셋을 다른 프로젝트로 복사한다. move_asset이면 복사본을 확인한 뒤
+// 이쪽의 항목과 파일을 지운다. 되돌리기로는 취소할 수 없다
+#[command]
+pub async fn transfer_asset(
+    app: AppHandle,
+    asset_id: String,
+    asset_kind: AssetKind,
+    target_project: String,
+    move_asset: Option<bool>,
+) -> Result<(), String> {
+    let move_asset = move_asset.unwrap_or(false);
+    let state = app.state::<ProjectState>();
+    if move_asset {
+        state.ensure_writable()?;
+    }
+    let (root, asset) = current(&app, |project| {
+        let asset = match asset_kind {
+            AssetKind::Model => project
+                .models
+                .iter()
+                .find(|m| m.id == asset_id)
+                .cloned()
+                .map(Asset::Model),
+            AssetKind::Dataset => project
+                .datasets
+                .iter()
+                .find(|d| d.id == asset_id)
+                .cloned()
+                .map(Asset::Dataset),
+        };
+        (project.root.clone(), asset)
+    })?;
+    let asset = asset.ok_or_else(|| match asset_kind {
+        AssetKind::Model => format!("model {asset_id} not found"),
+        AssetKind::Dataset => format!("dataset {asset_id} not found"),
+    })?;
+
+    let target = PathBuf::from(target_project);
+    let same = match (root.canonicalize(), target.canonicalize()) {
+        (Ok(root), Ok(target)) => root == target,
+        _ => root == target,
+    };
+    if same {
+        return Ok(());
+    }
+
+    // 대상이 뒤에 있는 탭이면 아직 저장 안 된 항목과도 겹치면 안 된다
+    let tabs = app.state::<OpenProjects>();
+    tabs.ensure_parked_writable(&target)?;
+    if let Some(name) = tabs
+        .with_parked(&target, |project| {
+            asset.is_in(project).then(|| project.name.clone())
+        })
+        .flatten()
+    {
+        return Err(format!("{name} already contains {}", asset.id()));
+    }
+    let (source, added) = {
+        let (root, target) = (root.clone(), target.clone());
+        tauri::async_runtime::spawn_blocking(move || copy_asset(&root, &target, asset))
+            .await
+            .map_err(|e| e.to_string())??
+    };
+    tabs.with_parked(&target, |project| added.add_to(project));
+    if !move_asset {
+        return Ok(());
+    }
+
+    // 복사하는 동안 다른 프로젝트로 바뀌었으면 원본은 건드리지 않는다. 저장 안 된 다른 편집은
+    // 같이 저장하지 않고 옮긴 항목만 매니페스트에서 뺀다
+    let removed = state.persist_change(&root, |project| match asset_kind {
+        AssetKind::Model => project.models.retain(|m| m.id != asset_id),
+        AssetKind::Dataset => project.datasets.retain(|d| d.id != asset_id),
+    })?;
+    if !removed {
+        return Err("the project changed during the transfer; the original was kept".into());
+    }
+    app.state::<UndoStack>()
+        .forget(asset_kind.into(), &asset_id);
+    history::sync_menu(&app);
+    update_window_title(&app);
+    // 매니페스트에서 먼저 뺀 뒤에 파일을 지워야 없는 파일을 가리키는 항목이 남지 않는다
+    if let Err(err) = std::fs::remove_file(&source) {
+        log::warn!("failed to remove {}: {err}", source.display());
+    }
+    let info = state.lock().as_ref().map(project_info);
+    emit(&app, "project-changed", info);
+    Ok(())
+}
+
+// 대상 프로젝트에 파일을 복사하고 매니페스트에 등록한다. 원본 파일 경로와 등록한 항목을 돌려준다
+fn copy_asset(root: &Path, target: &Path, asset: Asset) -> Result<(PathBuf, Asset), String> {
+    let mut project = load_project(target)?;
+    let (id, path, dir) = match &asset {
+        Asset::Model(m) => (m.id.as_str(), &m.path, MODELS_DIR),
+        Asset::Dataset(d) => (d.id.as_str(), &d.path, DATASETS_DIR),
+    };
+    if asset.is_in(&project) {
+        return Err(format!("{} already contains {id}", project.name));
+    }
+    let source = root.join(path);
+    if !source.is_file() {
+        return Err(format!("{} is missing", source.display()));
+    }
+
+    std::fs::create_dir_all(target.join(dir))
+        .map_err(|e| format!("failed to create {}: {e}", target.join(dir).display()))?;
+    let relative = asset_destination(target, dir, &source, id);
+    let dest = target.join(&relative);
+    // 복사본을 다시 읽어서 해시가 같은지 본다. 옮기기라면 이게 맞아야 원본을 지울 수 있다
+    let verified = copy_and_hash(&source, &dest, |_, _| true).and_then(|copied| {
+        let written = hash_file(&dest)?;
+        Ok(copied.filter(|copied| *copied == written))
+    });
+    let sha256 = match verified {
+        Ok(Some(sha256)) => sha256,
+        Ok(None) => {
+            remove_partial(&dest);
+            return Err(format!("the copy of {} does not match", source.display()));
+        }
+        Err(err) => {
+            remove_partial(&dest);
+            return Err(format!("failed to copy {}: {err}", source.display()));
+        }
+    };
+
+    let added = match asset {
+        Asset::Model(model) => Asset::Model(ModelRef {
+            path: relative,
+            sha256: Some(sha256),
+            ..model
+        }),
+        Asset::Dataset(dataset) => Asset::Dataset(DatasetRef {
+            path: relative,
+            ..dataset
+        }),
+    };
+    added.clone().add_to(&mut project);
+    if let Err(err) = project.save(&project.manifest_path()) {
+        remove_partial(&dest);
+        return Err(err.to_string());
+    }
+    Ok((source, added))
+}
+
+// 태그는 앞뒤 공백을 떼고 소문자로 저장해서 "Vision"과 "vision "이 같은 태그가 되게 한다
+pub fn normalize_tag(tag: &str) -> Result<String, String> {
+    let tag = tag.trim().to_lowercase();
+    if tag.is_empty() {
+        return Err("tag cannot be empty".into());
+    }
+    Ok(tag)
+}
+
+fn asset_tags<R: Runtime>(
+    app: &AppHandle<R>,
+    kind: AssetKind,
+    id: &str,
+) -> Result<Vec<String>, String> {
+    current(app, |project| project.tags(kind, id).cloned())?
+        .ok_or_else(|| format!("{} {id} not found", kind_label(kind)))
+}
+
+fn kind_label(kind: AssetKind) -> &'static str {
+    match kind {
+        AssetKind::Model => "model",
+        AssetKind::Dataset => "dataset",
+    }
+}
+
+// 이미 붙어 있는 태그면 기록을 남기지 않고 그대로 둔다
+#[command]
+pub fn add_tag(
+    app: AppHandle,
+    kind: AssetKind,
+    id: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    let tag = normalize_tag(&tag)?;
+    if !asset_tags(&app, kind, &id)?.contains(&tag) {
+        history::execute(
+            &app,
+            ProjectCommand::AddTag {
+                kind,
+                id: id.clone(),
+                tag,
+            },
+        )?;
+    }
+    asset_tags(&app, kind, &id)
+}
+
+#[command]
+pub fn remove_tag(
+    app: AppHandle,
+    kind: AssetKind,
+    id: String,
+    tag: String,
+) -> Result<Vec<String>, String> {
+    let tag = normalize_tag(&tag)?;
+    if let Some(index) = asset_tags(&app, kind, &id)?.iter().position(|t| *t == tag) {
+        history::execute(
+            &app,
+            ProjectCommand::RemoveTag {
+                kind,
+                id: id.clone(),
+                index,
+                tag,
+            },
+        )?;
+    }
+    asset_tags(&app, kind, &id)
+}
+
+#[command]
+pub fn list_tags(app: AppHandle, kind: AssetKind, id: String) -> Result<Vec<String>, String> {
+    asset_tags(&app, kind, &id)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaggedAsset {
+    pub kind: AssetKind,
+    pub id: String,
+    pub name: String,
+    pub tags: Vec<String>,
+}
+
+// 태그를 하나도 안 주면 거르지 않고 전부 돌려준다
+#[command]
+pub fn filter_by_tags(
+    app: AppHandle,
+    tags: Vec<String>,
+    match_all: bool,
+) -> Result<Vec<TaggedAsset>, String> {
+    let wanted = tags
+        .iter()
+        .map(|tag| normalize_tag(tag))
+        .collect::<Result<Vec<_>, _>>()?;
+    current(&app, |project| filter_assets(project, &wanted, match_all))
+}
+
+pub fn filter_assets(project: &Project, wanted: &[String], match_all: bool) -> Vec<TaggedAsset> {
+    let models = project
+        .models
+        .iter()
+        .map(|m| (AssetKind::Model, &m.id, &m.name, &m.tags));
+    let datasets = project
+        .datasets
+        .iter()
+        .map(|d| (AssetKind::Dataset, &d.id, &d.name, &d.tags));
+    models
+        .chain(datasets)
+        .filter(|(_, _, _, tags)| {
+            wanted.is_empty()
+                || if match_all {
+                    wanted.iter().all(|w| tags.contains(w))
+                } else {
+                    wanted.iter().any(|w| tags.contains(w))
+                }
+        })
+        .map(|(kind, id, name, tags)| TaggedAsset {
+            kind,
+            id: id.clone(),
+            name: name.clone(),
+            tags: tags.clone(),
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Model,
+    Dataset,
+    Pipeline,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    pub id: String,
+    pub name: String,
+    pub score: u32,
+}
+
+// 태그로 맞은 것은 이름으로 맞은 것보다 조금 뒤에 온다
+const TAG_PENALTY: u32 = 10;
+
+#[command]
+pub fn search_project(app: AppHandle, query: String) -> Result<Vec<SearchHit>, String> {
+    current(&app, |project| search(project, &query))
+}
+
+pub fn search(project: &Project, query: &str) -> Vec<SearchHit> {
+    let query = query.trim();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let entries = project
+        .models
+        .iter()
+        .map(|m| (SearchKind::Model, &m.id, &m.name, &m.tags))
+        .chain(
+            project
+                .datasets
+                .iter()
+                .map(|d| (SearchKind::Dataset, &d.id, &d.name, &d.tags)),
+        )
+        .chain(
+            project
+                .pipelines
+                .iter()
+                .map(|p| (SearchKind::Pipeline, &p.id, &p.name, &p.tags)),
+        );
+
+    let mut hits: Vec<SearchHit> = entries
+        .filter_map(|(kind, id, name, tags)| {
+            let by_tag = tags
+                .iter()
+                .filter_map(|tag| match_score(query, tag))
+                .map(|score| score.saturating_sub(TAG_PENALTY));
+            let score = match_score(query, name).into_iter().chain(by_tag).max()?;
+            Some(SearchHit {
+                kind,
+                id: id.clone(),
+                name: name.clone(),
+                score,
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    hits
+}
+
+// 대소문자는 구분하지 않는다. 완전 일치 100, 앞부분 일치 80, 부분 문자열 60,
+// 글자가 순서대로만 들어 있으면(퍼지) 흩어진 정도에 따라 40 이하
+pub fn match_score(query: &str, text: &str) -> Option<u32> {
+    let query = query.to_lowercase();
+    let text = text.to_lowercase();
+    if query.is_empty() {
+        return None;
+    }
+    if text == query {
+        return Some(100);
+    }
+    if text.starts_with(&query) {
+        return Some(80);
+    }
+    if text.contains(&query) {
+        return Some(60);
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    let mut positions = Vec::new();
+    let mut from = 0;
+    for c in query.chars() {
+        let found = text[from..].iter().position(|t| *t == c)? + from;
+        positions.push(found);
+        from = found + 1;
+    }
+    let span = positions.last()? - positions.first()? + 1;
+    Some((40 * positions.len() / span).max(1) as u32)
+}
+
+// Finder/탐색기에서 파일을 선택한 채로 연다. 리눅스는 파일 관리자가 지원하지 않으면 상위 폴더를 연다
+#[command]
+pub fn reveal_in_explorer(app: AppHandle, path: String) -> Result<(), String> {
+    let root = current(&app, |project| project.root.clone())?;
+    let target = project_path(&root, Path::new(&path))?;
+    app.opener()
+        .reveal_item_in_dir(&target)
+        .map_err(|e| format!("failed to reveal {}: {e}", target.display()))
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct DiskUsage {
+    pub models_bytes: u64,
+    pub datasets_bytes: u64,
+    pub runs_bytes: u64,
+    // 위 세 폴더 밖의 파일(매니페스트, 파이프라인 등)도 포함한다
+    pub total_bytes: u64,
+}
+
+// 큰 프로젝트는 오래 걸리므로 블로킹 스레드에서 돈다
+#[command]
+pub async fn project_disk_usage(app: AppHandle) -> Result<DiskUsage, String> {
+    let root = current(&app, |project| project.root.clone())?;
+    tauri::async_runtime::spawn_blocking(move || disk_usage(&root))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub fn disk_usage(root: &Path) -> DiskUsage {
+    let mut usage = DiskUsage::default();
+    let Ok(entries) = std::fs::read_dir(root) else {
+        log::warn!("failed to read project directory {}", root.display());
+        return usage;
+    };
+    for entry in entries.flatten() {
+        let bytes = entry_size(&entry.path());
+        usage.total_bytes += bytes;
+        match entry.file_name().to_str() {
+            Some(MODELS_DIR) => usage.models_bytes += bytes,
+            Some(DATASETS_DIR) => usage.datasets_bytes += bytes,
+            Some(RUNS_DIR) => usage.runs_bytes += bytes,
+            _ => {}
+        }
+    }
+    usage
+}
+
+// 심볼릭 링크는 따라가지 않는다(순환 방지). 못 읽는 폴더는 로그만 남기고 0으로 친다
+pub fn entry_size(path: &Path) -> u64 {
+    let meta = match std::fs::symlink_metadata(path) {
+        Ok(meta) => meta,
+        Err(err) => {
+            log::warn!("failed to stat {}: {err}", path.display());
+            return 0;
+        }
+    };
+    if meta.file_type().is_symlink() {
+        return 0;
+    }
+    if !meta.is_dir() {
+        return meta.len();
+    }
+    match std::fs::read_dir(path) {
+        Ok(entries) => entries
+            .flatten()
+            .map(|entry| entry_size(&entry.path()))
+            .sum(),
+        Err(err) => {
+            log::warn!("failed to read {}: {err}", path.display());
+            0
+        }
+    }
+}
+
+// 프로젝트 기준 상대 경로나 프로젝트 안의 절대 경로만 받는다.
+// 심볼릭 링크나 ..로 밖을 가리키지 못하게 실제 경로로 풀어서 비교한다
+pub fn project_path(root: &Path, path: &Path) -> Result<PathBuf, String> {
+    let joined = root.join(path);
+    if !joined.exists() {
+        return Err(format!("{} does not exist", joined.display()));
+    }
+    let resolved = joined
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve {}: {e}", joined.display()))?;
+    let root = root
+        .canonicalize()
+        .map_err(|e| format!("failed to resolve {}: {e}", root.display()))?;
+    if !resolved.starts_with(&root) {
+        return Err(format!("{} is outside the project", path.display()));
+    }
+    Ok(resolved)
+}
+
+fn current<R: Runtime, T>(app: &AppHandle<R>, f: impl FnOnce(&Project) -> T) -> Result<T, String> {
+    let state = app.state::<ProjectState>();
+    let guard = state.lock();
+    guard
+        .as_ref()
+        .map(f)
+        .ok_or_else(|| "no project is open".into())
+}
+
+pub fn project_info(project: &Project) -> ProjectInfo {
+    ProjectInfo {
+        name: project.name.clone(),
+        path: project.root.clone(),
+        version: project.version.clone(),
+    }
+}
+
+pub fn load_project(dir: &Path) -> Result<Project, String> {
+    let manifest = dir.join(MANIFEST_FILE);
+    if !manifest.is_file() {
+        return Err(format!(
+            "{} is not a Metagate project: {MANIFEST_FILE} not found",
+            dir.display()
+        ));
+    }
+
+    Project::load(&manifest).map_err(|e| e.to_string())
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct IntegrityReport {
+    pub root: PathBuf,
+    pub missing: Vec<IntegrityEntry>,
+    // 모델만 들어간다. 데이터셋과 파이프라인은 매니페스트에 크기와 해시가 없어서 있는지만 본다
+    pub modified: Vec<IntegrityEntry>,
+    pub ok: Vec<IntegrityEntry>,
+}
+
+impl IntegrityReport {
+    pub fn has_problems(&self) -> bool {
+        !self.missing.is_empty() || !self.modified.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IntegrityEntry {
+    // 매니페스트 섹션 이름: models, datasets, pipelines
+    pub section: String,
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+    // modified일 때 무엇이 달라졌는지
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[command]
+pub async fn scan_integrity(app: AppHandle) -> Result<IntegrityReport, String> {
+    let project = current(&app, Project::clone)?;
+    tauri::async_runtime::spawn_blocking(move || check_integrity(&project))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// 프로젝트를 연 직후에 돌린다. 큰 모델은 해시에 시간이 걸리니 기다리지 않는다
+pub fn scan_in_background<R: Runtime>(app: &AppHandle<R>) {
+    let Ok(project) = current(app, Project::clone) else {
+        return;
+    };
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let report =
+            match tauri::async_runtime::spawn_blocking(move || check_integrity(&project)).await {
+                Ok(report) => report,
+                Err(err) => return log::warn!("integrity scan failed: {err}"),
+            };
+        // 그사이 다른 프로젝트로 바꿨으면 알리지 않는다
+        let still_open = current(&app, |p| p.root == report.root).unwrap_or(false);
+        if still_open && report.has_problems() {
+            emit(&app, "integrity-warning", report);
+        }
+    });
+}
+
+// 파일이 있는지 보고, 모델은 기록된 크기와 해시까지 맞춰 본다. 크기가 다르면 해시는 건너뛴다.
+// 데이터셋과 파이프라인은 비교할 기록이 없어서 내용이 바뀌어도 ok로 나온다
+pub fn check_integrity(project: &Project) -> IntegrityReport {
+    let mut report = IntegrityReport {
+        root: project.root.clone(),
+        ..IntegrityReport::default()
+    };
+    let mut check = |section: &str, id: &str, name: &str, path: &Path, reason: Option<String>| {
+        let entry = IntegrityEntry {
+            section: section.into(),
+            id: id.into(),
+            name: name.into(),
+            path: path.to_path_buf(),
+            reason: None,
+        };
+        if !project.root.join(path).is_file() {
+            report.missing.push(entry);
+        } else if reason.is_some() {
+            report.modified.push(IntegrityEntry { reason, ..entry });
+        } else {
+            report.ok.push(entry);
+        }
+    };
+    for model in &project.models {
+        let reason = model_changes(&project.root.join(&model.path), model);
+        check("models", &model.id, &model.name, &model.path, reason);
+    }
+    for dataset in &project.datasets {
+        check("datasets", &dataset.id, &dataset.name, &dataset.path, None);
+    }
+    for pipeline in &project.pipelines {
+        check(
+            "pipelines",
+            &pipeline.id,
+            &pipeline.name,
+            &pipeline.path,
+            None,
+        );
+    }
+    report
+}
+
+fn model_changes(file: &Path, model: &ModelRef) -> Option<String> {
+    let size = std::fs::metadata(file).ok()?.len();
+    if size != model.size_bytes {
+        return Some(format!(
+            "size changed from {} to {size} bytes",
+            model.size_bytes
+        ));
+    }
+    let recorded = model.sha256.as_ref()?;
+    match hash_file(file) {
+        Ok(hash) if hash == *recorded => None,
+        Ok(_) => Some("contents no longer match the recorded hash".into()),
+        Err(err) => Some(format!("failed to hash: {err}")),
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RepairReport {
+    // 고치기 전 매니페스트를 복사해 둔 곳. 매니페스트가 없었으면 None
+    pub backup: Option<PathBuf>,
+    // 원래 매니페스트가 열리지 않던 이유
+    pub manifest_error: Option<String>,
+    // 매니페스트에서 그대로 살린 항목 수
+    pub kept: usize,
+    pub recovered: Vec<RecoveredAsset>,
+    pub dropped: Vec<DroppedEntry>,
+}
+
+// 폴더에는 있는데 매니페스트에 없던 파일을 새로 등록한 것
+#[derive(Debug, Clone, Serialize)]
+pub struct RecoveredAsset {
+    // 매니페스트 섹션 이름: models, datasets, pipelines
+    pub section: String,
+    pub id: String,
+    pub name: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DroppedEntry {
+    pub section: String,
+    // 항목의 id. id도 읽을 수 없으면 #순번, 폴더의 파일이면 경로
+    pub entry: String,
+    pub reason: String,
+}
+
+// 매니페스트가 깨져서 열리지 않을 때 쓴다. 읽을 수 있는 항목은 그대로 두고, 폴더에 있는데
+// 매니페스트에 없는 파일은 새 id로 등록한다. 다시 열기는 사용자가 한다
+#[command]
+pub async fn repair_project(app: AppHandle, path: String) -> Result<RepairReport, String> {
+    let dir = PathBuf::from(path);
+    ensure_closed(&app, &dir)?;
+    tauri::async_runtime::spawn_blocking(move || repair_manifest(&dir))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+// 열린 프로젝트는 저장할 때 메모리의 내용으로 덮어쓰므로 고쳐도 소용이 없다. 뒤쪽 탭도 마찬가지다
+fn ensure_closed<R: Runtime>(app: &AppHandle<R>, dir: &Path) -> Result<(), String> {
+    let dir = tabs::canonical(dir);
+    let is_active = app
+        .state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .is_some_and(|project| tabs::canonical(&project.root) == dir);
+    if is_active || app.state::<OpenProjects>().parked_at(&dir).is_some() {
+        return Err("close the project before repairing it".into());
+    }
+    Ok(())
+}
+
+pub fn repair_manifest(dir: &Path) -> Result<RepairReport, String> {
+    if !dir.is_dir() {
+        return Err(format!("{} is not a directory", dir.display()));
+    }
+    let manifest = dir.join(MANIFEST_FILE);
+    let mut report = RepairReport::default();
+    let value = match std::fs::read(&manifest) {
+        Ok(raw) => {
+            let raw = String::from_utf8_lossy(&raw);
+            if let Err(err) = serde_json::from_str::<Project>(&raw) {
+                report.manifest_error = Some(err.to_string());
+            }
+            // JSON으로도 읽히지 않으면 살릴 항목 없이 폴더만 훑는다
+            serde_json::from_str(&raw).unwrap_or(Value::Null)
+        }
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            report.manifest_error = Some(format!("{MANIFEST_FILE} not found"));
+            Value::Null
+        }
+        Err(err) => return Err(format!("failed to read {}: {err}", manifest.display())),
+    };
+
+    let fallback_name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "Recovered Project".into());
+    let name = value
+        .get("name")
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|n| !n.is_empty())
+        .unwrap_or(&fallback_name);
+    let mut project = Project::new(dir, name);
+    if let Some(version) = value.get("version").and_then(Value::as_str) {
+        project.version = version.into();
+    }
+    if let Some(created_at) = value
+        .get("created_at")
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+    {
+        project.created_at = created_at;
+    }
+    project.models = salvage(&value, "models", |m: &ModelRef| &m.id, &mut report);
+    project.datasets = salvage(&value, "datasets", |d: &DatasetRef| &d.id, &mut report);
+    project.pipelines = salvage(&value, "pipelines", |p: &PipelineRef| &p.id, &mut report);
+
+    let referenced: HashSet<PathBuf> = project
+        .models
+        .iter()
+        .map(|m| &m.path)
+        .chain(project.datasets.iter().map(|d| &d.path))
+        .chain(project.pipelines.iter().map(|p| &p.path))
+        .map(|path| dir.join(path))
+        .collect();
+    let [models_dir, datasets_dir, pipelines_dir] = PROJECT_DIRS;
+    for (relative, path) in unregistered_files(dir, models_dir, &referenced) {
+        let format = match detect_format(&path) {
+            Ok(format) => format,
+            Err(err) => {
+                report
+                    .dropped
+                    .push(unreadable_file("models", &relative, err.to_string()));
+                continue;
+            }
+        };
+        let metadata = std::fs::metadata(&path).ok();
+        let model = ModelRef {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: file_stem(&relative),
+            path: relative,
+            format,
+            size_bytes: metadata.as_ref().map_or(0, |m| m.len()),
+            // 언제 가져왔는지는 알 수 없으니 파일을 마지막으로 고친 시각으로 대신한다
+            imported_at: metadata
+                .and_then(|m| m.modified().ok())
+                .map_or_else(Utc::now, DateTime::<Utc>::from),
+            sha256: hash_file(&path).ok(),
+            tags: Vec::new(),
+            description: None,
+            parent_id: None,
+        };
+        report.recovered.push(recovered_asset(
+            "models",
+            &model.id,
+            &model.name,
+            &model.path,
+        ));
+        project.models.push(model);
+    }
+    for (relative, _) in unregistered_files(dir, datasets_dir, &referenced) {
+        if DatasetFormat::from_path(&relative).is_none() {
+            report.dropped.push(unreadable_file(
+                "datasets",
+                &relative,
+                "unsupported dataset format".into(),
+            ));
+            continue;
+        }
+        let dataset = DatasetRef {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: file_stem(&relative),
+            path: relative,
+            tags: Vec::new(),
+            stats: None,
+        };
+        report.recovered.push(recovered_asset(
+            "datasets",
+            &dataset.id,
+            &dataset.name,
+            &dataset.path,
+        ));
+        project.datasets.push(dataset);
+    }
+    for (relative, path) in unregistered_files(dir, pipelines_dir, &referenced) {
+        let definition = match PipelineDefinition::load(&path) {
+            Ok(definition) => definition,
+            Err(err) => {
+                report
+                    .dropped
+                    .push(unreadable_file("pipelines", &relative, err.to_string()));
+                continue;
+            }
+        };
+        let pipeline = PipelineRef {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: definition.name,
+            path: relative,
+            tags: Vec::new(),
+        };
+        report.recovered.push(recovered_asset(
+            "pipelines",
+            &pipeline.id,
+            &pipeline.name,
+            &pipeline.path,
+        ));
+        project.pipelines.push(pipeline);
+    }
+    // 새 매니페스트를 쓰기 전에 원본을 남긴다. 복사가 안 되면 고치지 않는다
+    if manifest.exists() {
+        let backup = dir.join(format!(
+            "{MANIFEST_FILE}.bak-{}",
+            Utc::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::copy(&manifest, &backup)
+            .map_err(|e| format!("failed to back up {}: {e}", manifest.display()))?;
+        report.backup = Some(backup);
+    }
+    project.save(&manifest).map_err(|e| e.to_string())?;
+    log::info!(
+        "repaired {}: kept {}, recovered {}, dropped {}",
+        manifest.display(),
+        report.kept,
+        report.recovered.len(),
+        report.dropped.len()
+    );
+    Ok(report)
+}
+
+// 항목을 하나씩 읽어서 읽히는 것만 남긴다. id가 겹치면 앞의 것을 쓴다
+fn salvage<T: DeserializeOwned>(
+    manifest: &Value,
+    section: &str,
+    id: impl Fn(&T) -> &String,
+    report: &mut RepairReport,
+) -> Vec<T> {
+    let entries = manifest
+        .get(section)
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let mut kept: Vec<T> = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let reason = match serde_json::from_value::<T>(entry.clone()) {
+            Ok(item) if kept.iter().any(|k| id(k) == id(&item)) => "duplicate id".to_string(),
+            Ok(item) => {
+                kept.push(item);
+                continue;
+            }
+            Err(err) => err.to_string(),
+        };
+        report.dropped.push(DroppedEntry {
+            section: section.into(),
+            entry: entry
+                .get("id")
+                .and_then(Value::as_str)
+                .map_or_else(|| format!("#{index}"), String::from),
+            reason,
+        });
+    }
+    report.kept += kept.len();
+    kept
+}
+
+// sub 폴더 바로 아래에서 매니페스트가 가리키지 않는 파일. 숨김 파일은 뺀다
+fn unregistered_files(
+    root: &Path,
+    sub: &str,
+    referenced: &HashSet<PathBuf>,
+) -> Vec<(PathBuf, PathBuf)> {
+    let Ok(entries) = std::fs::read_dir(root.join(sub)) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(PathBuf, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+        .filter(|entry| !entry.file_name().to_string_lossy().starts_with('.'))
+        .map(|entry| (PathBuf::from(sub).join(entry.file_name()), entry.path()))
+        .filter(|(_, path)| !referenced.contains(path))
+        .collect();
+    files.sort();
+    files
+}
+
+fn recovered_asset(section: &str, id: &str, name: &str, path: &Path) -> RecoveredAsset {
+    RecoveredAsset {
+        section: section.into(),
+        id: id.into(),
+        name: name.into(),
+        path: path.to_path_buf(),
+    }
+}
+
+fn unreadable_file(section: &str, relative: &Path, reason: String) -> DroppedEntry {
+    DroppedEntry {
+        section: section.into(),
+        entry: relative.display().to_string(),
+        reason,
+    }
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::project::{DatasetRef, PipelineRef};
+    use crate::testing::TempDir;
+
+    #[test]
+    fn match_score_ranks_exact_prefix_substring_then_fuzzy() {
+        assert_eq!(match_score("resnet", "ResNet"), Some(100));
+        assert_eq!(match_score("res", "ResNet-50"), Some(80));
+        assert_eq!(match_score("net", "ResNet-50"), Some(60));
+        // r, n, 5가 8글자에 걸쳐 있다
+        assert_eq!(match_score("rn5", "resnet-50"), Some(15));
+        assert!(match_score("rn5", "resnet-5") > match_score("rn5", "r-n-------5"));
+        assert_eq!(match_score("xyz", "ResNet"), None);
+        assert_eq!(match_score("", "ResNet"), None);
+    }
+
+    #[test]
+    fn search_orders_hits_and_ignores_empty_queries() {
+        let mut project = Project::new(Path::new("/p"), "p");
+        project.pipelines.push(PipelineRef {
+            id: "train".into(),
+            name: "Train Classifier".into(),
+            path: "pipelines/train.yaml".into(),
+            tags: vec!["vision".into()],
+        });
+        project.datasets.push(DatasetRef {
+            id: "images".into(),
+            name: "Images".into(),
+            path: "datasets/images.csv".into(),
+            tags: Vec::new(),
+            stats: None,
+        });
+
+        let hits = search(&project, "VISION");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].kind, SearchKind::Pipeline);
+        assert_eq!(hits[0].score, 90);
+
+        let hits = search(&project, "i");
+        assert_eq!(hits[0].id, "images");
+        assert!(search(&project, "  ").is_empty());
+    }
+
+    #[test]
+    fn concurrent_saves_never_leave_a_partial_manifest() {
+        let root = TempDir::new("save");
+        let state = ProjectState::default();
+        state.open(Project::new(&root, "p"));
+        let manifest = root.join(MANIFEST_FILE);
+        let done = AtomicBool::new(false);
+
+        std::thread::scope(|scope| {
+            // 저장하는 동안 계속 읽어서 중간 상태의 파일이 보이는지 확인한다
+            let reader = scope.spawn(|| {
+                while !done.load(Ordering::SeqCst) {
+                    if manifest.exists() {
+                        Project::load(&manifest).expect("manifest is always complete");
+                    }
+                }
+            });
+            let writers: Vec<_> = (0..2)
+                .map(|writer| {
+                    let state = &state;
+                    scope.spawn(move || {
+                        for i in 0..50 {
+                            state
+                                .mutate(|project| project.name = format!("p-{writer}-{i}"))
+                                .unwrap();
+                            state.save().unwrap();
+                        }
+                    })
+                })
+                .collect();
+            for writer in writers {
+                writer.join().unwrap();
+            }
+            done.store(true, Ordering::SeqCst);
+            reader.join().unwrap();
+        });
+
+        let saved = Project::load(&manifest).unwrap();
+        let leftover = root.join(format!("{MANIFEST_FILE}.tmp")).exists();
+
+        assert_eq!(saved.name, state.lock().as_ref().unwrap().name);
+        assert!(!leftover);
+    }
+
+    #[test]
+    fn persisted_changes_leave_other_edits_unsaved() {
+        let root = TempDir::new("persist");
+        let mut project = Project::new(&root, "p");
+        project.pipelines.push(PipelineRef {
+            id: "train".into(),
+            name: "Train".into(),
+            path: "pipelines/train.yaml".into(),
+            tags: Vec::new(),
+        });
+        project.save(&root.join(MANIFEST_FILE)).unwrap();
+        let state = ProjectState::default();
+        state.open(project);
+        state
+            .mutate(|project| project.name = "renamed".into())
+            .unwrap();
+
+        let drop_pipeline = |project: &mut Project| project.pipelines.clear();
+        assert_eq!(state.persist_change(&root, drop_pipeline), Ok(true));
+        let saved = Project::load(&root.join(MANIFEST_FILE)).unwrap();
+        assert!(saved.pipelines.is_empty());
+        assert_eq!(saved.name, "p");
+        assert!(state.lock().as_ref().unwrap().pipelines.is_empty());
+        assert!(state.dirty.load(Ordering::SeqCst));
+        assert_eq!(
+            state.persist_change(&root.join("other"), drop_pipeline),
+            Ok(false)
+        );
+    }
+
+    #[test]
+    fn revert_needs_a_pending_request_and_keeps_read_only() {
+        let root = TempDir::new("revert");
+        let project = Project::new(&root, "p");
+        project.save(&root.join(MANIFEST_FILE)).unwrap();
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(PendingRevert::default());
+        app.manage(UndoStack::default());
+        let app = app.handle();
+        let state = app.state::<ProjectState>();
+        state.open_with(project, true);
+
+        assert!(answer_revert(app, true).is_err());
+        state.dirty.store(true, Ordering::SeqCst);
+        request_revert_project(app).unwrap();
+        assert_eq!(answer_revert(app, true), Ok(true));
+        assert!(state.is_read_only());
+        assert!(!state.dirty.load(Ordering::SeqCst));
+        assert!(answer_revert(app, true).is_err());
+    }
+
+    #[test]
+    fn repair_refuses_an_open_project_under_another_spelling() {
+        let root = TempDir::new("repair-open");
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(OpenProjects::default());
+        let app = app.handle();
+        app.state::<ProjectState>()
+            .open_with(Project::new(&root, "demo"), false);
+
+        assert!(ensure_closed(app, &root.join(".")).is_err());
+        assert!(ensure_closed(app, &root.join("models")).is_ok());
+    }
+
+    #[test]
+    fn try_save_reports_a_save_in_progress() {
+        let state = ProjectState::default();
+        state.open(Project::new(Path::new("/nonexistent"), "p"));
+        state.mutate(|_| {}).unwrap();
+
+        let saving = state.lock_saving();
+        assert_eq!(state.try_save(), Ok(SaveOutcome::SaveInProgress));
+        drop(saving);
+        assert!(state.dirty.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn read_only_projects_refuse_changes_and_saves() {
+        let state = ProjectState::default();
+        state.open_with(Project::new(Path::new("/nonexistent"), "shared"), true);
+
+        assert_eq!(
+            state.mutate(|p| p.name = "mine".into()),
+            Err(READ_ONLY_ERROR.into())
+        );
+        assert_eq!(state.save(), Err(READ_ONLY_ERROR.into()));
+        assert_eq!(state.try_save(), Ok(SaveOutcome::Unchanged));
+        assert_eq!(state.title().as_deref(), Some("shared (Read-Only)"));
+        assert!(!state.dirty.load(Ordering::SeqCst));
+
+        state.open(Project::new(Path::new("/nonexistent"), "mine"));
+        assert!(state.mutate(|_| {}).is_ok());
+        assert_eq!(state.title().as_deref(), Some("mine"));
+    }
+
+    #[test]
+    fn integrity_scan_sorts_missing_modified_and_ok_assets() {
+        let root = TempDir::new("integrity");
+        std::fs::create_dir_all(root.join("models")).unwrap();
+        std::fs::write(root.join("models/a.onnx"), b"weights").unwrap();
+        std::fs::write(root.join("models/b.onnx"), b"weights").unwrap();
+        let mut project = Project::new(&root, "demo");
+        for (id, size) in [("a", 7), ("b", 3)] {
+            project.models.push(ModelRef {
+                id: id.into(),
+                name: id.into(),
+                path: format!("models/{id}.onnx").into(),
+                format: crate::fs::project::ModelFormat::Onnx,
+                size_bytes: size,
+                imported_at: Utc::now(),
+                sha256: None,
+                tags: Vec::new(),
+                description: None,
+                parent_id: None,
+            });
+        }
+        project.datasets.push(DatasetRef {
+            id: "iris".into(),
+            name: "Iris".into(),
+            path: "datasets/iris.csv".into(),
+            tags: Vec::new(),
+            stats: None,
+        });
+        // 파이프라인은 기록된 크기가 없으니 내용이 바뀌어도 ok다
+        std::fs::create_dir_all(root.join("pipelines")).unwrap();
+        std::fs::write(root.join("pipelines/train.yaml"), b"steps: []").unwrap();
+        project.pipelines.push(PipelineRef {
+            id: "train".into(),
+            name: "Train".into(),
+            path: "pipelines/train.yaml".into(),
+            tags: Vec::new(),
+        });
+
+        let report = check_integrity(&project);
+
+        let ids = |entries: &[IntegrityEntry]| -> Vec<String> {
+            entries.iter().map(|e| e.id.clone()).collect()
+        };
+        assert_eq!(ids(&report.ok), ["a", "train"]);
+        assert_eq!(ids(&report.modified), ["b"]);
+        assert_eq!(
+            report.modified[0].reason.as_deref(),
+            Some("size changed from 3 to 7 bytes")
+        );
+        assert_eq!(ids(&report.missing), ["iris"]);
+    }
+
+    #[test]
+    fn check_destination_rejects_folders_inside_the_project() {
+        let base = TempDir::new("save-as");
+        let source = base.join("demo");
+        std::fs::create_dir_all(source.join("models")).unwrap();
+        let nested = check_destination(&source, &source.join("models/demo"));
+        let itself = check_destination(&source, &source);
+        let sibling = check_destination(&source, &base.join("copy/demo"));
+
+        assert!(nested.unwrap_err().contains("inside the project"));
+        assert!(itself.is_err());
+        assert!(sibling.is_ok());
+    }
+
+    #[test]
+    fn project_path_rejects_paths_outside_the_root() {
+        let root = TempDir::new("reveal");
+        std::fs::create_dir_all(root.join("models")).unwrap();
+        let inside = project_path(&root, Path::new("models"));
+        let outside = project_path(&root, Path::new(".."));
+        let missing = project_path(&root, Path::new("models/nope.onnx"));
+
+        assert!(inside.is_ok());
+        assert!(outside.unwrap_err().contains("outside the project"));
+        assert!(missing.unwrap_err().contains("does not exist"));
+    }
+
+    #[test]
+    fn disk_usage_is_broken_down_by_folder() {
+        let root = TempDir::new("usage");
+        std::fs::create_dir_all(root.join("models/nested")).unwrap();
+        std::fs::create_dir_all(root.join("runs/r1")).unwrap();
+        std::fs::write(root.join("models/nested/a.onnx"), [0u8; 100]).unwrap();
+        std::fs::write(root.join("runs/r1/log.txt"), [0u8; 10]).unwrap();
+        std::fs::write(root.join(MANIFEST_FILE), [0u8; 5]).unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&root, root.join("models/loop")).unwrap();
+
+        let usage = disk_usage(&root);
+
+        assert_eq!(
+            usage,
+            DiskUsage {
+                models_bytes: 100,
+                datasets_bytes: 0,
+                runs_bytes: 10,
+                total_bytes: 115,
+            }
+        );
+    }
+
+    #[test]
+    fn tag_filter_supports_any_and_all() {
+        let mut project = Project::new(Path::new("/p"), "p");
+        for (id, tags) in [("a", vec!["vision", "prod"]), ("b", vec!["vision"])] {
+            project.datasets.push(DatasetRef {
+                id: id.into(),
+                name: id.into(),
+                path: format!("datasets/{id}.csv").into(),
+                tags: tags.into_iter().map(String::from).collect(),
+                stats: None,
+            });
+        }
+        let wanted = ["vision".to_string(), "prod".to_string()];
+        let ids = |hits: Vec<TaggedAsset>| hits.into_iter().map(|h| h.id).collect::<Vec<_>>();
+
+        assert_eq!(ids(filter_assets(&project, &wanted, false)), ["a", "b"]);
+        assert_eq!(ids(filter_assets(&project, &wanted, true)), ["a"]);
+        assert_eq!(normalize_tag("  Vision "), Ok("vision".to_string()));
+        assert!(normalize_tag(" ").is_err());
+    }
+
+    #[test]
+    fn copied_assets_are_registered_in_the_target() {
+        let base = TempDir::new("transfer");
+        let (source, target) = (base.join("source"), base.join("target"));
+        std::fs::create_dir_all(source.join("datasets")).unwrap();
+        std::fs::create_dir_all(target.join("datasets")).unwrap();
+        std::fs::write(source.join("datasets/iris.csv"), "a,b\n1,2\n").unwrap();
+        std::fs::write(target.join("datasets/iris.csv"), "taken").unwrap();
+        Project::new(&target, "target")
+            .save(&target.join(MANIFEST_FILE))
+            .unwrap();
+        let dataset = DatasetRef {
+            id: "iris".into(),
+            name: "Iris".into(),
+            path: "datasets/iris.csv".into(),
+            tags: vec!["tabular".into()],
+            stats: None,
+        };
+
+        let copied = copy_asset(&source, &target, Asset::Dataset(dataset.clone()));
+        let again = copy_asset(&source, &target, Asset::Dataset(dataset));
+        let project = load_project(&target).unwrap();
+        let contents = std::fs::read_to_string(target.join(&project.datasets[0].path)).unwrap();
+
+        assert_eq!(copied.unwrap().0, source.join("datasets/iris.csv"));
+        assert!(again.is_err());
+        assert_eq!(project.datasets.len(), 1);
+        assert_eq!(
+            project.datasets[0].path,
+            Path::new("datasets/iris-iris.csv")
+        );
+        assert_eq!(project.datasets[0].tags, ["tabular"]);
+        assert_eq!(contents, "a,b\n1,2\n");
+    }
+
+    #[test]
+    fn repair_keeps_readable_entries_and_rescans_folders() {
+        let root = TempDir::new("repair");
+        for dir in PROJECT_DIRS {
+            std::fs::create_dir_all(root.join(dir)).unwrap();
+        }
+        std::fs::write(root.join("models/a.onnx"), [0x08, 0x07]).unwrap();
+        std::fs::write(root.join("models/b.onnx"), [0x08, 0x07]).unwrap();
+        std::fs::write(root.join("models/notes.txt"), "todo").unwrap();
+        std::fs::write(root.join("datasets/iris.csv"), "a,b\n1,2\n").unwrap();
+        std::fs::write(
+            root.join("pipelines/train.yaml"),
+            "name: Train\nstages: []\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("pipelines/broken.yaml"), "stages: [").unwrap();
+        let manifest = r#"{
+            "name": "Demo",
+            "created_at": "2024-01-01T00:00:00Z",
+            "models": [
+                {"id": "a", "name": "A", "path": "models/a.onnx", "format": "onnx",
+                 "size_bytes": 2, "imported_at": "2024-01-01T00:00:00Z"},
+                {"id": "b", "name": "B", "path": "models/b.onnx", "format": "keras"}
+            ],
+            "datasets": "oops"
+        }"#;
+        std::fs::write(root.join(MANIFEST_FILE), manifest).unwrap();
+
+        let report = repair_manifest(&root).unwrap();
+        let project = load_project(&root).unwrap();
+        let backup = std::fs::read_to_string(report.backup.as_ref().unwrap()).unwrap();
+
+        assert!(report.manifest_error.is_some());
+        assert_eq!(backup, manifest);
+        assert_eq!(report.kept, 1);
+        let recovered: Vec<&str> = report.recovered.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(recovered, ["b", "iris", "Train"]);
+        let dropped: Vec<&str> = report.dropped.iter().map(|d| d.entry.as_str()).collect();
+        assert_eq!(dropped, ["b", "models/notes.txt", "pipelines/broken.yaml"]);
+
+        assert_eq!(project.name, "Demo");
+        assert_eq!(project.models[0].id, "a");
+        assert_eq!(project.models.len(), 2);
+        assert_eq!(project.datasets.len(), 1);
+        assert_eq!(project.pipelines.len(), 1);
+    }
+}
@@ -0,0 +1,55 @@
+use std::collections::HashMap;
+
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::emit;
+use crate::fs::menu::refresh_menu;
+use crate::fs::preferences::Preferences;
+use crate::fs::shortcuts::{
+    app_shortcuts, remap, validate_accelerator as validate, AcceleratorOverrides, ShortcutEntry,
+};
+
+// 메뉴와 같은 목록에서 가져오므로 단축키 창이 실제 메뉴와 어긋나지 않는다
+#[command]
+pub fn list_shortcuts(app: AppHandle) -> Vec<ShortcutEntry> {
+    app_shortcuts(&app)
+}
+
+#[command]
+pub fn set_accelerator(app: AppHandle, menu_id: String, accelerator: String) -> Result<(), String> {
+    let overrides = app.state::<AcceleratorOverrides>().get();
+    let overrides = remap(&overrides, &menu_id, &accelerator)?;
+    apply_accelerators(&app, overrides)
+}
+
+// 적용하지 않고 문법만 본다. 다른 메뉴와 겹치는지는 set_accelerator가 확인한다
+#[command]
+pub fn validate_accelerator(accelerator: String) -> Result<(), String> {
+    validate(&accelerator)
+}
+
+#[command]
+pub fn reset_accelerators(app: AppHandle) -> Result<(), String> {
+    apply_accelerators(&app, HashMap::new())
+}
+
+fn apply_accelerators<R: Runtime>(
+    app: &AppHandle<R>,
+    overrides: HashMap<String, String>,
+) -> Result<(), String> {
+    let mut preferences = Preferences::load(app);
+    preferences.accelerators = overrides.clone();
+    preferences.save(app).map_err(|e| e.to_string())?;
+    sync_accelerators(app, overrides);
+    emit(app, "preferences-changed", preferences);
+    Ok(())
+}
+
+// 환경설정 창에서 통째로 저장한 경우에도 불러서 메뉴를 맞춘다
+pub fn sync_accelerators<R: Runtime>(app: &AppHandle<R>, overrides: HashMap<String, String>) {
+    let state = app.state::<AcceleratorOverrides>();
+    if state.get() != overrides {
+        state.set(overrides);
+        refresh_menu(app);
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::deploy::Deployments;
+use super::emit;
+use super::jobs::JobSlots;
+use super::logs::LogStreams;
+use super::monitoring::SystemMonitor;
+use super::operation::Operations;
+use super::pipeline::PipelineRuns;
+use super::tabs::open_projects;
+use super::train::TrainingRuns;
+use crate::fs::preferences::Preferences;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfirmQuit {
+    pub active_runs: usize,
+    // 저장 안 된 변경이 있는 프로젝트 이름. 뒤에 있는 탭은 자동 저장도 되지 않는다
+    pub unsaved_projects: Vec<String>,
+}
+
+pub fn active_run_count<R: Runtime>(app: &AppHandle<R>) -> usize {
+    app.state::<PipelineRuns>().lock().len() + app.state::<TrainingRuns>().lock().len()
+}
+
+// 실행 중인 작업이나 저장 안 된 탭이 있으면 바로 끄지 않고 프론트엔드에 물어본다.
+// 대답은 confirm_quit으로 온다. 바로 종료했으면 true
+pub fn request_quit<R: Runtime>(app: &AppHandle<R>) -> bool {
+    let active_runs = active_run_count(app);
+    let unsaved_projects: Vec<String> = open_projects(app)
+        .into_iter()
+        .filter(|tab| tab.dirty)
+        .map(|tab| tab.info.name)
+        .collect();
+    if active_runs == 0 && unsaved_projects.is_empty() {
+        app.exit(0);
+        return true;
+    }
+    emit(
+        app,
+        "confirm-quit",
+        ConfirmQuit {
+            active_runs,
+            unsaved_projects,
+        },
+    );
+    false
+}
+
+#[command]
+pub async fn confirm_quit(app: AppHandle, quit: bool) {
+    if quit {
+        shutdown_all_runs(&app).await;
+        app.exit(0);
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ResetSummary {
+    pub pipelines: usize,
+    pub trainings: usize,
+    pub health_monitors: usize,
+    pub log_streams: usize,
+    pub metrics_stream: bool,
+    pub operations: usize,
+    pub queued_jobs: usize,
+}
+
+// 모두 멈추기 버튼. 프로젝트는 열어 둔 채로 돌고 있는 것만 정리한다.
+// 목록을 비우면서 멈추니 연달아 불러도 두 번째는 0으로 끝난다
+#[command]
+pub async fn reset_workspace(app: AppHandle) -> Result<ResetSummary, String> {
+    Ok(reset(&app).await)
+}
+
+pub async fn reset<R: Runtime>(app: &AppHandle<R>) -> ResetSummary {
+    let mut summary = ResetSummary {
+        health_monitors: app.state::<Deployments>().stop_all_monitors(),
+        log_streams: app.state::<LogStreams>().close_all(),
+        metrics_stream: app.state::<SystemMonitor>().stop_stream(),
+        operations: app.state::<Operations>().cancel_all(),
+        queued_jobs: app.state::<JobSlots>().clear_queue(),
+        ..ResetSummary::default()
+    };
+    (summary.pipelines, summary.trainings) = shutdown_all_runs(app).await;
+    emit(app, "workspace-reset", summary.clone());
+    summary
+}
+
+// 추적 중인 모든 파이프라인/학습 프로세스를 SIGTERM → 유예 → kill 순서로 동시에 멈춘다.
+// 멈춘 (파이프라인, 학습) 개수를 돌려준다
+pub async fn shutdown_all_runs<R: Runtime>(app: &AppHandle<R>) -> (usize, usize) {
+    let grace = Duration::from_secs(Preferences::load(app).stop_grace_period_secs);
+    // 멈추는 실행이 자리를 비우면서 큐의 다음 작업을 띄우지 않게 먼저 비운다
+    app.state::<JobSlots>().clear_queue();
+    let mut handles = app.state::<PipelineRuns>().drain_handles();
+    let pipelines = handles.len();
+    handles.extend(app.state::<TrainingRuns>().drain_handles());
+    let trainings = handles.len() - pipelines;
+
+    let stops: Vec<_> = handles
+        .into_iter()
+        .map(|handle| tauri::async_runtime::spawn(handle.stop(grace)))
+        .collect();
+    for stop in stops {
+        let _ = stop.await;
+    }
+    (pipelines, trainings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reset_with_nothing_running_stops_nothing() {
+        let app = tauri::test::mock_app();
+        app.manage(PipelineRuns::default());
+        app.manage(TrainingRuns::default());
+        app.manage(Deployments::default());
+        app.manage(LogStreams::default());
+        app.manage(SystemMonitor::default());
+        app.manage(Operations::default());
+        app.manage(JobSlots::default());
+
+        let first = tauri::async_runtime::block_on(reset(app.handle()));
+        let second = tauri::async_runtime::block_on(reset(app.handle()));
+        assert_eq!(first, ResetSummary::default());
+        assert_eq!(second, ResetSummary::default());
+    }
+}
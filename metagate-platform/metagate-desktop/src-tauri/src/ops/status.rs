@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::deploy::{Deployment, Deployments};
+use super::pipeline::{PipelineProgress, PipelineRuns, RunId};
+use super::train::{TrainingMetrics, TrainingRuns};
+
+// 대시보드의 "지금 돌고 있는 것" 한 번에 보기. 상태 맵만 읽으니 자주 불러도 된다
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ActiveOps {
+    pub pipelines: Vec<ActivePipeline>,
+    pub trainings: Vec<ActiveTraining>,
+    pub deployments: Vec<ActiveDeployment>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivePipeline {
+    pub run_id: RunId,
+    pub pipeline_id: String,
+    pub started_at: DateTime<Utc>,
+    pub progress: Option<PipelineProgress>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveTraining {
+    pub run_id: RunId,
+    pub model_id: String,
+    pub dataset_id: String,
+    pub epochs: u32,
+    pub started_at: DateTime<Utc>,
+    pub paused: bool,
+    pub latest: Option<TrainingMetrics>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActiveDeployment {
+    #[serde(flatten)]
+    pub deployment: Deployment,
+    // 헬스 체크를 주기적으로 돌리고 있는지
+    pub monitored: bool,
+}
+
+#[command]
+pub fn active_operations(app: AppHandle) -> ActiveOps {
+    active(&app)
+}
+
+// 먼저 시작한 것부터 보여준다
+pub fn active<R: Runtime>(app: &AppHandle<R>) -> ActiveOps {
+    let mut pipelines: Vec<ActivePipeline> = app
+        .state::<PipelineRuns>()
+        .lock()
+        .iter()
+        .map(|(run_id, run)| ActivePipeline {
+            run_id: run_id.clone(),
+            pipeline_id: run.pipeline_id.clone(),
+            started_at: run.started_at,
+            progress: run.progress.clone(),
+        })
+        .collect();
+    pipelines.sort_by_key(|p| p.started_at);
+
+    let mut trainings: Vec<ActiveTraining> = app
+        .state::<TrainingRuns>()
+        .lock()
+        .iter()
+        .map(|(run_id, run)| ActiveTraining {
+            run_id: run_id.clone(),
+            model_id: run.config.model_id.clone(),
+            dataset_id: run.config.dataset_id.clone(),
+            epochs: run.config.epochs,
+            started_at: run.started_at,
+            paused: run.is_paused(),
+            latest: run.latest.clone(),
+        })
+        .collect();
+    trainings.sort_by_key(|t| t.started_at);
+
+    let deployments = app.state::<Deployments>();
+    let monitored = deployments.monitored();
+    let mut deployments: Vec<ActiveDeployment> = deployments
+        .list()
+        .into_iter()
+        .map(|deployment| ActiveDeployment {
+            monitored: monitored.contains(&deployment.id),
+            deployment,
+        })
+        .collect();
+    deployments.sort_by_key(|d| d.deployment.deployed_at);
+
+    ActiveOps {
+        pipelines,
+        trainings,
+        deployments,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn idle_app_reports_empty_lists() {
+        let app = tauri::test::mock_app();
+        app.manage(PipelineRuns::default());
+        app.manage(TrainingRuns::default());
+        app.manage(Deployments::default());
+
+        let ops = active(app.handle());
+        assert!(ops.pipelines.is_empty());
+        assert!(ops.trainings.is_empty());
+        assert!(ops.deployments.is_empty());
+    }
+}
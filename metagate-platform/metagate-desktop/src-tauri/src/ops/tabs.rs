@@ -0,0 +1,333 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+use std::sync::{Mutex, MutexGuard};
+
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime};
+
+use super::delete::PendingDelete;
+use super::emit;
+use super::error::{guard, CommandError};
+use super::history::{self, History, UndoStack};
+use super::project::{
+    load_project, project_info, remember_recent, scan_in_background, sync_read_only_menu,
+    ParkedProject, ProjectInfo, ProjectState, READ_ONLY_ERROR,
+};
+use super::watch::ProjectWatcher;
+use super::window::update_window_title;
+use crate::fs::project::Project;
+
+pub type ProjectId = String;
+
+// 활성 프로젝트는 지금처럼 ProjectState에 있고, 나머지 탭은 실행 취소 기록과 함께 여기 맡겨 둔다.
+// 프로젝트 커맨드는 모두 활성 탭에만 작동한다
+#[derive(Default)]
+pub struct OpenProjects(Mutex<Tabs>);
+
+#[derive(Default)]
+struct Tabs {
+    // 탭 순서. 활성 탭도 들어 있다
+    order: Vec<ProjectId>,
+    active: Option<ProjectId>,
+    parked: HashMap<ProjectId, Tab>,
+}
+
+struct Tab {
+    project: ParkedProject,
+    history: History,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpenProject {
+    pub id: ProjectId,
+    #[serde(flatten)]
+    pub info: ProjectInfo,
+    pub active: bool,
+    pub dirty: bool,
+    pub read_only: bool,
+}
+
+impl OpenProjects {
+    fn lock(&self) -> MutexGuard<'_, Tabs> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 탭을 거치지 않고 프로젝트를 열면 활성 탭 자리에 새 id로 들어간다
+    pub fn replace_active(&self) -> ProjectId {
+        let mut tabs = self.lock();
+        let id: ProjectId = uuid::Uuid::new_v4().to_string();
+        match tabs.active.replace(id.clone()) {
+            Some(old) => {
+                if let Some(slot) = tabs.order.iter_mut().find(|slot| **slot == old) {
+                    *slot = id.clone();
+                }
+            }
+            None => tabs.order.push(id.clone()),
+        }
+        id
+    }
+
+    // 뒤에 있는 탭의 프로젝트 폴더에 밖에서 항목을 넣었으면 메모리의 것에도 같이 넣어야
+    // 나중에 그 탭을 저장할 때 디스크의 변경을 덮어쓰지 않는다. 탭에 없으면 None
+    pub fn with_parked<T>(&self, dir: &Path, f: impl FnOnce(&mut Project) -> T) -> Option<T> {
+        let dir = canonical(dir);
+        let mut tabs = self.lock();
+        let tab = tabs
+            .parked
+            .values_mut()
+            .find(|tab| canonical(&tab.project.project.root) == dir)?;
+        Some(f(&mut tab.project.project))
+    }
+
+    // 읽기 전용으로 연 뒤쪽 탭의 폴더에는 밖에서도 항목을 넣거나 빼지 않는다
+    pub fn ensure_parked_writable(&self, dir: &Path) -> Result<(), String> {
+        let dir = canonical(dir);
+        let read_only = self
+            .lock()
+            .parked
+            .values()
+            .any(|tab| tab.project.read_only && canonical(&tab.project.project.root) == dir);
+        if read_only {
+            return Err(READ_ONLY_ERROR.into());
+        }
+        Ok(())
+    }
+
+    // 같은 폴더를 두 탭에서 고치면 서로 덮어쓰므로 이미 열린 탭을 찾는다
+    pub fn parked_at(&self, dir: &Path) -> Option<ProjectId> {
+        let dir = canonical(dir);
+        self.lock()
+            .parked
+            .iter()
+            .find(|(_, tab)| canonical(&tab.project.project.root) == dir)
+            .map(|(id, _)| id.clone())
+    }
+}
+
+pub fn canonical(path: &Path) -> PathBuf {
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+#[command]
+pub fn list_open_projects(app: AppHandle) -> Vec<OpenProject> {
+    open_projects(&app)
+}
+
+pub fn open_projects<R: Runtime>(app: &AppHandle<R>) -> Vec<OpenProject> {
+    let state = app.state::<ProjectState>();
+    let active = state.lock().as_ref().map(project_info);
+    let tabs = app.state::<OpenProjects>();
+    let tabs = tabs.lock();
+    tabs.order
+        .iter()
+        .filter_map(|id| {
+            if tabs.active.as_ref() == Some(id) {
+                return Some(OpenProject {
+                    id: id.clone(),
+                    info: active.clone()?,
+                    active: true,
+                    dirty: state.dirty.load(Ordering::SeqCst),
+                    read_only: state.is_read_only(),
+                });
+            }
+            let tab = tabs.parked.get(id)?;
+            Some(OpenProject {
+                id: id.clone(),
+                info: project_info(&tab.project.project),
+                active: false,
+                dirty: tab.project.dirty,
+                read_only: tab.project.read_only,
+            })
+        })
+        .collect()
+}
+
+#[command]
+pub fn switch_project(app: AppHandle, id: String) -> Result<OpenProject, String> {
+    switch_to(&app, &id)
+}
+
+pub fn switch_to<R: Runtime>(app: &AppHandle<R>, id: &str) -> Result<OpenProject, String> {
+    let tabs = app.state::<OpenProjects>();
+    if tabs.lock().active.as_deref() == Some(id) {
+        return active_tab(app);
+    }
+    let tab = tabs
+        .lock()
+        .parked
+        .remove(id)
+        .ok_or_else(|| format!("project tab {id} is not open"))?;
+    park_active(app);
+    activate(app, id.into(), tab);
+    active_tab(app)
+}
+
+// 탭을 하나 더 연다. 이미 열려 있는 폴더면 그 탭으로 넘어간다
+#[command]
+pub fn open_additional_project(app: AppHandle, path: String) -> Result<OpenProject, CommandError> {
+    let dir = Path::new(&path);
+    if let Some(id) = app.state::<OpenProjects>().parked_at(dir) {
+        return Ok(switch_to(&app, &id)?);
+    }
+    let active_root = app
+        .state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .map(|p| canonical(&p.root));
+    if active_root == Some(canonical(dir)) {
+        return Ok(active_tab(&app)?);
+    }
+
+    let project = guard(|| Ok(load_project(dir)?))?;
+    park_active(&app);
+    let id: ProjectId = uuid::Uuid::new_v4().to_string();
+    app.state::<OpenProjects>().lock().order.push(id.clone());
+    let tab = Tab {
+        project: ParkedProject {
+            project,
+            dirty: false,
+            read_only: false,
+        },
+        history: History::default(),
+    };
+    activate(&app, id, tab);
+    remember_recent(&app, dir);
+    scan_in_background(&app);
+    Ok(active_tab(&app)?)
+}
+
+// 활성 탭을 닫은 뒤에 부른다. 남은 탭이 있으면 옆 탭을 연다
+pub fn close_active<R: Runtime>(app: &AppHandle<R>) {
+    let next = {
+        let tabs = app.state::<OpenProjects>();
+        let mut tabs = tabs.lock();
+        let Some(closed) = tabs.active.take() else {
+            return;
+        };
+        let position = tabs.order.iter().position(|id| *id == closed);
+        tabs.order.retain(|id| *id != closed);
+        let next = position
+            .map(|p| p.min(tabs.order.len().saturating_sub(1)))
+            .and_then(|p| tabs.order.get(p).cloned());
+        next.and_then(|id| tabs.parked.remove(&id).map(|tab| (id, tab)))
+    };
+    if let Some((id, tab)) = next {
+        activate(app, id, tab);
+    }
+}
+
+fn park_active<R: Runtime>(app: &AppHandle<R>) {
+    app.state::<ProjectWatcher>().stop();
+    app.state::<PendingDelete>().clear();
+    let Some(project) = app.state::<ProjectState>().park() else {
+        return;
+    };
+    let history = app.state::<UndoStack>().take();
+    let tabs = app.state::<OpenProjects>();
+    let mut tabs = tabs.lock();
+    let id = match tabs.active.take() {
+        Some(id) => id,
+        None => {
+            let id: ProjectId = uuid::Uuid::new_v4().to_string();
+            tabs.order.push(id.clone());
+            id
+        }
+    };
+    tabs.parked.insert(id, Tab { project, history });
+}
+
+fn activate<R: Runtime>(app: &AppHandle<R>, id: ProjectId, tab: Tab) {
+    app.state::<OpenProjects>().lock().active = Some(id);
+    app.state::<PendingDelete>().clear();
+    app.state::<ProjectState>().resume(tab.project);
+    app.state::<UndoStack>().restore(tab.history);
+    history::sync_menu(app);
+    sync_read_only_menu(app);
+    update_window_title(app);
+    match active_tab(app) {
+        Ok(tab) => emit(app, "project-switched", tab),
+        Err(err) => log::warn!("switched to a project tab that is gone: {err}"),
+    }
+}
+
+fn active_tab<R: Runtime>(app: &AppHandle<R>) -> Result<OpenProject, String> {
+    open_projects(app)
+        .into_iter()
+        .find(|tab| tab.active)
+        .ok_or_else(|| "no project is open".into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn switching_tabs_keeps_unsaved_changes() {
+        let app = tauri::test::mock_app();
+        app.manage(ProjectState::default());
+        app.manage(OpenProjects::default());
+        app.manage(UndoStack::default());
+        app.manage(ProjectWatcher::default());
+        app.manage(PendingDelete::default());
+        let app = app.handle();
+
+        let state = app.state::<ProjectState>();
+        state.open(Project::new(Path::new("/tmp/first"), "first"));
+        let first = app.state::<OpenProjects>().replace_active();
+        state.mutate(|p| p.name = "first (edited)".into()).unwrap();
+
+        park_active(app);
+        let second: ProjectId = "second".into();
+        app.state::<OpenProjects>()
+            .lock()
+            .order
+            .push(second.clone());
+        let tab = Tab {
+            project: ParkedProject {
+                project: Project::new(Path::new("/tmp/second"), "second"),
+                dirty: false,
+                read_only: false,
+            },
+            history: History::default(),
+        };
+        activate(app, second, tab);
+        let tabs = app.state::<OpenProjects>();
+        let parked = tabs.with_parked(Path::new("/tmp/first"), |p| p.name.clone());
+        assert_eq!(parked.as_deref(), Some("first (edited)"));
+        assert!(tabs.with_parked(Path::new("/tmp/second"), |_| ()).is_none());
+        let back = switch_to(app, &first).unwrap();
+
+        assert_eq!(back.info.name, "first (edited)");
+        assert!(back.dirty);
+        let tabs = open_projects(app);
+        assert_eq!(tabs.len(), 2);
+        assert!(!tabs[1].active && !tabs[1].dirty);
+
+        state.close();
+        close_active(app);
+        assert_eq!(state.lock().as_ref().unwrap().name, "second");
+        assert_eq!(open_projects(app).len(), 1);
+    }
+
+    #[test]
+    fn read_only_parked_tabs_are_found_by_any_path_spelling() {
+        let dir = crate::testing::TempDir::new("parked");
+        let tabs = OpenProjects::default();
+        tabs.lock().parked.insert(
+            "ro".into(),
+            Tab {
+                project: ParkedProject {
+                    project: Project::new(&dir, "ro"),
+                    dirty: false,
+                    read_only: true,
+                },
+                history: History::default(),
+            },
+        );
+
+        assert!(tabs.ensure_parked_writable(&dir.join(".")).is_err());
+        assert!(tabs.ensure_parked_writable(&dir.join("other")).is_ok());
+        assert_eq!(tabs.parked_at(&dir.join(".")).as_deref(), Some("ro"));
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{command, State};
+
+// 백분위는 커맨드마다 최근 이만큼의 호출로만 계산한다
+const SAMPLE_WINDOW: usize = 256;
+
+#[derive(Default)]
+struct Samples {
+    count: u64,
+    recent: VecDeque<Duration>,
+}
+
+// build.rs가 모은 async 커맨드 이름
+include!(concat!(env!("OUT_DIR"), "/async_commands.rs"));
+
+// invoke_handler가 동기 커맨드 호출을 여기에 기록한다. async 커맨드는 런타임에 넘겨지는
+// 순간 핸들러가 돌아와서 늘 0ms 가까이 잡히므로 기록하지 않는다
+pub struct CommandTimings {
+    samples: Mutex<HashMap<String, Samples>>,
+    slow_ms: AtomicU64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CommandTiming {
+    pub name: String,
+    pub count: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+}
+
+impl CommandTimings {
+    pub fn new(slow_ms: u64) -> Self {
+        CommandTimings {
+            samples: Mutex::new(HashMap::new()),
+            slow_ms: AtomicU64::new(slow_ms),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, Samples>> {
+        self.samples.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn set_slow_threshold(&self, slow_ms: u64) {
+        self.slow_ms.store(slow_ms, Ordering::Relaxed);
+    }
+
+    pub fn is_timed(name: &str) -> bool {
+        !ASYNC_COMMANDS.contains(&name)
+    }
+
+    pub fn record(&self, name: &str, elapsed: Duration) {
+        let slow_ms = self.slow_ms.load(Ordering::Relaxed);
+        if slow_ms > 0 && elapsed >= Duration::from_millis(slow_ms) {
+            log::warn!("command {name} took {} ms", elapsed.as_millis());
+        }
+        let mut samples = self.lock();
+        // 이름을 매번 복사하지 않게 처음 볼 때만 넣는다
+        let entry = match samples.get_mut(name) {
+            Some(entry) => entry,
+            None => samples.entry(name.to_string()).or_default(),
+        };
+        entry.count += 1;
+        if entry.recent.len() == SAMPLE_WINDOW {
+            entry.recent.pop_front();
+        }
+        entry.recent.push_back(elapsed);
+    }
+
+    pub fn summary(&self) -> Vec<CommandTiming> {
+        let mut timings: Vec<CommandTiming> = self
+            .lock()
+            .iter()
+            .map(|(name, samples)| {
+                let mut sorted: Vec<Duration> = samples.recent.iter().copied().collect();
+                sorted.sort();
+                CommandTiming {
+                    name: name.clone(),
+                    count: samples.count,
+                    p50_ms: percentile_ms(&sorted, 50),
+                    p95_ms: percentile_ms(&sorted, 95),
+                }
+            })
+            .collect();
+        timings.sort_by(|a, b| a.name.cmp(&b.name));
+        timings
+    }
+}
+
+#[command]
+pub fn command_timings(timings: State<'_, CommandTimings>) -> Vec<CommandTiming> {
+    timings.summary()
+}
+
+// nearest-rank 방식. sorted는 오름차순이어야 한다
+fn percentile_ms(sorted: &[Duration], percent: usize) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (percent * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1].as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_reports_percentiles_over_recent_calls() {
+        let timings = CommandTimings::new(0);
+        for ms in 1..=100 {
+            timings.record("list_runs", Duration::from_millis(ms));
+        }
+        timings.record("app_info", Duration::from_millis(2));
+
+        let summary = timings.summary();
+        assert_eq!(summary[0].name, "app_info");
+        assert!(CommandTimings::is_timed("list_shortcuts"));
+        assert!(!CommandTimings::is_timed("start_training"));
+        assert_eq!(summary[1].count, 100);
+        assert_eq!(summary[1].p50_ms, 50.0);
+        assert_eq!(summary[1].p95_ms, 95.0);
+    }
+
+    #[test]
+    fn only_the_last_window_of_samples_is_kept() {
+        let timings = CommandTimings::new(0);
+        for _ in 0..SAMPLE_WINDOW {
+            timings.record("slow", Duration::from_millis(900));
+        }
+        for _ in 0..SAMPLE_WINDOW {
+            timings.record("slow", Duration::from_millis(1));
+        }
+        let summary = timings.summary();
+        assert_eq!(summary[0].count, 2 * SAMPLE_WINDOW as u64);
+        assert_eq!(summary[0].p95_ms, 1.0);
+    }
+}
@@ -0,0 +1,715 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tauri::{command, AppHandle, Manager, Runtime};
+#[cfg(not(unix))]
+use tokio::io::AsyncWriteExt;
+use tokio::io::{AsyncBufReadExt, BufReader};
+#[cfg(not(unix))]
+use tokio::process::ChildStdin;
+use tokio::process::{Child, ChildStdout, Command};
+
+use super::dataset::{dataset_columns, dataset_path, ColumnType};
+use super::emit;
+use super::env::{process_env, SecretEnv};
+use super::jobs::{JobKind, JobSlots};
+use super::model::{model_inputs, model_source};
+use super::monitoring::SystemMonitor;
+use super::notify::notify;
+use super::pipeline::{RunId, LOG_FILE};
+#[cfg(unix)]
+use super::process::suspend;
+use super::process::{run_handle, RunHandle, RunSignals};
+use super::project::ProjectState;
+use crate::fs::menu_events::NavigationTarget;
+use crate::fs::onnx::TensorShape;
+use crate::fs::preferences::Preferences;
+use crate::fs::project::run_dir;
+use crate::fs::run::{
+    append_metric, finish_run, set_run_status, MetricPoint, RunKind, RunRecord, RunStatus,
+};
+
+const TRAINER_ENV: &str = "METAGATE_TRAINER";
+const DEFAULT_TRAINER: &str = "metagate-train";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingConfig {
+    pub model_id: String,
+    pub dataset_id: String,
+    pub epochs: u32,
+    pub batch_size: u32,
+    pub learning_rate: f64,
+    // None이면 트레이너가 장치를 고른다
+    #[serde(default)]
+    pub gpu_index: Option<u32>,
+}
+
+impl TrainingConfig {
+    fn validate(&self) -> Result<(), String> {
+        if self.epochs == 0 {
+            return Err("epochs must be at least 1".into());
+        }
+        if self.batch_size == 0 {
+            return Err("batch size must be at least 1".into());
+        }
+        if !(self.learning_rate.is_finite() && self.learning_rate > 0.0) {
+            return Err(format!("invalid learning rate {}", self.learning_rate));
+        }
+        Ok(())
+    }
+
+    // available은 읽어 낸 장치의 NVML 번호. 중간 장치를 못 읽었으면 번호가 건너뛴다
+    fn validate_gpu(&self, available: &[u32]) -> Result<(), String> {
+        match self.gpu_index {
+            Some(_) if available.is_empty() => Err("no GPUs are available on this machine".into()),
+            Some(index) if !available.contains(&index) => Err(format!(
+                "GPU {index} does not exist; available GPUs: {}",
+                available
+                    .iter()
+                    .map(u32::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+// 이 이름의 컬럼은 정답으로 보고 입력 특성 수에서 뺀다
+const LABEL_COLUMNS: [&str; 4] = ["label", "target", "class", "y"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Compatibility {
+    Compatible,
+    Incompatible,
+    // 모델 포맷이나 입력 모양 때문에 비교할 수 없는 경우. 맞지 않는다고 단정하지 않는다
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CompatibilityReport {
+    pub compatibility: Compatibility,
+    pub model_features: Option<u64>,
+    pub dataset_features: usize,
+    pub warnings: Vec<String>,
+}
+
+#[command]
+pub async fn check_compatibility(
+    app: AppHandle,
+    model_id: String,
+    dataset_id: String,
+) -> Result<CompatibilityReport, String> {
+    let (model, _) = model_source(&app.state::<ProjectState>(), &model_id)?;
+    let dataset = dataset_path(&app, &dataset_id)?;
+    tauri::async_runtime::spawn_blocking(move || {
+        let inputs = model_inputs(&model)?;
+        let columns = dataset_columns(&dataset)?;
+        Ok(compatibility(inputs.as_deref(), &columns))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+// 첫 번째 입력이 [batch, 특성 수] 모양일 때만 비교한다
+pub fn compatibility(
+    inputs: Option<&[TensorShape]>,
+    columns: &[(String, Option<ColumnType>)],
+) -> CompatibilityReport {
+    let mut warnings = Vec::new();
+    let features: Vec<_> = columns
+        .iter()
+        .filter(|(name, _)| !LABEL_COLUMNS.contains(&name.to_ascii_lowercase().as_str()))
+        .collect();
+    if features.len() == columns.len() {
+        warnings.push("no label column found; counting every column as a feature".to_string());
+    }
+    for (name, kind) in &features {
+        if matches!(kind, Some(ColumnType::String | ColumnType::Date)) {
+            warnings.push(format!("column \"{name}\" is not numeric"));
+        }
+    }
+    let mut report = CompatibilityReport {
+        compatibility: Compatibility::Unknown,
+        model_features: None,
+        dataset_features: features.len(),
+        warnings,
+    };
+
+    let Some(inputs) = inputs else {
+        report
+            .warnings
+            .push("the input shape of this model format cannot be read".into());
+        return report;
+    };
+    let Some(input) = inputs.first() else {
+        report.warnings.push("the model declares no inputs".into());
+        return report;
+    };
+    if inputs.len() > 1 {
+        report.warnings.push(format!(
+            "the model has {} inputs; only \"{}\" was checked",
+            inputs.len(),
+            input.name
+        ));
+    }
+    let expected = match input.dims.as_slice() {
+        [_, features] => features.parse::<u64>().ok(),
+        _ => None,
+    };
+    let Some(expected) = expected else {
+        report.warnings.push(format!(
+            "input \"{}\" has shape [{}], not a fixed-size feature vector",
+            input.name,
+            input.dims.join(", ")
+        ));
+        return report;
+    };
+
+    report.model_features = Some(expected);
+    report.compatibility = if expected == features.len() as u64 {
+        Compatibility::Compatible
+    } else {
+        report.warnings.push(format!(
+            "the model expects {expected} features but the dataset has {}",
+            features.len()
+        ));
+        Compatibility::Incompatible
+    };
+    report
+}
+
+pub struct TrainingRun {
+    pub config: TrainingConfig,
+    handle: RunHandle,
+    dir: PathBuf,
+    paused: bool,
+    pub started_at: DateTime<Utc>,
+    // 트레이너가 마지막으로 보낸 에폭 메트릭
+    pub latest: Option<TrainingMetrics>,
+    // 시그널로 멈출 수 없는 곳에서는 트레이너의 stdin으로 알려 준다
+    #[cfg(not(unix))]
+    control: Option<ChildStdin>,
+}
+
+impl TrainingRun {
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    // 멈춘 프로세스는 SIGTERM을 처리하지 못하니 종료시키기 전에 깨운다
+    fn wake(&self) {
+        #[cfg(unix)]
+        if let (true, Some(pid)) = (self.paused, self.handle.pid) {
+            suspend(pid, false);
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TrainingRuns {
+    runs: Mutex<HashMap<RunId, TrainingRun>>,
+}
+
+impl TrainingRuns {
+    pub fn lock(&self) -> MutexGuard<'_, HashMap<RunId, TrainingRun>> {
+        self.runs.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // 패닉 훅에서 쓴다. 락을 잡은 채로 패닉했을 수 있으므로 기다리지 않는다
+    pub fn try_ids(&self) -> Vec<RunId> {
+        self.runs
+            .try_lock()
+            .map(|runs| runs.keys().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn drain_handles(&self) -> Vec<RunHandle> {
+        self.lock()
+            .drain()
+            .map(|(_, run)| {
+                run.wake();
+                run.handle
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainingMetrics {
+    #[serde(default)]
+    pub run_id: RunId,
+    pub epoch: u32,
+    pub loss: f64,
+    pub accuracy: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingFinished {
+    pub run_id: RunId,
+    pub success: bool,
+    pub code: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TrainingCancelled {
+    pub run_id: RunId,
+    pub forced: bool,
+}
+
+#[command]
+pub async fn start_training(
+    app: AppHandle,
+    config: TrainingConfig,
+    env: Option<HashMap<String, String>>,
+) -> Result<RunId, String> {
+    launch_training(&app, config, &env.unwrap_or_default())
+}
+
+// 모델/데이터셋을 모두 확인한 다음에야 트레이너를 띄운다.
+// env는 config와 달리 실행 기록에 남기지 않는다
+pub fn launch_training<R: Runtime>(
+    app: &AppHandle<R>,
+    config: TrainingConfig,
+    env: &HashMap<String, String>,
+) -> Result<RunId, String> {
+    config.validate()?;
+    let env = process_env(&BTreeMap::new(), &app.state::<SecretEnv>().snapshot(), env)?;
+    if config.gpu_index.is_some() {
+        let gpus: Vec<u32> = app
+            .state::<SystemMonitor>()
+            .gpus()
+            .iter()
+            .map(|gpu| gpu.index)
+            .collect();
+        config.validate_gpu(&gpus)?;
+    }
+    let (root, model, dataset) = {
+        let state = app.state::<ProjectState>();
+        let guard = state.lock();
+        let project = guard.as_ref().ok_or("no project is open")?;
+        let model = project
+            .models
+            .iter()
+            .find(|m| m.id == config.model_id)
+            .ok_or_else(|| format!("model {} not found", config.model_id))?;
+        let dataset = project
+            .datasets
+            .iter()
+            .find(|d| d.id == config.dataset_id)
+            .ok_or_else(|| format!("dataset {} not found", config.dataset_id))?;
+        (
+            project.root.clone(),
+            project.root.join(&model.path),
+            project.root.join(&dataset.path),
+        )
+    };
+    if !dataset.exists() {
+        return Err(format!(
+            "dataset {} is missing on disk: {}",
+            config.dataset_id,
+            dataset.display()
+        ));
+    }
+
+    let run_id: RunId = uuid::Uuid::new_v4().to_string();
+    let target = config.model_id.clone();
+    let start = {
+        let (app, run_id) = (app.clone(), run_id.clone());
+        move || spawn_training(&app, &run_id, &root, &model, &dataset, config, env)
+    };
+    // 같은 모델을 이미 학습 중이거나 기다리는 중이면 submit이 거절한다
+    app.state::<JobSlots>()
+        .submit(app, run_id.clone(), JobKind::Training, target, start)?;
+    Ok(run_id)
+}
+
+fn spawn_training<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    root: &Path,
+    model: &Path,
+    dataset: &Path,
+    config: TrainingConfig,
+    env: BTreeMap<String, String>,
+) -> Result<(), String> {
+    // 큐에서 기다리는 사이에 다른 프로젝트를 열었을 수 있다
+    let open_root = app
+        .state::<ProjectState>()
+        .lock()
+        .as_ref()
+        .map(|p| p.root.clone());
+    if open_root.as_deref() != Some(root) {
+        return Err(format!(
+            "the project was closed before training {} could start",
+            config.model_id
+        ));
+    }
+    let io_err = |e: io::Error| format!("failed to start training: {e}");
+    let dir = run_dir(root, run_id);
+    fs::create_dir_all(&dir).map_err(io_err)?;
+    let log = File::create(dir.join(LOG_FILE)).map_err(io_err)?;
+    RunRecord::start(run_id, RunKind::Training, &config.model_id)
+        .with_config(&config)
+        .save(&dir)
+        .map_err(io_err)?;
+
+    let trainer = std::env::var(TRAINER_ENV).unwrap_or_else(|_| DEFAULT_TRAINER.into());
+    let mut command = Command::new(&trainer);
+    command.envs(&env);
+    if let Some(index) = config.gpu_index {
+        command.arg("--gpu").arg(index.to_string());
+    }
+    // pause/resume 메시지를 받을 stdin
+    #[cfg(not(unix))]
+    command.stdin(Stdio::piped());
+    let child = command
+        .arg("--model")
+        .arg(model)
+        .arg("--dataset")
+        .arg(dataset)
+        .arg("--epochs")
+        .arg(config.epochs.to_string())
+        .arg("--batch-size")
+        .arg(config.batch_size.to_string())
+        .arg("--learning-rate")
+        .arg(config.learning_rate.to_string())
+        .arg("--output")
+        .arg(&dir)
+        .current_dir(root)
+        .stdout(Stdio::piped())
+        .stderr(log.try_clone().map_err(io_err)?)
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(|e| format!("failed to run trainer {trainer}: {e}"))?;
+
+    let (handle, signals) = run_handle(&child);
+    #[cfg(not(unix))]
+    let mut child = child;
+    let run = TrainingRun {
+        config,
+        handle,
+        dir: dir.clone(),
+        paused: false,
+        started_at: Utc::now(),
+        latest: None,
+        #[cfg(not(unix))]
+        control: child.stdin.take(),
+    };
+    app.state::<TrainingRuns>()
+        .lock()
+        .insert(run_id.into(), run);
+
+    tauri::async_runtime::spawn(watch_training(
+        app.clone(),
+        run_id.into(),
+        dir,
+        child,
+        log,
+        signals,
+    ));
+    Ok(())
+}
+
+#[command]
+pub async fn cancel_training(app: AppHandle, run_id: String) -> Result<(), String> {
+    let grace = Duration::from_secs(Preferences::load(&app).stop_grace_period_secs);
+    cancel_run(&app, &run_id, grace).await
+}
+
+pub async fn cancel_run<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    grace: Duration,
+) -> Result<(), String> {
+    if app.state::<JobSlots>().dequeue(run_id) {
+        emit(
+            app,
+            "training-cancelled",
+            TrainingCancelled {
+                run_id: run_id.into(),
+                forced: false,
+            },
+        );
+        return Ok(());
+    }
+    let run = app
+        .state::<TrainingRuns>()
+        .lock()
+        .remove(run_id)
+        .ok_or("no such training run")?;
+
+    run.wake();
+    let forced = run.handle.stop(grace).await;
+    emit(
+        app,
+        "training-cancelled",
+        TrainingCancelled {
+            run_id: run_id.into(),
+            forced,
+        },
+    );
+    Ok(())
+}
+
+#[command]
+pub async fn pause_training(app: AppHandle, run_id: String) -> Result<(), String> {
+    set_paused(&app, &run_id, true).await
+}
+
+#[command]
+pub async fn resume_training(app: AppHandle, run_id: String) -> Result<(), String> {
+    set_paused(&app, &run_id, false).await
+}
+
+// GPU를 잠깐 다른 작업에 내주는 용도. 이미 그 상태면 아무것도 하지 않는다.
+// 끝난 실행은 목록에 없으므로 재개하려 하면 에러가 된다
+pub async fn set_paused<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    paused: bool,
+) -> Result<(), String> {
+    let not_running = || format!("training run {run_id} is not running");
+    let pid = {
+        let runs = app.state::<TrainingRuns>();
+        let runs = runs.lock();
+        let run = runs.get(run_id).ok_or_else(not_running)?;
+        if run.paused == paused {
+            return Ok(());
+        }
+        run.handle.pid.ok_or_else(not_running)?
+    };
+    send_pause(app, run_id, pid, paused).await?;
+
+    // 보내는 동안 끝났으면 기록은 watch_training이 정리한다
+    let dir = {
+        let runs = app.state::<TrainingRuns>();
+        let mut runs = runs.lock();
+        let run = runs.get_mut(run_id).ok_or_else(not_running)?;
+        run.paused = paused;
+        run.dir.clone()
+    };
+    let (status, event) = if paused {
+        (RunStatus::Paused, "training-paused")
+    } else {
+        (RunStatus::Running, "training-resumed")
+    };
+    set_run_status(&dir, status);
+    emit(app, event, run_id.to_string());
+    Ok(())
+}
+
+#[cfg(unix)]
+async fn send_pause<R: Runtime>(
+    _app: &AppHandle<R>,
+    run_id: &str,
+    pid: u32,
+    paused: bool,
+) -> Result<(), String> {
+    if !suspend(pid, paused) {
+        return Err(format!("failed to signal training run {run_id}"));
+    }
+    Ok(())
+}
+
+// {"command": "pause"} 또는 {"command": "resume"} 한 줄을 보낸다
+#[cfg(not(unix))]
+async fn send_pause<R: Runtime>(
+    app: &AppHandle<R>,
+    run_id: &str,
+    _pid: u32,
+    paused: bool,
+) -> Result<(), String> {
+    let taken = app
+        .state::<TrainingRuns>()
+        .lock()
+        .get_mut(run_id)
+        .and_then(|run| run.control.take());
+    let mut stdin = taken.ok_or_else(|| format!("training run {run_id} cannot be paused"))?;
+    let command = if paused { "pause" } else { "resume" };
+    let line = format!("{}\n", serde_json::json!({ "command": command }));
+    let sent = async {
+        stdin.write_all(line.as_bytes()).await?;
+        stdin.flush().await
+    }
+    .await;
+    if let Some(run) = app.state::<TrainingRuns>().lock().get_mut(run_id) {
+        run.control = Some(stdin);
+    }
+    sent.map_err(|e| format!("failed to message training run {run_id}: {e}"))
+}
+
+async fn watch_training<R: Runtime>(
+    app: AppHandle<R>,
+    run_id: RunId,
+    dir: PathBuf,
+    mut child: Child,
+    log: File,
+    mut signals: RunSignals,
+) {
+    let output = child.stdout.take().map(|stdout| {
+        tauri::async_runtime::spawn(forward_metrics(
+            app.clone(),
+            run_id.clone(),
+            dir.clone(),
+            stdout,
+            log,
+        ))
+    });
+
+    let status = signals.wait(&mut child).await;
+    if let Some(output) = output {
+        let _ = output.await;
+    }
+    signals.mark_exited();
+    app.state::<JobSlots>().release(&app, &run_id);
+
+    // 취소된 경우에는 training-cancelled를 이미 보냈다
+    if app.state::<TrainingRuns>().lock().remove(&run_id).is_some() {
+        let finished = finished(run_id, status);
+        let outcome = if finished.success {
+            RunStatus::Succeeded
+        } else {
+            RunStatus::Failed
+        };
+        finish_run(&dir, outcome, finished.code);
+        let body = if finished.success {
+            format!("Training run {} finished", finished.run_id)
+        } else {
+            format!("Training run {} failed", finished.run_id)
+        };
+        notify(
+            &app,
+            "Training finished",
+            &body,
+            NavigationTarget::Monitoring,
+        );
+        emit(&app, "training-finished", finished);
+    } else {
+        finish_run(&dir, RunStatus::Stopped, None);
+    }
+}
+
+// 트레이너는 epoch마다 {"epoch", "loss", "accuracy"} JSON 한 줄을 찍는다.
+// 차트용으로 metrics.jsonl에도 시각과 함께 남긴다
+async fn forward_metrics<R: Runtime>(
+    app: AppHandle<R>,
+    run_id: RunId,
+    dir: PathBuf,
+    stdout: ChildStdout,
+    mut log: File,
+) {
+    let mut lines = BufReader::new(stdout).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        let _ = writeln!(log, "{line}");
+        if let Ok(mut metrics) = serde_json::from_str::<TrainingMetrics>(&line) {
+            metrics.run_id = run_id.clone();
+            let point = MetricPoint {
+                epoch: metrics.epoch,
+                loss: metrics.loss,
+                accuracy: metrics.accuracy,
+                timestamp: Utc::now(),
+            };
+            if let Err(err) = append_metric(&dir, &point) {
+                log::warn!("failed to record metrics for run {run_id}: {err}");
+            }
+            if let Some(run) = app.state::<TrainingRuns>().lock().get_mut(&run_id) {
+                run.latest = Some(metrics.clone());
+            }
+            emit(&app, "training-metrics", metrics);
+        }
+    }
+}
+
+fn finished(run_id: RunId, status: io::Result<ExitStatus>) -> TrainingFinished {
+    match status {
+        Ok(status) => TrainingFinished {
+            run_id,
+            success: status.success(),
+            code: status.code(),
+        },
+        Err(err) => {
+            log::error!("failed to wait for training run {run_id}: {err}");
+            TrainingFinished {
+                run_id,
+                success: false,
+                code: None,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns(names: &[&str]) -> Vec<(String, Option<ColumnType>)> {
+        names
+            .iter()
+            .map(|name| (name.to_string(), Some(ColumnType::Float)))
+            .collect()
+    }
+
+    #[test]
+    fn compatibility_compares_feature_count_and_defers_on_unknown_shapes() {
+        let input = |dims: &[&str]| TensorShape {
+            name: "x".into(),
+            dims: dims.iter().map(|d| d.to_string()).collect(),
+        };
+        let data = columns(&["a", "b", "c", "Label"]);
+
+        let report = compatibility(Some(&[input(&["batch", "3"])]), &data);
+        assert_eq!(report.compatibility, Compatibility::Compatible);
+        assert!(report.warnings.is_empty());
+
+        let report = compatibility(Some(&[input(&["batch", "4"])]), &data);
+        assert_eq!(report.compatibility, Compatibility::Incompatible);
+        assert_eq!(
+            report.warnings,
+            ["the model expects 4 features but the dataset has 3"]
+        );
+
+        let image = compatibility(Some(&[input(&["batch", "3", "224", "224"])]), &data);
+        assert_eq!(image.compatibility, Compatibility::Unknown);
+        assert_eq!(
+            compatibility(None, &data).compatibility,
+            Compatibility::Unknown
+        );
+    }
+
+    #[test]
+    fn gpu_index_must_name_an_available_device() {
+        let mut config = TrainingConfig {
+            model_id: "m".into(),
+            dataset_id: "d".into(),
+            epochs: 1,
+            batch_size: 1,
+            learning_rate: 0.01,
+            gpu_index: None,
+        };
+        assert!(config.validate_gpu(&[]).is_ok());
+        config.gpu_index = Some(1);
+        assert!(config.validate_gpu(&[0, 1]).is_ok());
+        // 0번을 못 읽었어도 1번은 쓸 수 있다
+        assert!(config.validate_gpu(&[1]).is_ok());
+        assert_eq!(
+            config.validate_gpu(&[0]).unwrap_err(),
+            "GPU 1 does not exist; available GPUs: 0"
+        );
+        config.gpu_index = Some(2);
+        assert!(config.validate_gpu(&[0, 2]).is_ok());
+        assert!(config.validate_gpu(&[0, 1]).is_err());
+        assert_eq!(
+            config.validate_gpu(&[]).unwrap_err(),
+            "no GPUs are available on this machine"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::fmt;
+use std::time::Duration;
+
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use tauri::command;
+
+const UPDATE_URL_ENV: &str = "METAGATE_UPDATE_URL";
+const DEFAULT_UPDATE_URL: &str =
+    "https://github.com/Jeffr-K/metagate/releases/latest/download/latest.json";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 릴리스 엔드포인트가 내려주는 버전 정보
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReleaseManifest {
+    pub version: String,
+    pub url: String,
+    #[serde(default)]
+    pub notes: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum UpdateStatus {
+    UpToDate,
+    Available {
+        version: String,
+        url: String,
+        notes: String,
+    },
+}
+
+// "최신 버전"과 "확인 실패"를 UI가 구분할 수 있게 에러는 따로 둔다
+#[derive(Debug)]
+pub enum UpdateError {
+    Network(reqwest::Error),
+    InvalidVersion(String, semver::Error),
+}
+
+impl fmt::Display for UpdateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpdateError::Network(err) => write!(f, "couldn't check for updates: {err}"),
+            UpdateError::InvalidVersion(version, err) => {
+                write!(
+                    f,
+                    "couldn't check for updates: invalid version {version}: {err}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for UpdateError {}
+
+#[command]
+pub async fn check_for_updates() -> Result<UpdateStatus, String> {
+    fetch_update_status().await.map_err(|e| e.to_string())
+}
+
+pub async fn fetch_update_status() -> Result<UpdateStatus, UpdateError> {
+    let url = std::env::var(UPDATE_URL_ENV).unwrap_or_else(|_| DEFAULT_UPDATE_URL.into());
+    let client = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(UpdateError::Network)?;
+    let manifest: ReleaseManifest = client
+        .get(&url)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(UpdateError::Network)?
+        .json()
+        .await
+        .map_err(UpdateError::Network)?;
+
+    update_status(env!("CARGO_PKG_VERSION"), manifest)
+}
+
+pub fn update_status(
+    current: &str,
+    manifest: ReleaseManifest,
+) -> Result<UpdateStatus, UpdateError> {
+    if is_newer(current, &manifest.version)? {
+        Ok(UpdateStatus::Available {
+            version: manifest.version,
+            url: manifest.url,
+            notes: manifest.notes,
+        })
+    } else {
+        Ok(UpdateStatus::UpToDate)
+    }
+}
+
+// 태그 이름처럼 앞에 v가 붙어 있어도 받아 준다
+pub fn is_newer(current: &str, latest: &str) -> Result<bool, UpdateError> {
+    let parse = |version: &str| {
+        Version::parse(version.trim().trim_start_matches('v'))
+            .map_err(|e| UpdateError::InvalidVersion(version.into(), e))
+    };
+    Ok(parse(latest)? > parse(current)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn newer_versions_are_detected() {
+        assert!(is_newer("0.1.0", "0.2.0").unwrap());
+        assert!(is_newer("0.9.9", "1.0.0").unwrap());
+        assert!(is_newer("1.2.3", "v1.2.4").unwrap());
+        assert!(is_newer("1.0.0-beta.1", "1.0.0").unwrap());
+    }
+
+    #[test]
+    fn same_or_older_versions_are_up_to_date() {
+        assert!(!is_newer("1.0.0", "1.0.0").unwrap());
+        assert!(!is_newer("1.0.0", "0.9.0").unwrap());
+        assert!(!is_newer("1.0.0", "1.0.0-rc.1").unwrap());
+    }
+
+    #[test]
+    fn malformed_versions_are_errors() {
+        assert!(is_newer("1.0.0", "latest").is_err());
+    }
+}
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use serde::Serialize;
+use tauri::{command, AppHandle, Manager, Runtime, State, Window, WindowEvent};
+
+use super::emit;
+use crate::fs::locale::{app_locale, t};
+use crate::fs::menu::find_menu_item;
+use crate::fs::menu_events::focused_window;
+use crate::fs::preferences::{Preferences, Theme};
+
+pub const SIDEBAR_ITEM: &str = "toggle_sidebar";
+pub const FULL_SCREEN_ITEM: &str = "full_screen";
+pub const ZOOM_IN_ITEM: &str = "zoom_in";
+pub const ZOOM_OUT_ITEM: &str = "zoom_out";
+pub const ZOOM_RESET_ITEM: &str = "zoom_reset";
+
+const MIN_ZOOM: f64 = 0.5;
+const MAX_ZOOM: f64 = 3.0;
+const ZOOM_STEP: f64 = 0.1;
+
+// 사이드바 표시 여부. 시작할 때 환경설정에서 읽어 온다
+pub struct SidebarState(AtomicBool);
+
+impl SidebarState {
+    pub fn new(visible: bool) -> Self {
+        SidebarState(AtomicBool::new(visible))
+    }
+
+    pub fn visible(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+// 프론트엔드에서 사이드바를 직접 접었다 폈을 때 호출한다
+#[command]
+pub fn set_sidebar_visible(app: AppHandle, visible: bool) {
+    update_sidebar(&app, visible);
+}
+
+pub fn toggle_sidebar<R: Runtime>(app: &AppHandle<R>) {
+    let visible = !app.state::<SidebarState>().visible();
+    update_sidebar(app, visible);
+    emit(app, "toggle-sidebar", visible);
+}
+
+// 체크 항목은 클릭하면 스스로 체크가 바뀌므로 항상 상태 값으로 다시 맞춘다
+fn update_sidebar<R: Runtime>(app: &AppHandle<R>, visible: bool) {
+    app.state::<SidebarState>()
+        .0
+        .store(visible, Ordering::SeqCst);
+
+    if let Some(item) = find_menu_item(app, SIDEBAR_ITEM) {
+        if let Some(Err(err)) = item.as_check_menuitem().map(|i| i.set_checked(visible)) {
+            log::warn!("failed to update sidebar menu item: {err}");
+        }
+    }
+
+    let mut preferences = Preferences::load(app);
+    if preferences.sidebar_visible != visible {
+        preferences.sidebar_visible = visible;
+        if let Err(err) = preferences.save(app) {
+            log::warn!("failed to save preferences: {err}");
+        }
+    }
+}
+
+pub fn toggle_full_screen<R: Runtime>(app: &AppHandle<R>) {
+    let Some(window) = focused_window(app) else {
+        return;
+    };
+    let result = window
+        .is_fullscreen()
+        .and_then(|fullscreen| window.set_fullscreen(!fullscreen));
+    if let Err(err) = result {
+        log::error!("failed to toggle full screen: {err}");
+        return;
+    }
+    sync_full_screen_label(app, window.is_fullscreen().unwrap_or(false));
+}
+
+// 창마다 지금 보고 있는 뷰(models, datasets ...). 프론트엔드가 화면을 바꿀 때 알려준다
+#[derive(Default)]
+pub struct ActiveViews(Mutex<HashMap<String, String>>);
+
+impl ActiveViews {
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, String>> {
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn get(&self, label: &str) -> Option<String> {
+        self.lock().get(label).cloned()
+    }
+
+    pub fn remove(&self, label: &str) {
+        self.lock().remove(label);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelectAll {
+    pub window: String,
+    pub view: Option<String>,
+}
+
+#[command]
+pub fn set_active_view(window: Window, views: State<'_, ActiveViews>, view: String) {
+    views.lock().insert(window.label().to_string(), view);
+}
+
+// 메뉴 항목이 CmdOrCtrl+A를 가져가므로 텍스트 입력에 포커스가 있으면
+// 프론트엔드 핸들러가 뷰 대신 입력창의 기본 전체 선택을 한다
+pub fn select_all<R: Runtime>(app: &AppHandle<R>) {
+    let Some(window) = focused_window(app) else {
+        return;
+    };
+    let label = window.label().to_string();
+    let view = app.state::<ActiveViews>().get(&label);
+    emit(
+        app,
+        "select-all",
+        SelectAll {
+            window: label,
+            view,
+        },
+    );
+}
+
+// OS 단축키나 창 버튼으로 전체 화면이 바뀌어도 Resized가 오므로 그때 라벨을 맞춘다.
+// 메뉴는 앱 전체에 하나라서 포커스를 받은 창 기준으로 보여준다
+pub fn track_full_screen<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    match event {
+        WindowEvent::Resized(_) | WindowEvent::Focused(true)
+            if window.is_focused().unwrap_or(false) =>
+        {
+            let fullscreen = window.is_fullscreen().unwrap_or(false);
+            sync_full_screen_label(window.app_handle(), fullscreen);
+        }
+        _ => {}
+    }
+}
+
+fn sync_full_screen_label<R: Runtime>(app: &AppHandle<R>, fullscreen: bool) {
+    let Some(item) = find_menu_item(app, FULL_SCREEN_ITEM) else {
+        return;
+    };
+    let Some(item) = item.as_menuitem() else {
+        return;
+    };
+    let id = if fullscreen {
+        "exit_full_screen"
+    } else {
+        FULL_SCREEN_ITEM
+    };
+    if let Err(err) = item.set_text(t(id, &app_locale(app))) {
+        log::warn!("failed to update full screen menu item: {err}");
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zoom {
+    In,
+    Out,
+    Reset,
+}
+
+impl Zoom {
+    // 0.1씩 더하다 보면 오차가 쌓여서 소수 첫째 자리로 반올림한다
+    fn apply(self, factor: f64) -> f64 {
+        let next = match self {
+            Zoom::In => factor + ZOOM_STEP,
+            Zoom::Out => factor - ZOOM_STEP,
+            Zoom::Reset => 1.0,
+        };
+        clamp_zoom((next * 10.0).round() / 10.0)
+    }
+}
+
+pub fn clamp_zoom(factor: f64) -> f64 {
+    if factor.is_finite() {
+        factor.clamp(MIN_ZOOM, MAX_ZOOM)
+    } else {
+        1.0
+    }
+}
+
+// 배율은 환경설정 하나로 관리하므로 열려 있는 모든 창에 같이 적용한다
+pub fn zoom<R: Runtime>(app: &AppHandle<R>, step: Zoom) {
+    let mut preferences = Preferences::load(app);
+    preferences.zoom_factor = step.apply(clamp_zoom(preferences.zoom_factor));
+    for window in app.webview_windows().values() {
+        if let Err(err) = window.set_zoom(preferences.zoom_factor) {
+            log::warn!("failed to zoom window {}: {err}", window.label());
+        }
+    }
+    if let Err(err) = preferences.save(app) {
+        log::warn!("failed to save preferences: {err}");
+    }
+}
+
+pub fn apply_saved_zoom<R: Runtime>(window: &tauri::WebviewWindow<R>) -> tauri::Result<()> {
+    let factor = clamp_zoom(Preferences::load(window).zoom_factor);
+    if factor == 1.0 {
+        return Ok(());
+    }
+    window.set_zoom(factor)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ThemeChanged {
+    pub theme: Theme,
+    // System이면 지금 OS가 쓰는 테마
+    pub resolved: tauri::Theme,
+}
+
+// 창마다 ThemeChanged가 오므로 마지막으로 보낸 값과 같으면 다시 보내지 않는다
+#[derive(Default)]
+pub struct ThemeState(Mutex<Option<ThemeChanged>>);
+
+#[command]
+pub fn set_theme(app: AppHandle, theme: Theme) -> Result<(), String> {
+    let mut preferences = Preferences::load(&app);
+    preferences.theme = theme;
+    preferences.save(&app).map_err(|e| e.to_string())?;
+    apply_theme(&app, theme);
+    Ok(())
+}
+
+pub fn apply_theme<R: Runtime>(app: &AppHandle<R>, theme: Theme) {
+    let windows = app.webview_windows();
+    for window in windows.values() {
+        if let Err(err) = window.set_theme(native_theme(theme)) {
+            log::warn!("failed to set theme on window {}: {err}", window.label());
+        }
+    }
+    let resolved = match native_theme(theme) {
+        Some(native) => native,
+        None => windows
+            .values()
+            .find_map(|window| window.theme().ok())
+            .unwrap_or(tauri::Theme::Light),
+    };
+    notify_theme(app, ThemeChanged { theme, resolved });
+}
+
+// 새로 만든 창에도 저장된 테마를 입힌다
+pub fn apply_saved_theme<R: Runtime>(window: &tauri::WebviewWindow<R>) -> tauri::Result<()> {
+    window.set_theme(native_theme(Preferences::load(window).theme))
+}
+
+// System일 때는 창 테마를 None(OS 따름)으로 두므로, OS에서 다크 모드를 바꾸면 여기로 온다
+pub fn track_theme<R: Runtime>(window: &Window<R>, event: &WindowEvent) {
+    let WindowEvent::ThemeChanged(resolved) = event else {
+        return;
+    };
+    let theme = Preferences::load(window).theme;
+    if theme == Theme::System {
+        notify_theme(
+            window.app_handle(),
+            ThemeChanged {
+                theme,
+                resolved: *resolved,
+            },
+        );
+    }
+}
+
+fn notify_theme<R: Runtime>(app: &AppHandle<R>, changed: ThemeChanged) {
+    let state = app.state::<ThemeState>();
+    let mut last = state.0.lock().unwrap_or_else(|e| e.into_inner());
+    if *last == Some(changed) {
+        return;
+    }
+    *last = Some(changed);
+    drop(last);
+    emit(app, "theme-changed", changed);
+}
+
+fn native_theme(theme: Theme) -> Option<tauri::Theme> {
+    match theme {
+        Theme::Light => Some(tauri::Theme::Light),
+        Theme::Dark => Some(tauri::Theme::Dark),
+        Theme::System => None,
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
+
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use tauri::async_runtime::JoinHandle;
+use tauri::{command, AppHandle, Manager, Runtime};
+use tokio::sync::mpsc;
+
+use super::emit;
+use crate::fs::project::RUNS_DIR;
+
+// 큰 파일을 복사하는 동안 쏟아지는 이벤트를 한 번에 묶는 시간
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+struct ActiveWatcher {
+    root: PathBuf,
+    // 드롭되면 감시가 멈춘다
+    _watcher: RecommendedWatcher,
+    task: JoinHandle<()>,
+}
+
+impl Drop for ActiveWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[derive(Default)]
+pub struct ProjectWatcher {
+    active: Mutex<Option<ActiveWatcher>>,
+}
+
+impl ProjectWatcher {
+    fn lock(&self) -> MutexGuard<'_, Option<ActiveWatcher>> {
+        self.active.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn stop(&self) {
+        self.lock().take();
+    }
+
+    pub fn watched_root(&self) -> Option<PathBuf> {
+        self.lock().as_ref().map(|active| active.root.clone())
+    }
+
+    pub fn is_watching(&self, root: &Path) -> bool {
+        self.lock()
+            .as_ref()
+            .is_some_and(|active| active.root == root)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ProjectFilesChanged {
+    pub paths: Vec<PathBuf>,
+}
+
+#[command]
+pub fn watch_project(app: AppHandle, path: String) -> Result<(), String> {
+    start_watching(&app, Path::new(&path))
+}
+
+#[command]
+pub fn unwatch_project(app: AppHandle) {
+    app.state::<ProjectWatcher>().stop();
+}
+
+// 한 번에 한 프로젝트만 감시한다. 새로 감시를 시작하면 이전 감시는 버린다
+pub fn start_watching<R: Runtime>(app: &AppHandle<R>, root: &Path) -> Result<(), String> {
+    let watcher_state = app.state::<ProjectWatcher>();
+    if watcher_state.is_watching(root) {
+        return Ok(());
+    }
+    watcher_state.stop();
+
+    let (tx, rx) = mpsc::unbounded_channel::<PathBuf>();
+    let runs = root.join(RUNS_DIR);
+    let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+        match result {
+            Ok(event) => {
+                // 실행 로그는 계속 쓰이므로 알리지 않는다
+                for path in event.paths.into_iter().filter(|p| !p.starts_with(&runs)) {
+                    let _ = tx.send(path);
+                }
+            }
+            Err(err) => log::warn!("project watcher error: {err}"),
+        }
+    })
+    .map_err(|e| format!("failed to watch {}: {e}", root.display()))?;
+    watcher
+        .watch(root, RecursiveMode::Recursive)
+        .map_err(|e| format!("failed to watch {}: {e}", root.display()))?;
+
+    let task = tauri::async_runtime::spawn(debounce(app.clone(), rx));
+    *watcher_state.lock() = Some(ActiveWatcher {
+        root: root.to_path_buf(),
+        _watcher: watcher,
+        task,
+    });
+    Ok(())
+}
+
+// 첫 이벤트가 오고 DEBOUNCE 동안 더 오는 것까지 모아서 한 번만 보낸다
+async fn debounce<R: Runtime>(app: AppHandle<R>, mut rx: mpsc::UnboundedReceiver<PathBuf>) {
+    while let Some(first) = rx.recv().await {
+        let mut paths = BTreeSet::from([first]);
+        let deadline = tokio::time::sleep(DEBOUNCE);
+        tokio::pin!(deadline);
+        loop {
+            tokio::select! {
+                _ = &mut deadline => break,
+                next = rx.recv() => match next {
+                    Some(path) => {
+                        paths.insert(path);
+                    }
+                    None => break,
+                },
+            }
+        }
+        emit(
+            &app,
+            "project-files-changed",
+            ProjectFilesChanged {
+                paths: paths.into_iter().collect(),
+            },
+        );
+    }
+}
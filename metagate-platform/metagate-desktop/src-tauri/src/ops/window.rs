@@ -0,0 +1,207 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use tauri::{
+    command, AppHandle, Manager, Runtime, WebviewUrl, WebviewWindow, WebviewWindowBuilder,
+};
+
+use super::project::ProjectState;
+use super::view::{apply_saved_theme, apply_saved_zoom};
+use crate::fs::menu_events::focused_window;
+use crate::fs::window_state::MAIN_WINDOW;
+
+pub const NEW_WINDOW_ITEM: &str = "new_window";
+const WINDOW_TITLE: &str = "Metagate";
+// 저장 안 된 변경이 있을 때 제목 끝에 붙인다
+const DIRTY_MARKER: &str = "•";
+
+// 추가 창 번호. 메인 창이 1번이라 2부터 센다
+pub struct WindowCounter(AtomicUsize);
+
+impl Default for WindowCounter {
+    fn default() -> Self {
+        WindowCounter(AtomicUsize::new(2))
+    }
+}
+
+// 동기 커맨드에서 창을 만들면 Windows에서 멈출 수 있어서 async로 둔다
+#[command]
+pub async fn new_window(app: AppHandle) -> Result<String, String> {
+    open_window(&app).map_err(|e| e.to_string())
+}
+
+// 프로젝트 상태는 앱 전역 managed state라서 새 창도 같은 프로젝트를 본다.
+// 창 하나를 닫아도 상태는 앱이 끝날 때까지 남아 있다
+pub fn open_window<R: Runtime>(app: &AppHandle<R>) -> Result<String, tauri::Error> {
+    let counter = app.state::<WindowCounter>();
+    let (index, label) = loop {
+        let index = counter.0.fetch_add(1, Ordering::SeqCst);
+        let label = format!("main-{index}");
+        if app.get_webview_window(&label).is_none() {
+            break (index, label);
+        }
+    };
+
+    let document = DocumentState::current(app);
+    let window = WebviewWindowBuilder::new(app, &label, WebviewUrl::App("index.html".into()))
+        .title(document.title(Some(index)))
+        .inner_size(800.0, 600.0)
+        .build()?;
+    apply_saved_zoom(&window)?;
+    apply_saved_theme(&window)?;
+    #[cfg(target_os = "macos")]
+    set_document_edited(&window, &document);
+    Ok(label)
+}
+
+// 창 제목에 보여주는 프로젝트 상태
+struct DocumentState {
+    project: Option<String>,
+    #[cfg(target_os = "macos")]
+    manifest: Option<std::path::PathBuf>,
+    dirty: bool,
+}
+
+impl DocumentState {
+    fn current<R: Runtime>(app: &AppHandle<R>) -> Self {
+        let state = app.state::<ProjectState>();
+        let manifest = state.lock().as_ref().map(|p| p.manifest_path());
+        DocumentState {
+            project: state.title(),
+            dirty: manifest.is_some() && state.dirty.load(Ordering::SeqCst),
+            #[cfg(target_os = "macos")]
+            manifest,
+        }
+    }
+
+    fn title(&self, index: Option<usize>) -> String {
+        window_title(self.project.as_deref(), index, self.dirty)
+    }
+}
+
+// 메인 창은 번호 없이, 추가 창은 "(2)"처럼 번호를 붙인다
+pub fn window_title(project: Option<&str>, index: Option<usize>, dirty: bool) -> String {
+    let mut title = match project {
+        Some(name) => format!("{WINDOW_TITLE} — {name}"),
+        None => WINDOW_TITLE.to_string(),
+    };
+    if let Some(index) = index {
+        title.push_str(&format!(" ({index})"));
+    }
+    if dirty {
+        title.push(' ');
+        title.push_str(DIRTY_MARKER);
+    }
+    title
+}
+
+// 프로젝트를 열고 닫거나, 바꾸거나 저장한 뒤에 부른다.
+// 환경설정 같은 보조 창은 제목을 그대로 둔다
+pub fn update_window_title<R: Runtime>(app: &AppHandle<R>) {
+    let document = DocumentState::current(app);
+    for (label, window) in app.webview_windows() {
+        let index = match label.as_str() {
+            MAIN_WINDOW => None,
+            other => match other.strip_prefix("main-").and_then(|n| n.parse().ok()) {
+                Some(index) => Some(index),
+                None => continue,
+            },
+        };
+        if let Err(err) = window.set_title(&document.title(index)) {
+            log::warn!("failed to set title of window {label}: {err}");
+        }
+        #[cfg(target_os = "macos")]
+        set_document_edited(&window, &document);
+    }
+}
+
+// 제목 표시줄의 프로젝트 아이콘과 닫기 버튼의 수정됨 점. AppKit은 메인 스레드에서만 부른다
+#[cfg(target_os = "macos")]
+fn set_document_edited<R: Runtime>(window: &WebviewWindow<R>, document: &DocumentState) {
+    use objc2_app_kit::NSWindow;
+    use objc2_foundation::NSString;
+
+    let target = window.clone();
+    let filename = document
+        .manifest
+        .as_ref()
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let edited = document.dirty;
+    let result = window.run_on_main_thread(move || {
+        let Ok(ns_window) = target.ns_window() else {
+            return;
+        };
+        // 창이 살아 있는 동안 tauri가 넘겨주는 포인터는 NSWindow다
+        let ns_window = unsafe { &*ns_window.cast::<NSWindow>() };
+        ns_window.setRepresentedFilename(&NSString::from_str(&filename));
+        ns_window.setDocumentEdited(edited);
+    });
+    if let Err(err) = result {
+        log::warn!("failed to mark window {} as edited: {err}", window.label());
+    }
+}
+
+pub fn minimize_focused<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(window) = focused_window(app) {
+        if let Err(err) = window.minimize() {
+            log::error!("failed to minimize window {}: {err}", window.label());
+        }
+    }
+}
+
+// 메인 창, main-2, main-3 ... 순서로 올리고 원래 포커스가 있던 창을 마지막에 올려서 맨 앞에 둔다
+pub fn bring_all_to_front<R: Runtime>(app: &AppHandle<R>) {
+    let focused = focused_window(app).map(|window| window.label().to_string());
+    let mut windows: Vec<WebviewWindow<R>> = app.webview_windows().into_values().collect();
+    windows.sort_by_key(|window| window_order(window.label(), focused.as_deref()));
+    for window in windows {
+        let result = window
+            .unminimize()
+            .and_then(|_| window.show())
+            .and_then(|_| window.set_focus());
+        if let Err(err) = result {
+            log::warn!("failed to raise window {}: {err}", window.label());
+        }
+    }
+}
+
+// 번호 없는 창(환경설정 등)은 번호 있는 창 뒤, 라벨 순으로 둔다
+fn window_order(label: &str, focused: Option<&str>) -> (bool, usize, String) {
+    let index = if label == MAIN_WINDOW {
+        1
+    } else {
+        label
+            .strip_prefix("main-")
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(usize::MAX)
+    };
+    (Some(label) == focused, index, label.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_title_shows_project_window_number_and_unsaved_changes() {
+        assert_eq!(window_title(None, None, false), "Metagate");
+        assert_eq!(
+            window_title(Some("vision"), None, false),
+            "Metagate — vision"
+        );
+        assert_eq!(
+            window_title(Some("vision"), Some(3), true),
+            "Metagate — vision (3) •"
+        );
+    }
+
+    #[test]
+    fn windows_are_raised_in_number_order_with_focused_last() {
+        let mut labels = vec!["preferences", "main-10", "main", "main-2", "main-3"];
+        labels.sort_by_key(|label| window_order(label, Some("main-2")));
+        assert_eq!(
+            labels,
+            ["main", "main-3", "main-10", "preferences", "main-2"]
+        );
+    }
+}